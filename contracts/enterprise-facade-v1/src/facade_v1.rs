@@ -13,28 +13,31 @@ use crate::v1_structs::QueryV1Msg::{
     ProposalVotes, Proposals, ReleasableClaims, StakedNfts, TotalStakedAmount, UserStake,
 };
 use crate::v1_structs::{
-    CreateProposalV1Msg, Cw20HookV1Msg, Cw721HookV1Msg, DaoInfoResponseV1, ExecuteMsgsV1Msg,
-    ExecuteV1Msg, ProposalActionV1, ProposalResponseV1, ProposalsResponseV1,
+    to_v1_msgs, CreateProposalV1Msg, Cw20HookV1Msg, Cw721HookV1Msg, DaoInfoResponseV1,
+    ExecuteMsgsV1Msg, ExecuteV1Msg, ProposalActionV1, ProposalResponseV1, ProposalsResponseV1,
     TreasuryV1_0_0MigrationMsg, UnstakeCw20V1Msg, UnstakeCw721V1Msg, UnstakeV1Msg, UpgradeDaoV1Msg,
     UserStakeV1Params,
 };
 use common::cw::QueryContext;
+use common::nft::query_owned_token_ids;
 use cosmwasm_schema::serde::de::DeserializeOwned;
 use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::{to_json_binary, Addr, Deps, Empty, StdError, StdResult};
+use cw_asset::Asset;
 use cw_utils::Expiration;
 use enterprise_facade_api::api::{
     adapter_response_single_execute_msg, AdaptedExecuteMsg, AdaptedMsg, AdapterResponse,
-    AssetWhitelistParams, AssetWhitelistResponse, CastVoteMsg, ClaimsParams, ClaimsResponse,
-    ComponentContractsResponse, CreateProposalMsg, CreateProposalWithDenomDepositMsg,
-    CreateProposalWithTokenDepositMsg, DaoInfoResponse, DaoType, ExecuteProposalMsg,
-    GovConfigFacade, ListMultisigMembersMsg, MemberInfoResponse, MemberVoteParams,
-    MemberVoteResponse, MultisigMembersResponse, NftWhitelistParams, NftWhitelistResponse,
-    Proposal, ProposalParams, ProposalResponse, ProposalStatus, ProposalStatusParams,
-    ProposalStatusResponse, ProposalType, ProposalVotesParams, ProposalVotesResponse,
-    ProposalsParams, ProposalsResponse, QueryMemberInfoMsg, StakeMsg, StakedNftsParams,
-    StakedNftsResponse, TotalStakedAmountResponse, TreasuryAddressResponse, UnstakeMsg,
-    UserStakeParams, UserStakeResponse, V2MigrationStage, V2MigrationStageResponse,
+    AssetWhitelistParams, AssetWhitelistResponse, BalancesParams, BalancesResponse, CastVoteMsg,
+    ClaimsParams, ClaimsResponse, ComponentContractsResponse, CreateProposalMsg,
+    CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg, DaoInfoResponse, DaoType,
+    ExecuteProposalMsg, GovConfigFacade, ListMultisigMembersMsg, MemberInfoResponse,
+    MemberVoteParams, MemberVoteResponse, MultisigMembersResponse, NftBalance, NftWhitelistParams,
+    NftWhitelistResponse, Proposal, ProposalParams, ProposalResponse, ProposalStatus,
+    ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse, ProposalType,
+    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
+    QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse, TotalStakedAmountResponse,
+    TreasuryAddressResponse, UnstakeMsg, UserStakeParams, UserStakeResponse, V2MigrationStage,
+    V2MigrationStageResponse,
 };
 use enterprise_facade_api::error::DaoError::UnsupportedOperationForDaoType;
 use enterprise_facade_api::error::EnterpriseFacadeError::Dao;
@@ -91,6 +94,7 @@ impl EnterpriseFacade for EnterpriseFacadeV1 {
             unlocking_period: dao_info_v5.gov_config.unlocking_period,
             minimum_deposit: dao_info_v5.gov_config.minimum_deposit,
             allow_early_proposal_execution: dao_info_v5.gov_config.allow_early_proposal_execution,
+            snapshot_voting: false,
         };
 
         Ok(DaoInfoResponse {
@@ -160,6 +164,61 @@ impl EnterpriseFacade for EnterpriseFacadeV1 {
         self.query_enterprise_contract(qctx.deps, &NftWhitelist(params))
     }
 
+    fn query_balances(
+        &self,
+        qctx: QueryContext,
+        params: BalancesParams,
+    ) -> EnterpriseFacadeResult<BalancesResponse> {
+        // v1 DAOs hold their treasury in the enterprise contract itself, and have no balances
+        // query, so we compute holdings of their whitelisted assets and NFTs here
+        let asset_whitelist: AssetWhitelistResponse = self.query_enterprise_contract(
+            qctx.deps,
+            &AssetWhitelist(AssetWhitelistParams {
+                start_after: params.asset_start_after,
+                limit: params.asset_limit,
+            }),
+        )?;
+
+        let assets = asset_whitelist
+            .assets
+            .into_iter()
+            .map(|asset| {
+                let balance =
+                    asset.query_balance(&qctx.deps.querier, self.enterprise_address.to_string())?;
+                Ok(Asset::new(asset, balance))
+            })
+            .collect::<StdResult<Vec<Asset>>>()?;
+
+        let nft_whitelist: NftWhitelistResponse = self.query_enterprise_contract(
+            qctx.deps,
+            &NftWhitelist(NftWhitelistParams {
+                start_after: params.nft_start_after,
+                limit: params.nft_limit,
+            }),
+        )?;
+
+        let nfts = nft_whitelist
+            .nfts
+            .into_iter()
+            .map(|nft_contract| {
+                let owned_token_ids = query_owned_token_ids(
+                    qctx.deps,
+                    &nft_contract,
+                    &self.enterprise_address,
+                    None,
+                    params.token_ids_limit,
+                )?;
+                Ok(NftBalance {
+                    nft_contract,
+                    token_ids: owned_token_ids.token_ids,
+                    token_ids_start_after: owned_token_ids.next_start_after,
+                })
+            })
+            .collect::<StdResult<Vec<NftBalance>>>()?;
+
+        Ok(BalancesResponse { assets, nfts })
+    }
+
     fn query_proposal(
         &self,
         qctx: QueryContext,
@@ -180,6 +239,16 @@ impl EnterpriseFacade for EnterpriseFacadeV1 {
         qctx: QueryContext,
         params: ProposalsParams,
     ) -> EnterpriseFacadeResult<ProposalsResponse> {
+        // proposals cannot be cancelled in old DAOs
+        if let Some(ProposalStatusFilter::Cancelled) = params.filter {
+            return Ok(ProposalsResponse { proposals: vec![] });
+        }
+
+        // neither can they be tagged
+        if params.tag.is_some() {
+            return Ok(ProposalsResponse { proposals: vec![] });
+        }
+
         let response: ProposalsResponseV1 =
             self.query_enterprise_contract(qctx.deps, &Proposals(params))?;
 
@@ -534,6 +603,7 @@ impl EnterpriseFacadeV1 {
             quorum: gov_config.quorum,
             threshold: gov_config.threshold,
             veto_threshold: Some(gov_config.veto_threshold),
+            options: vec![],
             results: response.results.clone(),
         };
 
@@ -591,18 +661,18 @@ impl EnterpriseFacadeV1 {
                     }))
                 }
             }
-            ProposalAction::ExecuteMsgs(msg) => Ok(ExecuteMsgs(msg.into())),
+            ProposalAction::ExecuteMsgs(msg) => Ok(ExecuteMsgs(msg.try_into()?)),
             ProposalAction::ExecuteTreasuryMsgs(msg) => {
                 if msg.remote_treasury_target.is_some() {
                     Err(StdError::generic_err("remote treasuries not supported"))
                 } else {
                     Ok(ExecuteMsgs(ExecuteMsgsV1Msg {
                         action_type: msg.action_type,
-                        msgs: msg.msgs,
+                        msgs: to_v1_msgs(msg.msgs)?,
                     }))
                 }
             }
-            ProposalAction::ExecuteEnterpriseMsgs(msg) => Ok(ExecuteMsgs(msg.into())),
+            ProposalAction::ExecuteEnterpriseMsgs(msg) => Ok(ExecuteMsgs(msg.try_into()?)),
             ProposalAction::ModifyMultisigMembership(msg) => {
                 Ok(ModifyMultisigMembership(msg.into()))
             }
@@ -610,7 +680,15 @@ impl EnterpriseFacadeV1 {
             ProposalAction::UpdateMinimumWeightForRewards(msg) => {
                 Ok(UpdateMinimumWeightForRewards(msg.into()))
             }
-            ProposalAction::DeployCrossChainTreasury(_) => {
+            ProposalAction::DeployCrossChainTreasury(_)
+            | ProposalAction::DistributeFundsTargeted(_)
+            | ProposalAction::CancelProposal(_)
+            | ProposalAction::VetoProposal(_)
+            | ProposalAction::CreateStream(_)
+            | ProposalAction::CancelStream(_)
+            | ProposalAction::CreatePaymentSchedule(_)
+            | ProposalAction::CancelPaymentSchedule(_)
+            | ProposalAction::TransferNfts(_) => {
                 Err(StdError::generic_err("unsupported proposal action"))
             }
         }
@@ -651,6 +729,7 @@ impl EnterpriseFacadeV1 {
                         PollStatus::InProgress { .. } => return Err(StdError::generic_err("invalid state - resolved proposal status to 'in progress' after it ended").into()),
                         PollStatus::Passed { .. } => ProposalStatus::Passed,
                         PollStatus::Rejected { .. } => ProposalStatus::Rejected,
+                        PollStatus::Cancelled {} => ProposalStatus::Cancelled,
                     }
                 } else {
                     // proposal still in progress, let's see if it can be executed early
@@ -667,6 +746,7 @@ impl EnterpriseFacadeV1 {
                         match poll_status {
                             PollStatus::InProgress { .. } => ProposalStatus::InProgress,
                             PollStatus::Passed { .. } => ProposalStatus::InProgressCanExecuteEarly,
+                            PollStatus::Cancelled {} => ProposalStatus::Cancelled,
                             PollStatus::Rejected { reason } => match reason {
                                 QuorumNotReached
                                 | ThresholdNotReached
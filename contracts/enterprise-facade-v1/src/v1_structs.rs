@@ -1,7 +1,7 @@
 use common::commons::ModifyValue;
 use common::commons::ModifyValue::{Change, NoChange};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Decimal, StdError, StdResult, Timestamp, Uint128, Uint64};
 use cw_asset::{AssetInfoUnchecked, AssetUnchecked};
 use cw_utils::{Duration, Expiration};
 use enterprise_facade_api::api::{
@@ -13,7 +13,7 @@ use enterprise_facade_api::api::{
 };
 use enterprise_governance_controller_api::api::{
     DaoCouncilSpec, DistributeFundsMsg, ExecuteEnterpriseMsgsMsg, ExecuteMsgsMsg,
-    ModifyMultisigMembershipMsg, ProposalAction, RequestFundingFromDaoMsg,
+    ModifyMultisigMembershipMsg, ProposalAction, ProposalCosmosMsg, RequestFundingFromDaoMsg,
     UpdateAssetWhitelistProposalActionMsg, UpdateCouncilMsg, UpdateGovConfigMsg,
     UpdateMinimumWeightForRewardsMsg, UpdateNftWhitelistProposalActionMsg,
 };
@@ -107,6 +107,13 @@ impl From<UpdateGovConfigV1Msg> for UpdateGovConfigMsg {
             unlocking_period: value.unlocking_period,
             minimum_deposit: value.minimum_deposit,
             allow_early_proposal_execution: value.allow_early_proposal_execution,
+            snapshot_voting: NoChange,
+            proposal_cancellation_cutoff: NoChange,
+            proposal_deposit_outcomes: NoChange,
+            general_execution_timelock: NoChange,
+            council_execution_timelock: NoChange,
+            action_type_gov_params: NoChange,
+            review_period: NoChange,
         }
     }
 }
@@ -239,21 +246,25 @@ pub struct ExecuteMsgsV1Msg {
     pub msgs: Vec<String>,
 }
 
-impl From<ExecuteMsgsMsg> for ExecuteMsgsV1Msg {
-    fn from(value: ExecuteMsgsMsg) -> Self {
-        ExecuteMsgsV1Msg {
+impl TryFrom<ExecuteMsgsMsg> for ExecuteMsgsV1Msg {
+    type Error = StdError;
+
+    fn try_from(value: ExecuteMsgsMsg) -> StdResult<Self> {
+        Ok(ExecuteMsgsV1Msg {
             action_type: value.action_type,
-            msgs: value.msgs,
-        }
+            msgs: to_v1_msgs(value.msgs)?,
+        })
     }
 }
 
-impl From<ExecuteEnterpriseMsgsMsg> for ExecuteMsgsV1Msg {
-    fn from(value: ExecuteEnterpriseMsgsMsg) -> Self {
-        ExecuteMsgsV1Msg {
+impl TryFrom<ExecuteEnterpriseMsgsMsg> for ExecuteMsgsV1Msg {
+    type Error = StdError;
+
+    fn try_from(value: ExecuteEnterpriseMsgsMsg) -> StdResult<Self> {
+        Ok(ExecuteMsgsV1Msg {
             action_type: value.action_type,
-            msgs: value.msgs,
-        }
+            msgs: to_v1_msgs(value.msgs)?,
+        })
     }
 }
 
@@ -261,11 +272,20 @@ impl From<ExecuteMsgsV1Msg> for ExecuteMsgsMsg {
     fn from(value: ExecuteMsgsV1Msg) -> Self {
         ExecuteMsgsMsg {
             action_type: value.action_type,
-            msgs: value.msgs,
+            msgs: value
+                .msgs
+                .into_iter()
+                .map(ProposalCosmosMsg::from)
+                .collect(),
         }
     }
 }
 
+/// v1 DAOs only take messages as JSON strings.
+pub fn to_v1_msgs(msgs: Vec<ProposalCosmosMsg>) -> StdResult<Vec<String>> {
+    msgs.iter().map(ProposalCosmosMsg::to_json_string).collect()
+}
+
 #[cw_serde]
 pub struct ModifyMultisigMembershipV1Msg {
     /// Members to be edited.
@@ -327,7 +347,10 @@ impl From<DistributeFundsMsg> for DistributeFundsV1Msg {
 
 impl From<DistributeFundsV1Msg> for DistributeFundsMsg {
     fn from(value: DistributeFundsV1Msg) -> Self {
-        DistributeFundsMsg { funds: value.funds }
+        DistributeFundsMsg {
+            funds: value.funds,
+            stream_duration: None,
+        }
     }
 }
 
@@ -486,6 +509,7 @@ impl From<ProposalV1> for Proposal {
                 .into_iter()
                 .map(|action| action.into())
                 .collect(),
+            metadata: None,
         }
     }
 }
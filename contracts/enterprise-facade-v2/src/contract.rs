@@ -75,6 +75,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> EnterpriseFacadeResult<Bina
             let facade = get_facade(contract)?;
             to_json_binary(&facade.query_nft_whitelist(qctx, params)?)?
         }
+        QueryMsg::Balances { contract, params } => {
+            let facade = get_facade(contract)?;
+            to_json_binary(&facade.query_balances(qctx, params)?)?
+        }
         QueryMsg::Proposal { contract, params } => {
             let facade = get_facade(contract)?;
             to_json_binary(&facade.query_proposal(qctx, params)?)?
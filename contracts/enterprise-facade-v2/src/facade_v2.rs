@@ -8,15 +8,16 @@ use denom_staking_api::api::DenomConfigResponse;
 use denom_staking_api::msg::QueryMsg::DenomConfig;
 use enterprise_facade_api::api::{
     adapter_response_single_execute_msg, AdaptedBankMsg, AdaptedExecuteMsg, AdaptedMsg,
-    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, CastVoteMsg, Claim, ClaimAsset,
-    ClaimsParams, ClaimsResponse, CreateProposalMsg, CreateProposalWithDenomDepositMsg,
-    CreateProposalWithTokenDepositMsg, Cw20ClaimAsset, Cw721ClaimAsset, DaoCouncil,
-    DaoInfoResponse, DaoMetadata, DaoSocialData, DaoType, DenomClaimAsset, DenomUserStake,
-    ExecuteProposalMsg, GovConfigFacade, ListMultisigMembersMsg, MemberInfoResponse,
-    MemberVoteParams, MemberVoteResponse, MultisigMember, MultisigMembersResponse, NftUserStake,
-    NftWhitelistParams, NftWhitelistResponse, Proposal, ProposalParams, ProposalResponse,
-    ProposalStatus, ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse,
-    ProposalType, ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
+    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, BalancesParams,
+    BalancesResponse, CastVoteMsg, Claim, ClaimAsset, ClaimsParams, ClaimsResponse,
+    CreateProposalMsg, CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg,
+    Cw20ClaimAsset, Cw721ClaimAsset, DaoCouncil, DaoInfoResponse, DaoMetadata, DaoSocialData,
+    DaoType, DenomClaimAsset, DenomUserStake, ExecuteProposalMsg, GovConfigFacade,
+    ListMultisigMembersMsg, MemberInfoResponse, MemberVoteParams, MemberVoteResponse,
+    MultisigMember, MultisigMembersResponse, NftBalance, NftUserStake, NftWhitelistParams,
+    NftWhitelistResponse, Proposal, ProposalParams, ProposalResponse, ProposalStatus,
+    ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse, ProposalType,
+    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
     QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse, TokenUserStake,
     TotalStakedAmountResponse, TreasuryAddressResponse, UnstakeMsg, UserStake, UserStakeParams,
     UserStakeResponse, V2MigrationStage, V2MigrationStageResponse,
@@ -38,7 +39,7 @@ use enterprise_treasury_api::api::{
     HasIncompleteV2MigrationResponse, HasUnmovedStakesOrClaimsResponse,
 };
 use enterprise_treasury_api::msg::QueryMsg::{
-    AssetWhitelist, HasIncompleteV2Migration, HasUnmovedStakesOrClaims, NftWhitelist,
+    AssetWhitelist, Balances, HasIncompleteV2Migration, HasUnmovedStakesOrClaims, NftWhitelist,
 };
 use enterprise_versioning_api::api::Version;
 use membership_common_api::api::{
@@ -180,6 +181,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
             unlocking_period,
             minimum_deposit: gov_config.gov_config.minimum_deposit,
             allow_early_proposal_execution: gov_config.gov_config.allow_early_proposal_execution,
+            snapshot_voting: gov_config.gov_config.snapshot_voting,
         };
 
         Ok(DaoInfoResponse {
@@ -241,6 +243,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
             component_contracts.membership_contract.to_string(),
             &UserWeight(UserWeightParams {
                 user: msg.member_address,
+                expiration: None,
             }),
         )?;
         let total_weight: TotalWeightResponse = qctx.deps.querier.query_wasm_smart(
@@ -347,6 +350,41 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
         })
     }
 
+    fn query_balances(
+        &self,
+        qctx: QueryContext,
+        params: BalancesParams,
+    ) -> EnterpriseFacadeResult<BalancesResponse> {
+        let treasury_address = self
+            .component_contracts(qctx.deps)?
+            .enterprise_treasury_contract;
+
+        let balances: enterprise_treasury_api::api::BalancesResponse =
+            qctx.deps.querier.query_wasm_smart(
+                treasury_address.to_string(),
+                &Balances(enterprise_treasury_api::api::BalancesParams {
+                    asset_start_after: params.asset_start_after,
+                    asset_limit: params.asset_limit,
+                    nft_start_after: params.nft_start_after,
+                    nft_limit: params.nft_limit,
+                    token_ids_limit: params.token_ids_limit,
+                }),
+            )?;
+
+        Ok(BalancesResponse {
+            assets: balances.assets,
+            nfts: balances
+                .nfts
+                .into_iter()
+                .map(|nft_balance| NftBalance {
+                    nft_contract: nft_balance.nft_contract,
+                    token_ids: nft_balance.token_ids,
+                    token_ids_start_after: nft_balance.token_ids_start_after,
+                })
+                .collect(),
+        })
+    }
+
     fn query_proposal(
         &self,
         qctx: QueryContext,
@@ -384,6 +422,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                 &enterprise_governance_controller_api::msg::QueryMsg::Proposals(
                     enterprise_governance_controller_api::api::ProposalsParams {
                         filter: params.filter.map(map_proposal_filter),
+                        tag: params.tag,
                         start_after: params.start_after,
                         limit: params.limit,
                     },
@@ -487,7 +526,10 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
             DaoType::Denom => {
                 let denom_stake: UserWeightResponse = qctx.deps.querier.query_wasm_smart(
                     membership_contract.to_string(),
-                    &UserWeight(UserWeightParams { user: params.user }),
+                    &UserWeight(UserWeightParams {
+                        user: params.user,
+                        expiration: None,
+                    }),
                 )?;
 
                 Ok(UserStakeResponse {
@@ -499,7 +541,10 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
             DaoType::Token => {
                 let token_stake: UserWeightResponse = qctx.deps.querier.query_wasm_smart(
                     membership_contract.to_string(),
-                    &UserWeight(UserWeightParams { user: params.user }),
+                    &UserWeight(UserWeightParams {
+                        user: params.user,
+                        expiration: None,
+                    }),
                 )?;
 
                 Ok(UserStakeResponse {
@@ -777,6 +822,9 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                     description: params.description,
                     proposal_actions: params.proposal_actions,
                     deposit_owner: params.deposit_owner,
+                    options: None,
+                    execution_mode: None,
+                    metadata: None,
                 },
             ))?,
             vec![],
@@ -814,6 +862,9 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                     description: params.create_proposal_msg.description,
                     proposal_actions: params.create_proposal_msg.proposal_actions,
                     deposit_owner: params.create_proposal_msg.deposit_owner,
+                    options: None,
+                    execution_mode: None,
+                    metadata: None,
                 },
             ))?,
             funds: coins(params.deposit_amount.u128(), denom_config.denom),
@@ -855,6 +906,9 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                                     description: params.create_proposal_msg.description,
                                     proposal_actions: params.create_proposal_msg.proposal_actions,
                                     deposit_owner: params.create_proposal_msg.deposit_owner,
+                                    options: None,
+                                    execution_mode: None,
+                                    metadata: None,
                                 },
                             ),
                         )?,
@@ -944,6 +998,9 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                         description: params.description,
                         proposal_actions: params.proposal_actions,
                         deposit_owner: params.deposit_owner,
+                        options: None,
+                        execution_mode: None,
+                        metadata: None,
                     },
                 ),
             )?,
@@ -966,7 +1023,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                 &enterprise_governance_controller_api::msg::ExecuteMsg::CastVote(
                     enterprise_governance_controller_api::api::CastVoteMsg {
                         proposal_id: params.proposal_id,
-                        outcome: params.outcome,
+                        outcome: params.outcome.into(),
                     },
                 ),
             )?,
@@ -989,7 +1046,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                 &enterprise_governance_controller_api::msg::ExecuteMsg::CastCouncilVote(
                     enterprise_governance_controller_api::api::CastVoteMsg {
                         proposal_id: params.proposal_id,
-                        outcome: params.outcome,
+                        outcome: params.outcome.into(),
                     },
                 ),
             )?,
@@ -1017,7 +1074,12 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                 ProposalAction::UpdateAssetWhitelist(msg) => msg.remote_treasury_target.is_some(),
                 ProposalAction::UpdateNftWhitelist(msg) => msg.remote_treasury_target.is_some(),
                 ProposalAction::RequestFundingFromDao(msg) => msg.remote_treasury_target.is_some(),
+                ProposalAction::TransferNfts(msg) => msg.remote_treasury_target.is_some(),
                 ProposalAction::ExecuteTreasuryMsgs(msg) => msg.remote_treasury_target.is_some(),
+                ProposalAction::CreateStream(msg) => msg.remote_treasury_target.is_some(),
+                ProposalAction::CancelStream(msg) => msg.remote_treasury_target.is_some(),
+                ProposalAction::CreatePaymentSchedule(msg) => msg.remote_treasury_target.is_some(),
+                ProposalAction::CancelPaymentSchedule(msg) => msg.remote_treasury_target.is_some(),
                 ProposalAction::DeployCrossChainTreasury(_) => true,
                 _ => false,
             })
@@ -1308,6 +1370,13 @@ fn map_proposal_status(
         enterprise_governance_controller_api::api::ProposalStatus::Executed => {
             ProposalStatus::Executed
         }
+        enterprise_governance_controller_api::api::ProposalStatus::Cancelled => {
+            ProposalStatus::Cancelled
+        }
+        enterprise_governance_controller_api::api::ProposalStatus::Expired => {
+            ProposalStatus::Expired
+        }
+        enterprise_governance_controller_api::api::ProposalStatus::Vetoed => ProposalStatus::Vetoed,
     }
 }
 
@@ -1324,6 +1393,9 @@ fn map_proposal_filter(
         ProposalStatusFilter::Rejected => {
             enterprise_governance_controller_api::api::ProposalStatusFilter::Rejected
         }
+        ProposalStatusFilter::Cancelled => {
+            enterprise_governance_controller_api::api::ProposalStatusFilter::Cancelled
+        }
     }
 }
 
@@ -1342,6 +1414,7 @@ fn map_proposal(proposal: enterprise_governance_controller_api::api::Proposal) -
         started_at: proposal.started_at,
         expires: proposal.expires,
         proposal_actions: proposal.proposal_actions,
+        metadata: proposal.metadata,
     }
 }
 
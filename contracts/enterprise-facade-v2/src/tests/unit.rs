@@ -1 +1,71 @@
+use crate::contract::query;
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, ContractResult, StdError, SystemError, SystemResult, WasmQuery,
+};
+use cw_utils::Expiration;
+use enterprise_facade_api::api::{ProposalStatus, ProposalStatusParams, ProposalStatusResponse};
+use enterprise_facade_api::msg::QueryMsg;
+use enterprise_protocol::api::ComponentContractsResponse;
 
+const ENTERPRISE: &str = "enterprise";
+const GOVERNANCE_CONTROLLER: &str = "governance_controller";
+
+#[test]
+fn cancelled_proposal_status_is_reported_through_facade() {
+    let mut deps = mock_dependencies();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let response = match contract_addr.as_str() {
+                ENTERPRISE => to_json_binary(&ComponentContractsResponse {
+                    enterprise_factory_contract: Addr::unchecked("enterprise_factory"),
+                    enterprise_versioning_contract: Addr::unchecked("enterprise_versioning"),
+                    enterprise_governance_contract: Addr::unchecked("governance"),
+                    enterprise_governance_controller_contract: Addr::unchecked(
+                        GOVERNANCE_CONTROLLER,
+                    ),
+                    enterprise_outposts_contract: Addr::unchecked("enterprise_outposts"),
+                    enterprise_treasury_contract: Addr::unchecked("treasury"),
+                    funds_distributor_contract: Addr::unchecked("funds_distributor"),
+                    membership_contract: Addr::unchecked("membership"),
+                    council_membership_contract: Addr::unchecked("council_membership"),
+                    attestation_contract: None,
+                }),
+                GOVERNANCE_CONTROLLER => match from_json(msg) {
+                    Ok(enterprise_governance_controller_api::msg::QueryMsg::ProposalStatus(_)) => {
+                        to_json_binary(
+                            &enterprise_governance_controller_api::api::ProposalStatusResponse {
+                                status: enterprise_governance_controller_api::api::ProposalStatus::Cancelled,
+                                expires: Expiration::Never {},
+                                results: Default::default(),
+                            },
+                        )
+                    }
+                    _ => Err(StdError::generic_err("unsupported governance controller query")),
+                },
+                _ => Err(StdError::generic_err("unsupported contract")),
+            };
+
+            SystemResult::Ok(ContractResult::from(response))
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: format!("{:?}", query),
+        }),
+    });
+
+    let response: ProposalStatusResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalStatus {
+                contract: Addr::unchecked(ENTERPRISE),
+                params: ProposalStatusParams { proposal_id: 1 },
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(response.status, ProposalStatus::Cancelled);
+}
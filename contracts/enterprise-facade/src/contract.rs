@@ -5,11 +5,11 @@ use cosmwasm_std::{
 };
 use cw2::set_contract_version;
 use enterprise_facade_api::api::{
-    AdapterResponse, AssetWhitelistResponse, ClaimsResponse, ComponentContractsResponse,
-    DaoInfoResponse, MemberInfoResponse, MemberVoteResponse, MultisigMembersResponse,
-    NftWhitelistResponse, ProposalResponse, ProposalStatusResponse, ProposalVotesResponse,
-    ProposalsResponse, StakedNftsResponse, TotalStakedAmountResponse, TreasuryAddressResponse,
-    UserStakeResponse, V2MigrationStageResponse,
+    AdapterResponse, AssetWhitelistResponse, BalancesResponse, ClaimsResponse,
+    ComponentContractsResponse, DaoInfoResponse, MemberInfoResponse, MemberVoteResponse,
+    MultisigMembersResponse, NftWhitelistResponse, ProposalResponse, ProposalStatusResponse,
+    ProposalVotesResponse, ProposalsResponse, StakedNftsResponse, TotalStakedAmountResponse,
+    TreasuryAddressResponse, UserStakeResponse, V2MigrationStageResponse,
 };
 use enterprise_facade_api::error::EnterpriseFacadeResult;
 use enterprise_facade_api::msg::QueryMsg::{
@@ -21,7 +21,7 @@ use enterprise_treasury_api::api::{
     HasIncompleteV2MigrationResponse, HasUnmovedStakesOrClaimsResponse,
 };
 use QueryMsg::{
-    AssetWhitelist, CastCouncilVoteAdapted, CastVoteAdapted, ClaimAdapted, Claims,
+    AssetWhitelist, Balances, CastCouncilVoteAdapted, CastVoteAdapted, ClaimAdapted, Claims,
     ComponentContracts, CreateCouncilProposalAdapted, CreateProposalAdapted,
     CreateProposalWithDenomDepositAdapted, CreateProposalWithNftDepositAdapted,
     CreateProposalWithTokenDepositAdapted, CrossChainTreasuries, DaoInfo, HasUnmovedStakesOrClaims,
@@ -151,6 +151,18 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> EnterpriseFacadeResult<Bin
             )?;
             to_json_binary(&response)?
         }
+        Balances { contract, params } => {
+            let facade = get_facade(deps, contract)?;
+
+            let response: BalancesResponse = deps.querier.query_wasm_smart(
+                facade.facade_address.to_string(),
+                &Balances {
+                    contract: facade.dao_address,
+                    params,
+                },
+            )?;
+            to_json_binary(&response)?
+        }
         Proposal { contract, params } => {
             let facade = get_facade(deps, contract)?;
 
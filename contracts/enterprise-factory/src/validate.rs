@@ -47,7 +47,7 @@ pub fn validate_unlocking_period(
     unlocking_period: Duration,
 ) -> DaoResult<()> {
     if let Duration::Time(unlocking_time) = unlocking_period {
-        if unlocking_time < dao_gov_config.vote_duration {
+        if unlocking_time < dao_gov_config.longest_vote_duration() {
             return Err(VoteDurationLongerThanUnstaking);
         }
     }
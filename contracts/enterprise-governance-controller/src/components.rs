@@ -0,0 +1,41 @@
+use crate::state::ENTERPRISE_CONTRACT;
+use cosmwasm_std::{Addr, Deps};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use enterprise_protocol::api::ComponentContractsResponse;
+use enterprise_protocol::msg::QueryMsg::ComponentContracts;
+
+pub fn query_enterprise_components(
+    deps: Deps,
+) -> GovernanceControllerResult<ComponentContractsResponse> {
+    let enterprise = ENTERPRISE_CONTRACT.load(deps.storage)?;
+
+    let response: ComponentContractsResponse = deps
+        .querier
+        .query_wasm_smart(enterprise.to_string(), &ComponentContracts {})?;
+
+    Ok(response)
+}
+
+pub fn query_enterprise_governance_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    Ok(query_enterprise_components(deps)?.enterprise_governance_contract)
+}
+
+pub fn query_enterprise_treasury_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    Ok(query_enterprise_components(deps)?.enterprise_treasury_contract)
+}
+
+pub fn query_main_dao_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    query_enterprise_treasury_addr(deps)
+}
+
+pub fn query_enterprise_outposts_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    Ok(query_enterprise_components(deps)?.enterprise_outposts_contract)
+}
+
+pub fn query_membership_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    Ok(query_enterprise_components(deps)?.membership_contract)
+}
+
+pub fn query_council_membership_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+    Ok(query_enterprise_components(deps)?.council_membership_contract)
+}
@@ -1,22 +1,42 @@
-use crate::proposals::{get_proposal_actions, set_proposal_executed, PROPOSAL_INFOS};
+use crate::components::{
+    query_council_membership_addr, query_enterprise_components, query_enterprise_governance_addr,
+    query_enterprise_outposts_addr, query_enterprise_treasury_addr, query_main_dao_addr,
+    query_membership_addr,
+};
+use crate::council_budgets::{
+    assert_within_council_budgets, council_spent_assets, record_council_spends, remaining_budget,
+};
+use crate::delegations::{
+    has_delegators, load_delegators, remove_delegate, remove_delegated_votes, set_delegate,
+    DELEGATED_VOTES, DELEGATES, MAXIMUM_DELEGATORS_PER_DELEGATE,
+};
+use crate::proposals::{
+    get_proposal_actions, has_non_snapshot_proposals_in_voting, load_proposal_amendments,
+    load_snapshot_proposals_in_voting, register_proposal_action_execution,
+    register_proposal_deposit_resolution, remove_proposal_in_voting, save_proposal_amendment,
+    save_proposal_in_voting, set_proposal_action_result, set_proposal_executed,
+    set_winning_proposal_option, take_proposal_action_execution, take_proposal_deposit_resolution,
+    PROPOSAL_INFOS,
+};
+use crate::simulation::{simulate_proposal_action, TreasurySpends};
 use crate::state::{
     ProposalBeingVotedOn, ProposalExecutabilityStatus, State, COUNCIL_GOV_CONFIG, CREATION_DATE,
     ENTERPRISE_CONTRACT, GOV_CONFIG, INITIAL_CROSS_CHAIN_TREASURIES, STATE,
 };
 use crate::validate::{
     apply_gov_config_changes, validate_dao_council, validate_dao_gov_config,
-    validate_modify_multisig_membership, validate_proposal_actions, validate_unlocking_period,
+    validate_modify_multisig_membership, validate_proposal_content, validate_unlocking_period,
     validate_upgrade_dao,
 };
 use common::commons::ModifyValue::Change;
 use common::cw::{Context, Pagination, QueryContext};
 use cosmwasm_std::{
-    entry_point, from_json, to_json_binary, wasm_execute, Addr, Binary, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult, Timestamp,
-    Uint128, Uint64,
+    coins, entry_point, from_json, to_json_binary, wasm_execute, Addr, BankMsg, Binary, Decimal,
+    Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult,
+    Timestamp, Uint128, Uint64,
 };
 use cw2::set_contract_version;
-use cw20::Cw20ReceiveMsg;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use cw721::Cw721ExecuteMsg::TransferNft;
 use cw721::Cw721QueryMsg::OwnerOf;
 use cw721::{Approval, OwnerOfResponse};
@@ -28,26 +48,41 @@ use denom_staking_api::msg::QueryMsg::DenomConfig;
 use enterprise_governance_api::msg::ExecuteMsg::UpdateVotes;
 use enterprise_governance_api::msg::QueryMsg::SimulateEndPollStatus;
 use enterprise_governance_controller_api::api::ProposalAction::{
-    DistributeFunds, ExecuteEnterpriseMsgs, ExecuteMsgs, ModifyMultisigMembership,
-    RequestFundingFromDao, UpdateAssetWhitelist, UpdateCouncil, UpdateGovConfig, UpdateMetadata,
-    UpdateMinimumWeightForRewards, UpdateNftWhitelist, UpgradeDao,
+    DistributeFunds, DistributeFundsTargeted, ExecuteEnterpriseMsgs, ExecuteMsgs,
+    ModifyMultisigMembership, RequestFundingFromDao, UpdateAssetWhitelist, UpdateCouncil,
+    UpdateGovConfig, UpdateMetadata, UpdateMinimumWeightForRewards, UpdateNftWhitelist, UpgradeDao,
 };
+use enterprise_governance_controller_api::api::ProposalActionResult::{Failed, Pending, Succeeded};
+use enterprise_governance_controller_api::api::ProposalExecutionMode::PartialFailureTolerant;
 use enterprise_governance_controller_api::api::ProposalType::{Council, General};
 use enterprise_governance_controller_api::api::{
-    CastVoteMsg, ConfigResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
-    DistributeFundsMsg, ExecuteEnterpriseMsgsMsg, ExecuteMsgsMsg, ExecuteProposalMsg,
-    ExecuteTreasuryMsgsMsg, GovConfig, GovConfigResponse, MemberVoteParams, MemberVoteResponse,
-    ModifyMultisigMembershipMsg, Proposal, ProposalAction, ProposalActionType, ProposalDeposit,
-    ProposalDepositAsset, ProposalId, ProposalInfo, ProposalParams, ProposalResponse,
-    ProposalStatus, ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse,
-    ProposalType, ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
-    RequestFundingFromDaoMsg, UpdateAssetWhitelistProposalActionMsg, UpdateCouncilMsg,
-    UpdateGovConfigMsg, UpdateMinimumWeightForRewardsMsg, UpdateNftWhitelistProposalActionMsg,
+    AmendProposalMsg, CancelPaymentScheduleProposalActionMsg, CancelProposalMsg,
+    CancelStreamProposalActionMsg, CastVoteMsg, CastWeightedVoteMsg, ConfigResponse,
+    CouncilGovConfig, CouncilRemainingBudget, CouncilSpendBudgetsResponse,
+    CreatePaymentScheduleProposalActionMsg, CreateProposalMsg, CreateProposalWithNftDepositMsg,
+    CreateStreamProposalActionMsg, DelegateMsg, DelegatedVotingPowerParams,
+    DelegatedVotingPowerResponse, DelegationParams, DelegationResponse, DelegatorsParams,
+    DelegatorsResponse, DistributeFundsMsg, DistributeFundsTargetedMsg, ExecuteEnterpriseMsgsMsg,
+    ExecuteMsgsMsg, ExecuteProposalActionMsg, ExecuteProposalMsg, ExecuteTreasuryMsgsMsg,
+    ExecutionTimelock, GovConfig, GovConfigResponse, MemberVoteParams, MemberVoteResponse,
+    ModifyMultisigMembershipMsg, Proposal, ProposalAction, ProposalActionDiagnostic,
+    ProposalActionType, ProposalAmendment, ProposalAmendmentsParams, ProposalAmendmentsResponse,
+    ProposalCosmosMsg, ProposalDeposit, ProposalDepositAsset, ProposalDepositOutcome, ProposalId,
+    ProposalInfo, ProposalOption, ProposalParams, ProposalResponse, ProposalStatus,
+    ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse, ProposalType,
+    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
+    RequestFundingFromDaoMsg, RetryProposalActionsMsg, SimulateProposalExecutionParams,
+    SimulateProposalExecutionResponse, TransferNftsProposalActionMsg,
+    UpdateAssetWhitelistProposalActionMsg, UpdateCouncilMsg, UpdateGovConfigMsg,
+    UpdateMinimumWeightForRewardsMsg, UpdateNftWhitelistProposalActionMsg, VetoProposalMsg,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerError::{
-    CustomError, DuplicateNftDeposit, InsufficientProposalDeposit, InvalidCosmosMessage,
-    InvalidDepositType, NoDaoCouncil, NoSuchProposal, NoVotesAvailable, NoVotingPower,
-    ProposalAlreadyExecuted, ProposalCannotBeExecutedYet, RestrictedUser, Std, Unauthorized,
+    CustomError, DuplicateNftDeposit, InsufficientProposalDeposit, InvalidArgument,
+    InvalidDepositType, NoDaoCouncil, NoDelegation, NoFailedProposalActions, NoSuchProposal,
+    NoVotesAvailable, NoVotingPower, NoVotingPowerToDelegate, ProposalActionsRetryWindowPassed,
+    ProposalAlreadyExecuted, ProposalCancelled, ProposalCannotBeCancelled,
+    ProposalCannotBeExecutedYet, ProposalCannotBeVetoed, ProposalExpired, ProposalInReview,
+    ProposalNotInReview, ProposalVetoed, RestrictedUser, SelfDelegation, Std, Unauthorized,
     UnsupportedCouncilProposalAction, UnsupportedOperationForDaoType, WrongProposalType,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerResult;
@@ -55,21 +90,25 @@ use enterprise_governance_controller_api::msg::{
     Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
 use enterprise_governance_controller_api::response::{
+    execute_amend_proposal_response, execute_cancel_proposal_response,
     execute_cast_council_vote_response, execute_cast_vote_response,
-    execute_create_council_proposal_response, execute_create_proposal_response,
-    execute_execute_proposal_response, execute_weights_changed_response, instantiate_response,
-    reply_create_poll_response,
+    execute_cast_weighted_vote_response, execute_create_council_proposal_response,
+    execute_create_proposal_response, execute_delegate_response, execute_execute_proposal_response,
+    execute_retry_proposal_actions_response, execute_undelegate_response,
+    execute_weights_changed_response, instantiate_response, reply_create_poll_response,
 };
 use enterprise_outposts_api::api::{
     DeployCrossChainTreasuryMsg, ExecuteCrossChainTreasuryMsg, RemoteTreasuryTarget,
 };
 use enterprise_protocol::api::{
-    ComponentContractsResponse, DaoInfoResponse, DaoType, IsRestrictedUserParams,
-    IsRestrictedUserResponse, UpdateMetadataMsg, UpgradeDaoMsg,
+    DaoInfoResponse, DaoType, IsRestrictedUserParams, IsRestrictedUserResponse, UpdateMetadataMsg,
+    UpgradeDaoMsg,
 };
-use enterprise_protocol::msg::QueryMsg::{ComponentContracts, DaoInfo, IsRestrictedUser};
+use enterprise_protocol::msg::QueryMsg::{DaoInfo, IsRestrictedUser};
 use enterprise_treasury_api::api::{
-    ExecuteCosmosMsgsMsg, SpendMsg, UpdateAssetWhitelistMsg, UpdateNftWhitelistMsg,
+    CancelPaymentScheduleMsg, CancelStreamMsg, CreatePaymentScheduleMsg, CreateStreamMsg,
+    ExecuteCosmosMsgsMsg, SpendMsg, TransferNftsMsg, UpdateAssetWhitelistMsg,
+    UpdateNftWhitelistMsg,
 };
 use enterprise_treasury_api::msg::ExecuteMsg::{ExecuteCosmosMsgs, Spend};
 use funds_distributor_api::api::{UpdateMinimumEligibleWeightMsg, UpdateUserWeightsMsg};
@@ -82,20 +121,24 @@ use multisig_membership_api::msg::ExecuteMsg::{SetMembers, UpdateMembers};
 use nft_staking_api::api::{NftConfigResponse, NftTokenId};
 use nft_staking_api::msg::QueryMsg::NftConfig;
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, Poll, PollId, PollParams, PollRejectionReason,
-    PollResponse, PollStatus, PollStatusFilter, PollStatusResponse, PollVoterParams,
-    PollVoterResponse, PollVotersParams, PollVotersResponse, PollsParams, PollsResponse,
-    UpdateVotesParams, VotingScheme,
+    CancelPollParams, CastVoteParams, CastWeightedVoteParams, CreatePollParams, EndPollParams,
+    Poll, PollId, PollParams, PollRejectionReason, PollResponse, PollStatus, PollStatusFilter,
+    PollStatusResponse, PollVoterParams, PollVoterResponse, PollVotersParams, PollVotersResponse,
+    PollsParams, PollsResponse, UpdatePollParams, UpdateVotesParams, VotingScheme,
 };
 use poll_engine_api::error::PollError::PollInProgress;
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::collections::HashSet;
+use std::slice;
 use token_staking_api::api::TokenConfigResponse;
 use token_staking_api::msg::QueryMsg::TokenConfig;
 use DaoType::{Denom, Multisig, Nft, Token};
 use Expiration::{AtHeight, AtTime};
 use PollRejectionReason::{IsRejectingOutcome, IsVetoOutcome, QuorumNotReached};
-use ProposalAction::{DeployCrossChainTreasury, ExecuteTreasuryMsgs};
+use ProposalAction::{
+    CancelPaymentSchedule, CancelProposal, CancelStream, CreatePaymentSchedule, CreateStream,
+    DeployCrossChainTreasury, ExecuteTreasuryMsgs, TransferNfts, VetoProposal,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:enterprise-governance-controller";
@@ -105,6 +148,13 @@ pub const CREATE_POLL_REPLY_ID: u64 = 1;
 pub const END_POLL_REPLY_ID: u64 = 2;
 pub const EXECUTE_PROPOSAL_ACTIONS_REPLY_ID: u64 = 3;
 pub const CAST_VOTE_REPLY_ID: u64 = 4;
+/// Reply IDs of separately executed proposal actions and of proposal deposit resolutions
+/// are allocated from this value onwards.
+pub const EXECUTE_PROPOSAL_ACTION_REPLY_ID_OFFSET: u64 = 1_000_000;
+
+/// Time (in seconds) after a proposal's execution during which its failed actions can be retried,
+/// used when the proposal type has no execution window configured.
+const DEFAULT_PROPOSAL_ACTIONS_RETRY_WINDOW: u64 = 7 * 24 * 60 * 60;
 
 const PROPOSAL_ACTIONS_EXECUTION_STATUS: &str = "status";
 
@@ -165,11 +215,18 @@ pub fn execute(
         ExecuteMsg::CreateProposalWithNftDeposit(msg) => create_proposal_with_nft_deposit(ctx, msg),
         ExecuteMsg::CreateCouncilProposal(msg) => create_council_proposal(ctx, msg),
         ExecuteMsg::CastVote(msg) => cast_vote(ctx, msg),
+        ExecuteMsg::CastWeightedVote(msg) => cast_weighted_vote(ctx, msg),
         ExecuteMsg::CastCouncilVote(msg) => cast_council_vote(ctx, msg),
+        ExecuteMsg::Delegate(msg) => delegate(ctx, msg),
+        ExecuteMsg::Undelegate {} => undelegate(ctx),
         ExecuteMsg::ExecuteProposal(msg) => execute_proposal(ctx, msg),
+        ExecuteMsg::CancelProposal(msg) => cancel_proposal(ctx, msg),
+        ExecuteMsg::AmendProposal(msg) => amend_proposal(ctx, msg),
+        ExecuteMsg::RetryProposalActions(msg) => retry_proposal_actions(ctx, msg),
         ExecuteMsg::Receive(msg) => receive_cw20(ctx, msg),
         ExecuteMsg::WeightsChanged(msg) => weights_changed(ctx, msg),
         ExecuteMsg::ExecuteProposalActions(msg) => execute_proposal_actions(ctx, msg),
+        ExecuteMsg::ExecuteProposalAction(msg) => execute_proposal_action(ctx, msg),
         ExecuteMsg::DeployInitialCrossChainTreasuries {} => {
             execute_deploy_initial_cross_chain_treasuries(ctx)
         }
@@ -345,15 +402,13 @@ fn create_proposal(
         deps: ctx.deps.as_ref(),
         env: ctx.env.clone(),
     };
-    let user_available_votes = get_user_available_votes(qctx, proposer.clone())?;
+    let user_available_votes = get_user_available_votes(qctx, proposer.clone(), Never {})?;
 
     assert_sufficient_deposit_or_member(&gov_config, &deposit, user_available_votes)?;
 
-    validate_proposal_actions(
-        ctx.deps.as_ref(),
-        query_dao_type(ctx.deps.as_ref())?,
-        &msg.proposal_actions,
-    )?;
+    validate_proposal_content(ctx.deps.as_ref(), query_dao_type(ctx.deps.as_ref())?, &msg)?;
+
+    let gov_config = apply_action_type_gov_params(gov_config, &msg);
 
     let create_poll_submsg = create_poll(ctx, gov_config, msg, deposit, General, proposer)?;
 
@@ -404,11 +459,7 @@ fn create_council_proposal(
     match dao_council {
         None => Err(NoDaoCouncil),
         Some(dao_council) => {
-            validate_proposal_actions(
-                ctx.deps.as_ref(),
-                query_dao_type(ctx.deps.as_ref())?,
-                &msg.proposal_actions,
-            )?;
+            validate_proposal_content(ctx.deps.as_ref(), query_dao_type(ctx.deps.as_ref())?, &msg)?;
 
             let member_weight = query_council_member_weight(
                 ctx.deps.as_ref(),
@@ -419,17 +470,12 @@ fn create_council_proposal(
                 return Err(Unauthorized);
             }
 
-            let allowed_actions = dao_council.allowed_proposal_action_types;
-
-            // validate that proposal actions are allowed
-            for proposal_action in &msg.proposal_actions {
-                let proposal_action_type = to_proposal_action_type(proposal_action);
-                if !allowed_actions.contains(&proposal_action_type) {
-                    return Err(UnsupportedCouncilProposalAction {
-                        action: proposal_action_type,
-                    });
-                }
-            }
+            validate_council_proposal_actions(
+                ctx.deps.as_ref(),
+                &dao_council,
+                &msg,
+                ctx.env.block.time,
+            )?;
 
             let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
 
@@ -458,6 +504,112 @@ fn create_council_proposal(
     }
 }
 
+/// Validates that the council is allowed to propose the proposal's actions,
+/// and that they stay within the council's spend budgets.
+fn validate_council_proposal_actions(
+    deps: Deps,
+    dao_council: &CouncilGovConfig,
+    msg: &CreateProposalMsg,
+    now: Timestamp,
+) -> GovernanceControllerResult<()> {
+    for proposal_action in msg.all_proposal_actions() {
+        let proposal_action_type = to_proposal_action_type(proposal_action);
+        if !dao_council
+            .allowed_proposal_action_types
+            .contains(&proposal_action_type)
+        {
+            return Err(UnsupportedCouncilProposalAction {
+                action: proposal_action_type,
+            });
+        }
+    }
+
+    let proposal_actions = msg.all_proposal_actions().cloned().collect::<Vec<_>>();
+    let spent_assets = council_spent_assets(deps, &proposal_actions)?;
+    assert_within_council_budgets(deps.storage, &dao_council.spend_budgets, &spent_assets, now)?;
+
+    Ok(())
+}
+
+/// Replaces the gov config's quorum, threshold, veto threshold and vote duration with the
+/// strictest of the parameters applicable to each of the proposal's actions.
+/// Actions whose type has no override are governed by the gov config's own parameters.
+fn apply_action_type_gov_params(gov_config: GovConfig, msg: &CreateProposalMsg) -> GovConfig {
+    let proposal_actions = msg
+        .proposal_actions
+        .iter()
+        .chain(
+            msg.options
+                .iter()
+                .flatten()
+                .flat_map(|option| option.proposal_actions.iter()),
+        )
+        .collect::<Vec<&ProposalAction>>();
+
+    let applicable_params = proposal_actions
+        .into_iter()
+        .map(|action| {
+            let action_type = to_proposal_action_type(action);
+            let params = gov_config
+                .action_type_gov_params
+                .iter()
+                .find(|params| params.action_type == action_type);
+
+            match params {
+                Some(params) => (
+                    params.quorum.unwrap_or(gov_config.quorum),
+                    params.threshold.unwrap_or(gov_config.threshold),
+                    params.veto_threshold.or(gov_config.veto_threshold),
+                    params.vote_duration.unwrap_or(gov_config.vote_duration),
+                ),
+                None => (
+                    gov_config.quorum,
+                    gov_config.threshold,
+                    gov_config.veto_threshold,
+                    gov_config.vote_duration,
+                ),
+            }
+        })
+        .collect::<Vec<(Decimal, Decimal, Option<Decimal>, u64)>>();
+
+    // proposals without actions are governed by the gov config's own parameters
+    if applicable_params.is_empty() {
+        return gov_config;
+    }
+
+    let mut quorum = Decimal::zero();
+    let mut threshold = Decimal::zero();
+    let mut vote_duration = 0u64;
+
+    for (action_quorum, action_threshold, _, action_vote_duration) in &applicable_params {
+        quorum = max(quorum, *action_quorum);
+        threshold = max(threshold, *action_threshold);
+        vote_duration = max(vote_duration, *action_vote_duration);
+    }
+
+    // a lower veto threshold makes the proposal easier to veto, so it is the stricter one.
+    // actions without a veto threshold are vetoed at their regular threshold.
+    let veto_threshold = if applicable_params
+        .iter()
+        .any(|(_, _, veto_threshold, _)| veto_threshold.is_some())
+    {
+        applicable_params
+            .iter()
+            .map(|(_, threshold, veto_threshold, _)| veto_threshold.unwrap_or(*threshold))
+            .min()
+    } else {
+        None
+    };
+
+    GovConfig {
+        quorum,
+        threshold,
+        veto_threshold,
+        vote_duration,
+        ..gov_config
+    }
+}
+
 fn to_proposal_action_type(proposal_action: &ProposalAction) -> ProposalActionType {
     match proposal_action {
         UpdateMetadata(_) => ProposalActionType::UpdateMetadata,
@@ -466,14 +618,22 @@ fn to_proposal_action_type(proposal_action: &ProposalAction) -> ProposalActionTy
         UpdateAssetWhitelist(_) => ProposalActionType::UpdateAssetWhitelist,
         UpdateNftWhitelist(_) => ProposalActionType::UpdateNftWhitelist,
         RequestFundingFromDao(_) => ProposalActionType::RequestFundingFromDao,
+        TransferNfts(_) => ProposalActionType::TransferNfts,
         UpgradeDao(_) => ProposalActionType::UpgradeDao,
         ExecuteMsgs(_) => ProposalActionType::ExecuteMsgs,
         ExecuteTreasuryMsgs(_) => ProposalActionType::ExecuteTreasuryMsgs,
         ExecuteEnterpriseMsgs(_) => ProposalActionType::ExecuteEnterpriseMsgs,
         ModifyMultisigMembership(_) => ProposalActionType::ModifyMultisigMembership,
         DistributeFunds(_) => ProposalActionType::DistributeFunds,
+        DistributeFundsTargeted(_) => ProposalActionType::DistributeFundsTargeted,
         UpdateMinimumWeightForRewards(_) => ProposalActionType::UpdateMinimumWeightForRewards,
         DeployCrossChainTreasury(_) => ProposalActionType::DeployCrossChainTreasury,
+        CancelProposal(_) => ProposalActionType::CancelProposal,
+        VetoProposal(_) => ProposalActionType::VetoProposal,
+        CreateStream(_) => ProposalActionType::CreateStream,
+        CancelStream(_) => ProposalActionType::CancelStream,
+        CreatePaymentSchedule(_) => ProposalActionType::CreatePaymentSchedule,
+        CancelPaymentSchedule(_) => ProposalActionType::CancelPaymentSchedule,
     }
 }
 
@@ -485,7 +645,28 @@ fn create_poll(
     proposal_type: ProposalType,
     proposer: Addr,
 ) -> GovernanceControllerResult<SubMsg> {
-    let ends_at = ctx.env.block.time.plus_seconds(gov_config.vote_duration);
+    // voting only starts once the review period is over
+    let review_ends_at = gov_config
+        .review_period
+        .map(|review_period| ctx.env.block.time.plus_seconds(review_period));
+
+    let ends_at = review_ends_at
+        .unwrap_or(ctx.env.block.time)
+        .plus_seconds(gov_config.vote_duration);
+
+    let voting_power_snapshot = if proposal_type == General && gov_config.snapshot_voting {
+        Some(ctx.env.block.time)
+    } else {
+        None
+    };
+
+    let (scheme, options) = match &msg.options {
+        None => (VotingScheme::CoinVoting, vec![]),
+        Some(options) => (
+            VotingScheme::MultipleChoice,
+            options.iter().map(|option| option.label.clone()).collect(),
+        ),
+    };
 
     let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
     let create_poll_submsg = SubMsg::reply_on_success(
@@ -496,11 +677,12 @@ fn create_poll(
                 deposit_amount: Uint128::zero(),
                 label: msg.title,
                 description: msg.description.unwrap_or_default(),
-                scheme: VotingScheme::CoinVoting,
+                scheme,
                 ends_at,
                 quorum: gov_config.quorum,
                 threshold: gov_config.threshold,
                 veto_threshold: gov_config.veto_threshold,
+                options,
             }),
             vec![],
         )?,
@@ -517,6 +699,14 @@ fn create_poll(
                 earliest_execution: None,
                 proposal_deposit: deposit,
                 proposal_actions: msg.proposal_actions,
+                voting_power_snapshot,
+                proposal_options: msg.options,
+                latest_execution: None,
+                vetoed_at: None,
+                execution_mode: msg.execution_mode.unwrap_or_default(),
+                action_results: vec![],
+                review_ends_at,
+                metadata: msg.metadata,
             }),
             ..state
         },
@@ -526,31 +716,80 @@ fn create_poll(
 }
 
 fn cast_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<Response> {
-    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+    let user_available_votes = load_general_proposal_voting_power(ctx, msg.proposal_id)?;
 
-    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
-    let user_available_votes = get_user_available_votes(qctx, ctx.info.sender.clone())?;
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
 
-    if user_available_votes == Uint128::zero() {
-        return Err(Unauthorized);
-    }
+    let delegated_votes_submsgs =
+        cast_delegated_votes_submsgs(ctx, msg.proposal_id, |voter, amount| {
+            enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
+                poll_id: msg.proposal_id.into(),
+                outcome: msg.outcome,
+                voter,
+                amount,
+            })
+        })?;
 
-    let proposal_info = PROPOSAL_INFOS
-        .may_load(ctx.deps.storage, msg.proposal_id)?
-        .ok_or(NoSuchProposal)?;
+    let cast_vote_submessage = SubMsg::reply_on_success(
+        wasm_execute(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
+                poll_id: msg.proposal_id.into(),
+                outcome: msg.outcome,
+                voter: ctx.info.sender.to_string(),
+                amount: user_available_votes,
+            }),
+            vec![],
+        )?,
+        CAST_VOTE_REPLY_ID,
+    );
 
-    if proposal_info.proposal_type != General {
-        return Err(WrongProposalType);
-    }
+    save_proposal_being_voted_on(ctx, msg.proposal_id)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_cast_vote_response(
+        dao_address.to_string(),
+        msg.proposal_id,
+        ctx.info.sender.to_string(),
+        msg.outcome,
+        user_available_votes,
+    )
+    .add_submessages(delegated_votes_submsgs)
+    .add_submessage(cast_vote_submessage))
+}
+
+fn cast_weighted_vote(
+    ctx: &mut Context,
+    msg: CastWeightedVoteMsg,
+) -> GovernanceControllerResult<Response> {
+    let user_available_votes = load_general_proposal_voting_power(ctx, msg.proposal_id)?;
 
     let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
 
+    let outcomes = msg
+        .outcomes
+        .iter()
+        .map(|choice| format!("{}:{}", choice.outcome, choice.weight))
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let delegated_votes_submsgs =
+        cast_delegated_votes_submsgs(ctx, msg.proposal_id, |voter, amount| {
+            enterprise_governance_api::msg::ExecuteMsg::CastWeightedVote(CastWeightedVoteParams {
+                poll_id: msg.proposal_id.into(),
+                outcomes: msg.outcomes.clone(),
+                voter,
+                amount,
+            })
+        })?;
+
     let cast_vote_submessage = SubMsg::reply_on_success(
         wasm_execute(
             governance_contract.to_string(),
-            &enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
+            &enterprise_governance_api::msg::ExecuteMsg::CastWeightedVote(CastWeightedVoteParams {
                 poll_id: msg.proposal_id.into(),
-                outcome: msg.outcome,
+                outcomes: msg.outcomes,
                 voter: ctx.info.sender.to_string(),
                 amount: user_available_votes,
             }),
@@ -559,33 +798,155 @@ fn cast_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<
         CAST_VOTE_REPLY_ID,
     );
 
-    let total_available_votes =
-        total_available_votes(ctx.deps.as_ref(), Never {}, proposal_info.proposal_type)?;
+    save_proposal_being_voted_on(ctx, msg.proposal_id)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_cast_weighted_vote_response(
+        dao_address.to_string(),
+        msg.proposal_id,
+        ctx.info.sender.to_string(),
+        outcomes,
+        user_available_votes,
+    )
+    .add_submessages(delegated_votes_submsgs)
+    .add_submessage(cast_vote_submessage))
+}
+
+/// Checks that the sender can vote on the given general proposal, and returns their own voting power.
+/// Users without voting power of their own can still vote if someone delegated to them.
+fn load_general_proposal_voting_power(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<Uint128> {
+    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.proposal_type != General {
+        return Err(WrongProposalType);
+    }
+
+    if proposal_info.is_in_review(ctx.env.block.time) {
+        return Err(ProposalInReview);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let user_available_votes = get_user_available_votes(
+        qctx,
+        ctx.info.sender.clone(),
+        proposal_info.voting_power_snapshot.map_or(Never {}, AtTime),
+    )?;
+
+    if user_available_votes == Uint128::zero()
+        && !has_delegators(ctx.deps.storage, ctx.info.sender.clone())
+    {
+        return Err(Unauthorized);
+    }
+
+    // the sender is voting directly, so delegates can no longer vote on their behalf
+    DELEGATED_VOTES.remove(ctx.deps.storage, (proposal_id, ctx.info.sender.clone()));
+
+    Ok(user_available_votes)
+}
+
+/// Creates submessages casting votes on behalf of the sender's delegators.
+/// Delegators that voted on the proposal themselves are skipped.
+///
+/// Delegated votes are cast as the delegators' own votes, so that the existing
+/// vote updates on weight changes keep them in line with the delegators' stake.
+fn cast_delegated_votes_submsgs(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+    cast_vote_msg: impl Fn(String, Uint128) -> enterprise_governance_api::msg::ExecuteMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let delegate = ctx.info.sender.clone();
+
+    let delegators = load_delegators(
+        ctx.deps.storage,
+        delegate.clone(),
+        None,
+        Some(MAXIMUM_DELEGATORS_PER_DELEGATE as usize),
+    )?;
+
+    if delegators.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let proposal_info = PROPOSAL_INFOS.load(ctx.deps.storage, proposal_id)?;
+    let expiration = proposal_info.voting_power_snapshot.map_or(Never {}, AtTime);
+
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+
+    let mut submsgs = vec![];
+
+    for delegator in delegators {
+        let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+
+        let voted_through_delegate = DELEGATED_VOTES
+            .may_load(ctx.deps.storage, (proposal_id, delegator.clone()))?
+            .is_some();
+
+        if !voted_through_delegate {
+            let existing_vote = query_member_vote(
+                qctx.clone(),
+                MemberVoteParams {
+                    member: delegator.to_string(),
+                    proposal_id,
+                },
+            )?
+            .vote;
+
+            if existing_vote.is_some() {
+                // delegator voted directly, their own vote takes precedence
+                continue;
+            }
+        }
+
+        let delegator_votes = get_user_available_votes(qctx, delegator.clone(), expiration)?;
+
+        DELEGATED_VOTES.save(
+            ctx.deps.storage,
+            (proposal_id, delegator.clone()),
+            &delegate,
+        )?;
+
+        submsgs.push(SubMsg::new(wasm_execute(
+            governance_contract.to_string(),
+            &cast_vote_msg(delegator.to_string(), delegator_votes),
+            vec![],
+        )?));
+    }
+
+    Ok(submsgs)
+}
+
+/// Remembers the proposal's current executability, so that the vote reply can tell
+/// whether the vote changed it.
+fn save_proposal_being_voted_on(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<()> {
+    let proposal_info = PROPOSAL_INFOS.load(ctx.deps.storage, proposal_id)?;
+
+    let total_available_votes = total_available_votes(ctx.deps.as_ref(), Never {}, &proposal_info)?;
 
     let end_proposal_status =
-        simulate_end_proposal_status(ctx.deps.as_ref(), msg.proposal_id, total_available_votes)?
-            .status;
+        simulate_end_proposal_status(ctx.deps.as_ref(), proposal_id, total_available_votes)?.status;
 
     STATE.update(ctx.deps.storage, |state| -> StdResult<State> {
         Ok(State {
             proposal_being_voted_on: Some(ProposalBeingVotedOn {
-                proposal_id: msg.proposal_id,
+                proposal_id,
                 executability_status: end_proposal_status.into(),
             }),
             ..state
         })
     })?;
 
-    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
-
-    Ok(execute_cast_vote_response(
-        dao_address.to_string(),
-        msg.proposal_id,
-        ctx.info.sender.to_string(),
-        msg.outcome,
-        user_available_votes,
-    )
-    .add_submessage(cast_vote_submessage))
+    Ok(())
 }
 
 fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<Response> {
@@ -613,6 +974,10 @@ fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControlle
                 return Err(WrongProposalType);
             }
 
+            if proposal_info.is_in_review(ctx.env.block.time) {
+                return Err(ProposalInReview);
+            }
+
             let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
 
             let cast_vote_submessage = SubMsg::new(wasm_execute(
@@ -640,6 +1005,53 @@ fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControlle
     }
 }
 
+fn delegate(ctx: &mut Context, msg: DelegateMsg) -> GovernanceControllerResult<Response> {
+    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+
+    let dao_type = query_dao_type(ctx.deps.as_ref())?;
+
+    if dao_type == Multisig {
+        return Err(UnsupportedOperationForDaoType {
+            dao_type: dao_type.to_string(),
+        });
+    }
+
+    let delegate = ctx.deps.api.addr_validate(&msg.delegate)?;
+
+    if delegate == ctx.info.sender {
+        return Err(SelfDelegation);
+    }
+
+    // delegations cost the delegate gas every time they vote, so only members can delegate
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    if get_user_available_votes(qctx, ctx.info.sender.clone(), Never {})?.is_zero() {
+        return Err(NoVotingPowerToDelegate);
+    }
+
+    set_delegate(ctx.deps.storage, ctx.info.sender.clone(), delegate.clone())?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_delegate_response(
+        dao_address.to_string(),
+        ctx.info.sender.to_string(),
+        delegate.to_string(),
+    ))
+}
+
+fn undelegate(ctx: &mut Context) -> GovernanceControllerResult<Response> {
+    let delegate =
+        remove_delegate(ctx.deps.storage, ctx.info.sender.clone())?.ok_or(NoDelegation)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_undelegate_response(
+        dao_address.to_string(),
+        ctx.info.sender.to_string(),
+        delegate.to_string(),
+    ))
+}
+
 fn execute_proposal(
     ctx: &mut Context,
     msg: ExecuteProposalMsg,
@@ -654,13 +1066,31 @@ fn execute_proposal(
         return Err(ProposalAlreadyExecuted);
     }
 
+    if proposal_info.vetoed_at.is_some() {
+        return Err(ProposalVetoed);
+    }
+
     if let Some(earliest_execution) = proposal_info.earliest_execution {
         if ctx.env.block.time < earliest_execution {
             return Err(ProposalCannotBeExecutedYet);
         }
     }
 
-    let submsgs = end_proposal(ctx, &msg, proposal_info.proposal_type.clone())?;
+    if proposal_info.is_past_latest_execution(ctx.env.block.time) {
+        return Err(ProposalExpired);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll_status = query_poll_status(&qctx, msg.proposal_id)?.status;
+
+    let submsgs = match poll_status {
+        // the poll already ended, and the proposal was queued until its execution delay passed
+        PollStatus::Passed { .. } => {
+            set_proposal_executed(ctx.deps.storage, msg.proposal_id, ctx.env.block.clone())?;
+            vec![execute_proposal_actions_submsg(ctx, msg.proposal_id)?]
+        }
+        _ => end_proposal(ctx, &msg, &proposal_info)?,
+    };
 
     let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
 
@@ -672,31 +1102,345 @@ fn execute_proposal(
     .add_submessages(submsgs))
 }
 
-fn return_proposal_deposit_submsgs(
-    deps: DepsMut,
-    proposal_id: ProposalId,
-) -> GovernanceControllerResult<Vec<SubMsg>> {
+fn cancel_proposal(
+    ctx: &mut Context,
+    msg: CancelProposalMsg,
+) -> GovernanceControllerResult<Response> {
+    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+
     let proposal_info = PROPOSAL_INFOS
-        .may_load(deps.storage, proposal_id)?
+        .may_load(ctx.deps.storage, msg.proposal_id)?
         .ok_or(NoSuchProposal)?;
 
-    if let Some(deposit) = proposal_info.proposal_deposit {
-        send_proposal_deposit_to(deposit.asset, deposit.depositor)
-    } else {
-        Ok(vec![])
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    if ctx.info.sender != poll.proposer {
+        return Err(Unauthorized);
     }
-}
 
-fn send_proposal_deposit_to(
-    deposit_asset: ProposalDepositAsset,
-    recipient: Addr,
-) -> GovernanceControllerResult<Vec<SubMsg>> {
-    let transfer_msgs = match deposit_asset {
-        ProposalDepositAsset::Denom { denom, amount } => {
-            vec![SubMsg::new(
-                Asset::native(denom, amount).transfer_msg(recipient)?,
-            )]
-        }
+    let has_votes = poll.results.values().any(|count| *count > 0);
+
+    if has_votes {
+        let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+
+        let is_before_cutoff = gov_config
+            .proposal_cancellation_cutoff
+            .map(|cutoff| ctx.env.block.time < poll.started_at.plus_seconds(cutoff))
+            .unwrap_or(false);
+
+        if !is_before_cutoff {
+            return Err(ProposalCannotBeCancelled);
+        }
+    }
+
+    let submsgs = cancel_proposal_submsgs(ctx, msg.proposal_id)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_cancel_proposal_response(
+        dao_address.to_string(),
+        msg.proposal_id,
+        proposal_info.proposal_type,
+    )
+    .add_submessages(submsgs))
+}
+
+/// Replaces the content of a proposal that is still in review, keeping the previous content
+/// in the proposal's amendment history. Only the proposer can amend their proposal, and the
+/// amended actions have to be governed by the same parameters the poll was created with.
+fn amend_proposal(
+    ctx: &mut Context,
+    msg: AmendProposalMsg,
+) -> GovernanceControllerResult<Response> {
+    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    if ctx.info.sender != poll.proposer {
+        return Err(Unauthorized);
+    }
+
+    if !proposal_info.is_in_review(ctx.env.block.time) {
+        return Err(ProposalNotInReview);
+    }
+
+    let previous_content = CreateProposalMsg {
+        title: poll.label.clone(),
+        description: Some(poll.description.clone()),
+        proposal_actions: proposal_info.proposal_actions.clone(),
+        deposit_owner: None,
+        options: proposal_info.proposal_options.clone(),
+        execution_mode: Some(proposal_info.execution_mode.clone()),
+        metadata: proposal_info.metadata.clone(),
+    };
+
+    let amended_content = CreateProposalMsg {
+        title: msg.title.clone().unwrap_or_else(|| poll.label.clone()),
+        description: msg
+            .description
+            .clone()
+            .or(previous_content.description.clone()),
+        proposal_actions: msg
+            .proposal_actions
+            .clone()
+            .unwrap_or_else(|| proposal_info.proposal_actions.clone()),
+        ..previous_content.clone()
+    };
+
+    validate_proposal_content(
+        ctx.deps.as_ref(),
+        query_dao_type(ctx.deps.as_ref())?,
+        &amended_content,
+    )?;
+
+    match proposal_info.proposal_type {
+        General => {
+            // the poll's voting parameters were fixed when it was created
+            let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+
+            if apply_action_type_gov_params(gov_config.clone(), &previous_content)
+                != apply_action_type_gov_params(gov_config, &amended_content)
+            {
+                return Err(InvalidArgument {
+                    msg: "Amended proposal actions require different governance parameters than the proposal was created with".to_string(),
+                });
+            }
+        }
+        Council => {
+            let dao_council = COUNCIL_GOV_CONFIG
+                .load(ctx.deps.storage)?
+                .ok_or(NoDaoCouncil)?;
+
+            validate_council_proposal_actions(
+                ctx.deps.as_ref(),
+                &dao_council,
+                &amended_content,
+                ctx.env.block.time,
+            )?;
+        }
+    }
+
+    let amendment_id =
+        save_proposal_amendment(ctx.deps.storage, msg.proposal_id, |amendment_id| {
+            ProposalAmendment {
+                amendment_id,
+                amended_at: ctx.env.block.time,
+                title: poll.label,
+                description: poll.description,
+                proposal_actions: proposal_info.proposal_actions.clone(),
+            }
+        })?;
+
+    PROPOSAL_INFOS.save(
+        ctx.deps.storage,
+        msg.proposal_id,
+        &ProposalInfo {
+            proposal_actions: amended_content.proposal_actions,
+            ..proposal_info
+        },
+    )?;
+
+    let mut submsgs = vec![];
+
+    if msg.title.is_some() || msg.description.is_some() {
+        let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+
+        submsgs.push(SubMsg::new(wasm_execute(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::ExecuteMsg::UpdatePoll(UpdatePollParams {
+                poll_id: msg.proposal_id.into(),
+                label: msg.title,
+                description: msg.description,
+            }),
+            vec![],
+        )?));
+    }
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(
+        execute_amend_proposal_response(dao_address.to_string(), msg.proposal_id, amendment_id)
+            .add_submessages(submsgs),
+    )
+}
+
+/// Cancels the proposal's poll and resolves its deposit, without checking who is cancelling.
+/// Used directly by proposals cancelling other proposals.
+fn cancel_proposal_submsgs(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.executed_at.is_some() {
+        return Err(ProposalAlreadyExecuted);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, proposal_id)?.poll;
+
+    match poll.status {
+        PollStatus::InProgress { ends_at } if ctx.env.block.time < ends_at => {}
+        PollStatus::Cancelled {} => return Err(ProposalCancelled),
+        _ => return Err(ProposalCannotBeCancelled),
+    }
+
+    remove_proposal_in_voting(ctx.deps.storage, proposal_id);
+    remove_delegated_votes(ctx.deps.storage, proposal_id)?;
+
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+
+    let cancel_poll_submsg = SubMsg::new(wasm_execute(
+        governance_contract.to_string(),
+        &enterprise_governance_api::msg::ExecuteMsg::CancelPoll(CancelPollParams {
+            poll_id: proposal_id.into(),
+        }),
+        vec![],
+    )?);
+
+    let deposit_outcome = GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .proposal_deposit_outcomes
+        .cancelled;
+
+    let deposit_submsgs =
+        resolve_proposal_deposit_submsgs(ctx.deps.branch(), proposal_id, &deposit_outcome)?;
+
+    let mut submsgs = vec![cancel_poll_submsg];
+    submsgs.extend(deposit_submsgs);
+
+    Ok(submsgs)
+}
+
+/// Refunds, burns, confiscates or distributes the proposal's deposit, depending on the outcome.
+/// Burning and distributing can fail for reasons outside of the DAO's control, so they are
+/// done in submessages that send the deposit to the treasury if they fail, instead of
+/// preventing the proposal from being resolved.
+fn resolve_proposal_deposit_submsgs(
+    deps: DepsMut,
+    proposal_id: ProposalId,
+    outcome: &ProposalDepositOutcome,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let deposit = match proposal_info.proposal_deposit {
+        Some(deposit) => deposit,
+        None => return Ok(vec![]),
+    };
+
+    match (outcome, deposit.asset) {
+        (ProposalDepositOutcome::Refund, asset) => {
+            send_proposal_deposit_to(asset, deposit.depositor)
+        }
+        (ProposalDepositOutcome::Burn, ProposalDepositAsset::Denom { denom, amount }) => {
+            let burn_msg = BankMsg::Burn {
+                amount: coins(amount.u128(), denom),
+            };
+            let reply_id = register_proposal_deposit_resolution(deps.storage, proposal_id)?;
+            Ok(vec![SubMsg::reply_always(burn_msg, reply_id)])
+        }
+        (ProposalDepositOutcome::Burn, ProposalDepositAsset::Cw20 { token_addr, amount }) => {
+            let burn_msg = wasm_execute(
+                token_addr.to_string(),
+                &Cw20ExecuteMsg::Burn { amount },
+                vec![],
+            )?;
+            let reply_id = register_proposal_deposit_resolution(deps.storage, proposal_id)?;
+            Ok(vec![SubMsg::reply_always(burn_msg, reply_id)])
+        }
+        (
+            ProposalDepositOutcome::DistributeToMembers,
+            ProposalDepositAsset::Denom { denom, amount },
+        ) => {
+            let funds_distributor =
+                query_enterprise_components(deps.as_ref())?.funds_distributor_contract;
+            let distribute_msg = wasm_execute(
+                funds_distributor.to_string(),
+                &funds_distributor_api::msg::ExecuteMsg::DistributeNative {},
+                coins(amount.u128(), denom),
+            )?;
+            let reply_id = register_proposal_deposit_resolution(deps.storage, proposal_id)?;
+            Ok(vec![SubMsg::reply_always(distribute_msg, reply_id)])
+        }
+        (
+            ProposalDepositOutcome::DistributeToMembers,
+            ProposalDepositAsset::Cw20 { token_addr, amount },
+        ) => {
+            let funds_distributor =
+                query_enterprise_components(deps.as_ref())?.funds_distributor_contract;
+            let distribute_msg = wasm_execute(
+                token_addr.to_string(),
+                &Cw20ExecuteMsg::Send {
+                    contract: funds_distributor.to_string(),
+                    amount,
+                    msg: to_json_binary(&funds_distributor_api::msg::Cw20HookMsg::Distribute {})?,
+                },
+                vec![],
+            )?;
+            let reply_id = register_proposal_deposit_resolution(deps.storage, proposal_id)?;
+            Ok(vec![SubMsg::reply_always(distribute_msg, reply_id)])
+        }
+        // NFTs can't be burned or distributed, so they go to the treasury instead
+        (
+            ProposalDepositOutcome::SendToTreasury
+            | ProposalDepositOutcome::Burn
+            | ProposalDepositOutcome::DistributeToMembers,
+            asset,
+        ) => {
+            let treasury_contract = query_enterprise_treasury_addr(deps.as_ref())?;
+            send_proposal_deposit_to(asset, treasury_contract)
+        }
+    }
+}
+
+/// Handles the reply of a submessage burning or distributing a proposal's deposit.
+/// If it failed, sends the deposit to the treasury instead.
+fn reply_resolve_proposal_deposit(
+    deps: DepsMut,
+    proposal_id: ProposalId,
+    result: SubMsgResult,
+) -> GovernanceControllerResult<Response> {
+    let response = Response::new()
+        .add_attribute("action", "resolve_proposal_deposit")
+        .add_attribute("proposal_id", proposal_id.to_string());
+
+    match result {
+        SubMsgResult::Ok(_) => Ok(response),
+        SubMsgResult::Err(_) => {
+            let deposit = PROPOSAL_INFOS
+                .load(deps.storage, proposal_id)?
+                .proposal_deposit
+                .ok_or(NoSuchProposal)?;
+
+            let treasury_contract = query_enterprise_treasury_addr(deps.as_ref())?;
+
+            Ok(response
+                .add_attribute("fallback", "send_to_treasury")
+                .add_submessages(send_proposal_deposit_to(deposit.asset, treasury_contract)?))
+        }
+    }
+}
+
+fn send_proposal_deposit_to(
+    deposit_asset: ProposalDepositAsset,
+    recipient: Addr,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let transfer_msgs = match deposit_asset {
+        ProposalDepositAsset::Denom { denom, amount } => {
+            vec![SubMsg::new(
+                Asset::native(denom, amount).transfer_msg(recipient)?,
+            )]
+        }
         ProposalDepositAsset::Cw20 { token_addr, amount } => {
             vec![SubMsg::new(
                 Asset::cw20(token_addr, amount).transfer_msg(recipient)?,
@@ -724,17 +1468,35 @@ fn send_proposal_deposit_to(
 fn end_proposal(
     ctx: &mut Context,
     msg: &ExecuteProposalMsg,
-    proposal_type: ProposalType,
+    proposal_info: &ProposalInfo,
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
     let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
     let poll = query_poll(&qctx, msg.proposal_id)?.poll;
 
+    if poll.status == (PollStatus::Cancelled {}) {
+        return Err(ProposalCancelled);
+    }
+
     let ends_at = poll.ends_at;
 
+    if ends_at <= ctx.env.block.time {
+        let status = determine_final_status_of_ended_poll(
+            ctx.deps.as_ref(),
+            ends_at,
+            ctx.env.block.time,
+            msg.proposal_id,
+            proposal_info,
+        )?;
+
+        if status == ProposalStatus::Expired {
+            return Err(ProposalExpired);
+        }
+    }
+
     let total_available_votes = if ends_at <= ctx.env.block.time {
-        total_available_votes(ctx.deps.as_ref(), AtTime(ends_at), proposal_type.clone())?
+        total_available_votes(ctx.deps.as_ref(), AtTime(ends_at), proposal_info)?
     } else {
-        total_available_votes(ctx.deps.as_ref(), Never {}, proposal_type.clone())?
+        total_available_votes(ctx.deps.as_ref(), Never {}, proposal_info)?
     };
 
     if total_available_votes == Uint128::zero() {
@@ -749,7 +1511,10 @@ fn end_proposal(
                 poll_id: msg.proposal_id.into(),
                 maximum_available_votes: total_available_votes,
                 error_if_already_ended: false,
-                allow_early_ending: allows_early_ending(ctx.deps.as_ref(), &proposal_type)?,
+                allow_early_ending: allows_early_ending(
+                    ctx.deps.as_ref(),
+                    &proposal_info.proposal_type,
+                )?,
             }),
             vec![],
         )?,
@@ -797,22 +1562,66 @@ fn resolve_ended_proposal(
             }
             .into());
         }
-        PollStatus::Passed { .. } => {
-            set_proposal_executed(ctx.deps.storage, proposal_id, ctx.env.block.clone())?;
-            let execute_proposal_actions_msg = SubMsg::reply_always(
-                wasm_execute(
-                    ctx.env.contract.address.to_string(),
-                    &ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
-                    vec![],
-                )?,
-                EXECUTE_PROPOSAL_ACTIONS_REPLY_ID,
+        PollStatus::Passed { outcome, .. } => {
+            set_winning_proposal_option(ctx.deps.storage, proposal_id, outcome)?;
+
+            let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+            let mut submsgs = resolve_proposal_deposit_submsgs(
+                ctx.deps.branch(),
+                proposal_id,
+                &gov_config.proposal_deposit_outcomes.passed,
+            )?;
+
+            let proposal_info = PROPOSAL_INFOS
+                .may_load(ctx.deps.storage, proposal_id)?
+                .ok_or(NoSuchProposal)?;
+
+            let now = ctx.env.block.time;
+
+            // polls that end early keep their original end time
+            let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+            let ends_at = query_poll(&qctx, proposal_id)?.poll.ends_at;
+            let voting_ended_at = min(ends_at, now);
+
+            if is_too_late_to_queue(
+                &gov_config,
+                &proposal_info.proposal_type,
+                voting_ended_at,
+                now,
+            ) {
+                return Err(ProposalExpired);
+            }
+
+            // the execution delay counts from the moment the proposal is queued, and not from
+            // when its voting ended, so that the council always gets the whole delay to veto it.
+            // the execution window, however, counts from the end of voting, so that a proposal
+            // nobody queues does not stay executable forever
+            let (earliest_execution, latest_execution) = execution_timelock_bounds(
+                &gov_config,
+                &proposal_info.proposal_type,
+                voting_ended_at,
+                now,
             );
-            let mut submsgs = return_proposal_deposit_submsgs(ctx.deps.branch(), proposal_id)?;
 
-            submsgs.insert(0, execute_proposal_actions_msg);
+            if earliest_execution > now {
+                // the proposal is queued until its execution delay passes
+                PROPOSAL_INFOS.save(
+                    ctx.deps.storage,
+                    proposal_id,
+                    &ProposalInfo {
+                        earliest_execution: Some(earliest_execution),
+                        latest_execution,
+                        ..proposal_info
+                    },
+                )?;
+            } else {
+                set_proposal_executed(ctx.deps.storage, proposal_id, ctx.env.block.clone())?;
+                submsgs.insert(0, execute_proposal_actions_submsg(ctx, proposal_id)?);
+            }
 
             submsgs
         }
+        PollStatus::Cancelled {} => return Err(ProposalCancelled),
         PollStatus::Rejected { reason } => {
             set_proposal_executed(ctx.deps.storage, proposal_id, ctx.env.block.clone())?;
 
@@ -821,20 +1630,21 @@ fn resolve_ended_proposal(
                 .ok_or(NoSuchProposal)?;
 
             match proposal_info.proposal_type {
-                General => match reason {
-                    QuorumNotReached | IsVetoOutcome => {
-                        if let Some(deposit) = proposal_info.proposal_deposit {
-                            // confiscate the deposit by sending it to treasury
-                            let treasury_contract =
-                                query_enterprise_treasury_addr(ctx.deps.as_ref())?;
-                            send_proposal_deposit_to(deposit.asset, treasury_contract)?
-                        } else {
-                            vec![]
-                        }
-                    }
-                    // return deposits only if quorum reached and not vetoed
-                    _ => return_proposal_deposit_submsgs(ctx.deps.branch(), proposal_id)?,
-                },
+                General => {
+                    let outcomes = GOV_CONFIG.load(ctx.deps.storage)?.proposal_deposit_outcomes;
+
+                    let deposit_outcome = match reason {
+                        QuorumNotReached => outcomes.quorum_not_reached,
+                        IsVetoOutcome => outcomes.vetoed,
+                        _ => outcomes.rejected,
+                    };
+
+                    resolve_proposal_deposit_submsgs(
+                        ctx.deps.branch(),
+                        proposal_id,
+                        &deposit_outcome,
+                    )?
+                }
                 Council => vec![],
             }
         }
@@ -843,6 +1653,110 @@ fn resolve_ended_proposal(
     Ok(submsgs)
 }
 
+/// Calculates the earliest and latest execution time of a proposal whose voting ended at
+/// the given time, and that is queued at the given time.
+fn execution_timelock_bounds(
+    gov_config: &GovConfig,
+    proposal_type: &ProposalType,
+    voting_ended_at: Timestamp,
+    queued_at: Timestamp,
+) -> (Timestamp, Option<Timestamp>) {
+    let timelock = execution_timelock(gov_config, proposal_type);
+
+    let earliest_execution = queued_at.plus_seconds(timelock.execution_delay);
+    let latest_execution = latest_execution(timelock, voting_ended_at);
+
+    (earliest_execution, latest_execution)
+}
+
+/// Latest time a passed proposal can be executed, counting its execution delay and window
+/// from the end of its voting.
+/// Proposals without an execution window, or with one too long to represent, never expire.
+fn latest_execution(timelock: &ExecutionTimelock, voting_ended_at: Timestamp) -> Option<Timestamp> {
+    let window = timelock.execution_window?;
+
+    timelock
+        .execution_delay
+        .checked_add(window)
+        .and_then(|seconds| seconds.checked_mul(1_000_000_000))
+        .and_then(|nanos| voting_ended_at.nanos().checked_add(nanos))
+        .map(Timestamp::from_nanos)
+}
+
+/// A passed proposal that was not queued yet cannot be queued anymore once its execution delay,
+/// counted from now, would end past its latest execution.
+fn is_too_late_to_queue(
+    gov_config: &GovConfig,
+    proposal_type: &ProposalType,
+    voting_ended_at: Timestamp,
+    now: Timestamp,
+) -> bool {
+    let timelock = execution_timelock(gov_config, proposal_type);
+
+    match latest_execution(timelock, voting_ended_at) {
+        Some(latest_execution) => {
+            let earliest_execution = timelock
+                .execution_delay
+                .checked_mul(1_000_000_000)
+                .and_then(|nanos| now.nanos().checked_add(nanos));
+
+            match earliest_execution {
+                Some(earliest_execution) => earliest_execution > latest_execution.nanos(),
+                None => true,
+            }
+        }
+        None => false,
+    }
+}
+
+fn execution_timelock<'a>(
+    gov_config: &'a GovConfig,
+    proposal_type: &ProposalType,
+) -> &'a ExecutionTimelock {
+    match proposal_type {
+        General => &gov_config.general_execution_timelock,
+        Council => &gov_config.council_execution_timelock,
+    }
+}
+
+/// Failed actions can be retried within the proposal type's execution window,
+/// counted from the moment the proposal was executed.
+fn is_past_proposal_actions_retry_window(
+    gov_config: &GovConfig,
+    proposal_info: &ProposalInfo,
+    now: Timestamp,
+) -> bool {
+    match &proposal_info.executed_at {
+        Some(executed_at) => {
+            let retry_window = execution_timelock(gov_config, &proposal_info.proposal_type)
+                .execution_window
+                .unwrap_or(DEFAULT_PROPOSAL_ACTIONS_RETRY_WINDOW);
+
+            executed_at
+                .time
+                .seconds()
+                .checked_add(retry_window)
+                .map(|retry_deadline| now.seconds() > retry_deadline)
+                .unwrap_or(false)
+        }
+        None => false,
+    }
+}
+
+fn execute_proposal_actions_submsg(
+    ctx: &Context,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<SubMsg> {
+    Ok(SubMsg::reply_always(
+        wasm_execute(
+            ctx.env.contract.address.to_string(),
+            &ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
+            vec![],
+        )?,
+        EXECUTE_PROPOSAL_ACTIONS_REPLY_ID,
+    ))
+}
+
 fn execute_proposal_actions(
     ctx: &mut Context,
     msg: ExecuteProposalMsg,
@@ -867,31 +1781,227 @@ fn execute_proposal_actions_submsgs(
     let proposal_actions =
         get_proposal_actions(ctx.deps.storage, proposal_id)?.ok_or(NoSuchProposal)?;
 
-    let mut submsgs: Vec<SubMsg> = vec![];
+    let proposal_info = PROPOSAL_INFOS.load(ctx.deps.storage, proposal_id)?;
+
+    if proposal_info.execution_mode == PartialFailureTolerant {
+        let action_indices = (0..proposal_actions.len() as u32).collect();
+        return execute_proposal_actions_separately_submsgs(ctx, proposal_id, action_indices);
+    }
+
+    spend_council_budgets(ctx, &proposal_info, &proposal_actions)?;
+
+    let mut submsgs: Vec<SubMsg> = vec![];
+
+    for proposal_action in proposal_actions {
+        submsgs.append(&mut proposal_action_submsgs(ctx, proposal_action)?)
+    }
+
+    Ok(submsgs)
+}
+
+/// Executes each of the given proposal actions in a submessage of its own,
+/// so that a failing action does not revert the others.
+/// The result of each action is recorded in the proposal once its submessage replies.
+fn execute_proposal_actions_separately_submsgs(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+    action_indices: Vec<u32>,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let mut submsgs: Vec<SubMsg> = vec![];
+
+    for action_index in action_indices {
+        set_proposal_action_result(ctx.deps.storage, proposal_id, action_index, Pending)?;
+
+        let reply_id =
+            register_proposal_action_execution(ctx.deps.storage, proposal_id, action_index)?;
+
+        submsgs.push(SubMsg::reply_always(
+            wasm_execute(
+                ctx.env.contract.address.to_string(),
+                &ExecuteMsg::ExecuteProposalAction(ExecuteProposalActionMsg {
+                    proposal_id,
+                    action_index,
+                }),
+                vec![],
+            )?,
+            reply_id,
+        ));
+    }
+
+    Ok(submsgs)
+}
+
+fn execute_proposal_action(
+    ctx: &mut Context,
+    msg: ExecuteProposalActionMsg,
+) -> GovernanceControllerResult<Response> {
+    // only this contract itself can execute this
+    if ctx.info.sender != ctx.env.contract.address {
+        return Err(Unauthorized);
+    }
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let proposal_action = proposal_info
+        .proposal_actions
+        .get(msg.action_index as usize)
+        .cloned()
+        .ok_or(InvalidArgument {
+            msg: format!("Proposal has no action {}", msg.action_index),
+        })?;
+
+    spend_council_budgets(ctx, &proposal_info, slice::from_ref(&proposal_action))?;
+
+    let submsgs = proposal_action_submsgs(ctx, proposal_action)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_proposal_action")
+        .add_attribute("proposal_id", msg.proposal_id.to_string())
+        .add_attribute("action_index", msg.action_index.to_string())
+        .add_submessages(submsgs))
+}
+
+fn retry_proposal_actions(
+    ctx: &mut Context,
+    msg: RetryProposalActionsMsg,
+) -> GovernanceControllerResult<Response> {
+    unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    // only proposals executed in the partial-failure tolerant mode can have failed actions
+    let failed_action_indices = proposal_info.failed_action_indices();
+
+    if failed_action_indices.is_empty() {
+        return Err(NoFailedProposalActions);
+    }
+
+    let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+
+    if is_past_proposal_actions_retry_window(&gov_config, &proposal_info, ctx.env.block.time) {
+        return Err(ProposalActionsRetryWindowPassed);
+    }
+
+    let retried_actions = failed_action_indices.len();
+
+    let submsgs =
+        execute_proposal_actions_separately_submsgs(ctx, msg.proposal_id, failed_action_indices)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_retry_proposal_actions_response(
+        dao_address.to_string(),
+        msg.proposal_id,
+        retried_actions,
+    )
+    .add_submessages(submsgs))
+}
+
+/// Council proposals have to stay within the council's budgets at the moment of execution too.
+/// Records the spends of the given actions towards the budgets. No-op for general proposals.
+fn spend_council_budgets(
+    ctx: &mut Context,
+    proposal_info: &ProposalInfo,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<()> {
+    if proposal_info.proposal_type == Council {
+        if let Some(dao_council) = COUNCIL_GOV_CONFIG.load(ctx.deps.storage)? {
+            let spent_assets = council_spent_assets(ctx.deps.as_ref(), proposal_actions)?;
+            let now = ctx.env.block.time;
+
+            assert_within_council_budgets(
+                ctx.deps.storage,
+                &dao_council.spend_budgets,
+                &spent_assets,
+                now,
+            )?;
+            record_council_spends(
+                ctx.deps.storage,
+                &dao_council.spend_budgets,
+                &spent_assets,
+                now,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn proposal_action_submsgs(
+    ctx: &mut Context,
+    proposal_action: ProposalAction,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsgs = match proposal_action {
+        UpdateMetadata(msg) => update_metadata(ctx.deps.branch(), msg)?,
+        UpdateGovConfig(msg) => update_gov_config(ctx, msg)?,
+        UpdateCouncil(msg) => update_council(ctx, msg)?,
+        RequestFundingFromDao(msg) => execute_funding_from_dao(ctx.deps.branch(), msg)?,
+        TransferNfts(msg) => transfer_nfts(ctx.deps.branch(), msg)?,
+        UpdateAssetWhitelist(msg) => update_asset_whitelist(ctx.deps.branch(), msg)?,
+        UpdateNftWhitelist(msg) => update_nft_whitelist(ctx.deps.branch(), msg)?,
+        UpgradeDao(msg) => upgrade_dao(ctx, msg)?,
+        ExecuteMsgs(msg) => execute_msgs(msg)?,
+        ExecuteTreasuryMsgs(msg) => execute_treasury_msgs(ctx, msg)?,
+        ExecuteEnterpriseMsgs(msg) => execute_enterprise_msgs(ctx, msg)?,
+        ModifyMultisigMembership(msg) => {
+            modify_multisig_membership(ctx.deps.branch(), ctx.env.clone(), msg)?
+        }
+        DistributeFunds(msg) => distribute_funds(ctx, msg)?,
+        DistributeFundsTargeted(msg) => distribute_funds_targeted(ctx, msg)?,
+        UpdateMinimumWeightForRewards(msg) => update_minimum_weight_for_rewards(ctx, msg)?,
+        DeployCrossChainTreasury(msg) => deploy_cross_chain_treasury(ctx, msg)?,
+        CancelProposal(msg) => cancel_proposal_submsgs(ctx, msg.proposal_id)?,
+        VetoProposal(msg) => veto_proposal(ctx, msg)?,
+        CreateStream(msg) => create_stream(ctx.deps.branch(), msg)?,
+        CancelStream(msg) => cancel_stream(ctx.deps.branch(), msg)?,
+        CreatePaymentSchedule(msg) => create_payment_schedule(ctx.deps.branch(), msg)?,
+        CancelPaymentSchedule(msg) => cancel_payment_schedule(ctx.deps.branch(), msg)?,
+    };
+
+    Ok(submsgs)
+}
+
+fn veto_proposal(
+    ctx: &mut Context,
+    msg: VetoProposalMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.executed_at.is_some() {
+        return Err(ProposalAlreadyExecuted);
+    }
+
+    if proposal_info.vetoed_at.is_some() {
+        return Err(ProposalVetoed);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll_status = query_poll_status(&qctx, msg.proposal_id)?.status;
+
+    // only proposals that passed and are waiting for their execution delay to pass are vetoable
+    let is_queued = matches!(poll_status, PollStatus::Passed { .. })
+        && !proposal_info.is_past_earliest_execution(ctx.env.block.time);
 
-    for proposal_action in proposal_actions {
-        let mut actions = match proposal_action {
-            UpdateMetadata(msg) => update_metadata(ctx.deps.branch(), msg)?,
-            UpdateGovConfig(msg) => update_gov_config(ctx, msg)?,
-            UpdateCouncil(msg) => update_council(ctx, msg)?,
-            RequestFundingFromDao(msg) => execute_funding_from_dao(ctx.deps.branch(), msg)?,
-            UpdateAssetWhitelist(msg) => update_asset_whitelist(ctx.deps.branch(), msg)?,
-            UpdateNftWhitelist(msg) => update_nft_whitelist(ctx.deps.branch(), msg)?,
-            UpgradeDao(msg) => upgrade_dao(ctx, msg)?,
-            ExecuteMsgs(msg) => execute_msgs(msg)?,
-            ExecuteTreasuryMsgs(msg) => execute_treasury_msgs(ctx, msg)?,
-            ExecuteEnterpriseMsgs(msg) => execute_enterprise_msgs(ctx, msg)?,
-            ModifyMultisigMembership(msg) => {
-                modify_multisig_membership(ctx.deps.branch(), ctx.env.clone(), msg)?
-            }
-            DistributeFunds(msg) => distribute_funds(ctx, msg)?,
-            UpdateMinimumWeightForRewards(msg) => update_minimum_weight_for_rewards(ctx, msg)?,
-            DeployCrossChainTreasury(msg) => deploy_cross_chain_treasury(ctx, msg)?,
-        };
-        submsgs.append(&mut actions)
+    if !is_queued {
+        return Err(ProposalCannotBeVetoed);
     }
 
-    Ok(submsgs)
+    PROPOSAL_INFOS.save(
+        ctx.deps.storage,
+        msg.proposal_id,
+        &ProposalInfo {
+            vetoed_at: Some(ctx.env.block.clone()),
+            ..proposal_info
+        },
+    )?;
+
+    Ok(vec![])
 }
 
 fn update_metadata(
@@ -925,6 +2035,92 @@ fn execute_funding_from_dao(
     Ok(vec![submsg])
 }
 
+fn transfer_nfts(
+    deps: DepsMut,
+    msg: TransferNftsProposalActionMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsg = execute_treasury_msg(
+        deps,
+        enterprise_treasury_api::msg::ExecuteMsg::TransferNfts(TransferNftsMsg {
+            nft_contract: msg.nft_contract,
+            token_ids: msg.token_ids,
+            recipient: msg.recipient,
+            msg: msg.msg,
+        }),
+        msg.remote_treasury_target,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn create_stream(
+    deps: DepsMut,
+    msg: CreateStreamProposalActionMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsg = execute_treasury_msg(
+        deps,
+        enterprise_treasury_api::msg::ExecuteMsg::CreateStream(CreateStreamMsg {
+            recipient: msg.recipient,
+            asset: msg.asset,
+            start_time: msg.start_time,
+            duration: msg.duration,
+            cliff: msg.cliff,
+        }),
+        msg.remote_treasury_target,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn cancel_stream(
+    deps: DepsMut,
+    msg: CancelStreamProposalActionMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsg = execute_treasury_msg(
+        deps,
+        enterprise_treasury_api::msg::ExecuteMsg::CancelStream(CancelStreamMsg {
+            stream_id: msg.stream_id,
+        }),
+        msg.remote_treasury_target,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn create_payment_schedule(
+    deps: DepsMut,
+    msg: CreatePaymentScheduleProposalActionMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsg = execute_treasury_msg(
+        deps,
+        enterprise_treasury_api::msg::ExecuteMsg::CreatePaymentSchedule(CreatePaymentScheduleMsg {
+            recipient: msg.recipient,
+            assets: msg.assets,
+            start_time: msg.start_time,
+            interval: msg.interval,
+            number_of_payments: msg.number_of_payments,
+        }),
+        msg.remote_treasury_target,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn cancel_payment_schedule(
+    deps: DepsMut,
+    msg: CancelPaymentScheduleProposalActionMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsg = execute_treasury_msg(
+        deps,
+        enterprise_treasury_api::msg::ExecuteMsg::CancelPaymentSchedule(CancelPaymentScheduleMsg {
+            schedule_id: msg.schedule_id,
+        }),
+        msg.remote_treasury_target,
+    )?;
+
+    Ok(vec![submsg])
+}
+
 fn update_gov_config(
     ctx: &mut Context,
     msg: UpdateGovConfigMsg,
@@ -1091,10 +2287,7 @@ fn upgrade_dao(ctx: &mut Context, msg: UpgradeDaoMsg) -> GovernanceControllerRes
 fn execute_msgs(msg: ExecuteMsgsMsg) -> GovernanceControllerResult<Vec<SubMsg>> {
     let mut submsgs: Vec<SubMsg> = vec![];
     for msg in msg.msgs {
-        submsgs.push(SubMsg::new(
-            serde_json_wasm::from_str::<CosmosMsg>(msg.as_str())
-                .map_err(|_| InvalidCosmosMessage)?,
-        ))
+        submsgs.push(SubMsg::new(msg.decode()?))
     }
     Ok(submsgs)
 }
@@ -1105,7 +2298,9 @@ fn execute_treasury_msgs(
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
     let submsg = execute_treasury_msg(
         ctx.deps.branch(),
-        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg { msgs: msg.msgs }),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: to_json_strings(&msg.msgs)?,
+        }),
         msg.remote_treasury_target,
     )?;
 
@@ -1121,7 +2316,9 @@ fn execute_enterprise_msgs(
     let submsg = SubMsg::new(wasm_execute(
         enterprise_contract.to_string(),
         &enterprise_protocol::msg::ExecuteMsg::ExecuteMsgs(
-            enterprise_protocol::api::ExecuteMsgsMsg { msgs: msg.msgs },
+            enterprise_protocol::api::ExecuteMsgsMsg {
+                msgs: to_json_strings(&msg.msgs)?,
+            },
         ),
         vec![],
     )?);
@@ -1129,6 +2326,10 @@ fn execute_enterprise_msgs(
     Ok(vec![submsg])
 }
 
+fn to_json_strings(msgs: &[ProposalCosmosMsg]) -> StdResult<Vec<String>> {
+    msgs.iter().map(ProposalCosmosMsg::to_json_string).collect()
+}
+
 fn modify_multisig_membership(
     deps: DepsMut,
     _env: Env,
@@ -1165,6 +2366,32 @@ fn distribute_funds(
                 funds_distributor_contract: enterprise_components
                     .funds_distributor_contract
                     .to_string(),
+                stream_duration: msg.stream_duration,
+            },
+        ),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn distribute_funds_targeted(
+    ctx: &mut Context,
+    msg: DistributeFundsTargetedMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let enterprise_components = query_enterprise_components(ctx.deps.as_ref())?;
+
+    let submsg = SubMsg::new(wasm_execute(
+        enterprise_components
+            .enterprise_treasury_contract
+            .to_string(),
+        &enterprise_treasury_api::msg::ExecuteMsg::DistributeFundsTargeted(
+            enterprise_treasury_api::api::DistributeFundsTargetedMsg {
+                asset: msg.asset,
+                allocations: msg.allocations,
+                funds_distributor_contract: enterprise_components
+                    .funds_distributor_contract
+                    .to_string(),
             },
         ),
         vec![],
@@ -1306,7 +2533,22 @@ pub fn weights_changed(
         return Err(Unauthorized);
     }
 
-    let update_votes_submsgs = update_user_votes(ctx.deps.as_ref(), &msg.weight_changes)?;
+    let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+
+    let update_votes_submsgs = if gov_config.snapshot_voting
+        && !has_non_snapshot_proposals_in_voting(ctx.deps.storage, ctx.env.block.time)?
+    {
+        // all the proposals in voting count votes by their snapshots, so there is nothing to update
+        vec![]
+    } else {
+        // proposals with a voting power snapshot keep the votes as they were cast, regardless of
+        // whether snapshot voting is still enabled in the gov config.
+        // votes cast by delegates are stored as delegators' own, so they get updated here too
+        let snapshot_proposals =
+            load_snapshot_proposals_in_voting(ctx.deps.storage, ctx.env.block.time)?;
+
+        update_user_votes(ctx.deps.as_ref(), &msg.weight_changes, snapshot_proposals)?
+    };
 
     let new_user_weights = msg
         .weight_changes
@@ -1337,7 +2579,10 @@ pub fn weights_changed(
 pub fn update_user_votes(
     deps: Deps,
     user_weight_changes: &Vec<UserWeightChange>,
+    excluded_proposals: Vec<ProposalId>,
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let excluded_polls: Vec<Uint64> = excluded_proposals.into_iter().map(Uint64::from).collect();
+
     let governance_contract = query_enterprise_governance_addr(deps)?;
 
     let mut update_votes_submsgs: Vec<SubMsg> = vec![];
@@ -1348,6 +2593,7 @@ pub fn update_user_votes(
             &UpdateVotes(UpdateVotesParams {
                 voter: user_weight_change.user.clone(),
                 new_amount: user_weight_change.new_weight,
+                excluded_polls: excluded_polls.clone(),
             }),
             vec![],
         )?));
@@ -1378,6 +2624,16 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
 
             PROPOSAL_INFOS.save(deps.storage, poll_id, &proposal_info)?;
 
+            let qctx = QueryContext::from(deps.as_ref(), env);
+            let voting_ends_at = query_poll(&qctx, poll_id)?.poll.ends_at;
+
+            save_proposal_in_voting(
+                deps.storage,
+                poll_id,
+                voting_ends_at,
+                proposal_info.voting_power_snapshot.is_some(),
+            )?;
+
             Ok(reply_create_poll_response(poll_id))
         }
         END_POLL_REPLY_ID => {
@@ -1401,6 +2657,9 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
                 },
             )?;
 
+            // voting has ended, delegated votes no longer need to be told apart from direct ones
+            remove_delegated_votes(ctx.deps.storage, proposal_id)?;
+
             let execute_submsgs = resolve_ended_proposal(ctx, proposal_id)?;
 
             Ok(Response::new().add_submessages(execute_submsgs))
@@ -1443,11 +2702,8 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
                     return Ok(Response::new());
                 }
 
-                let total_available_votes = total_available_votes(
-                    deps.as_ref(),
-                    Never {},
-                    proposal_info.proposal_type.clone(),
-                )?;
+                let total_available_votes =
+                    total_available_votes(deps.as_ref(), Never {}, &proposal_info)?;
 
                 let end_proposal_status = simulate_end_proposal_status(
                     deps.as_ref(),
@@ -1498,6 +2754,37 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
             }
             Ok(response)
         }
+        id if id >= EXECUTE_PROPOSAL_ACTION_REPLY_ID_OFFSET => {
+            if let Some(proposal_id) = take_proposal_deposit_resolution(deps.storage, id)? {
+                return reply_resolve_proposal_deposit(deps, proposal_id, msg.result);
+            }
+
+            let (proposal_id, action_index) = take_proposal_action_execution(deps.storage, id)?;
+
+            let mut response = Response::new()
+                .add_attribute("action", "execute_proposal_action")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("action_index", action_index.to_string());
+
+            let result = match msg.result {
+                SubMsgResult::Ok(_) => {
+                    response = response.add_attribute(PROPOSAL_ACTIONS_EXECUTION_STATUS, "success");
+                    Succeeded
+                }
+                // the chain only passes the error's codespace and code on to the reply,
+                // so that is all we can record about why the action failed
+                SubMsgResult::Err(error) => {
+                    response = response
+                        .add_attribute(PROPOSAL_ACTIONS_EXECUTION_STATUS, "failure")
+                        .add_attribute("execution_error", error.clone());
+                    Failed { error }
+                }
+            };
+
+            set_proposal_action_result(deps.storage, proposal_id, action_index, result)?;
+
+            Ok(response)
+        }
         _ => Err(Std(StdError::generic_err("No such reply ID found"))),
     }
 }
@@ -1549,6 +2836,18 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> GovernanceControllerResult<
         QueryMsg::ProposalStatus(params) => to_json_binary(&query_proposal_status(qctx, params)?)?,
         QueryMsg::MemberVote(params) => to_json_binary(&query_member_vote(qctx, params)?)?,
         QueryMsg::ProposalVotes(params) => to_json_binary(&query_proposal_votes(qctx, params)?)?,
+        QueryMsg::Delegation(params) => to_json_binary(&query_delegation(qctx, params)?)?,
+        QueryMsg::Delegators(params) => to_json_binary(&query_delegators(qctx, params)?)?,
+        QueryMsg::DelegatedVotingPower(params) => {
+            to_json_binary(&query_delegated_voting_power(qctx, params)?)?
+        }
+        QueryMsg::CouncilSpendBudgets {} => to_json_binary(&query_council_spend_budgets(qctx)?)?,
+        QueryMsg::SimulateProposalExecution(params) => {
+            to_json_binary(&query_simulate_proposal_execution(qctx, params)?)?
+        }
+        QueryMsg::ProposalAmendments(params) => {
+            to_json_binary(&query_proposal_amendments(qctx, params)?)?
+        }
     };
     Ok(response)
 }
@@ -1604,45 +2903,74 @@ pub fn query_proposals(
 ) -> GovernanceControllerResult<ProposalsResponse> {
     let governance_contract = query_enterprise_governance_addr(qctx.deps)?;
 
-    let polls: PollsResponse = qctx.deps.querier.query_wasm_smart(
-        governance_contract.to_string(),
-        &enterprise_governance_api::msg::QueryMsg::Polls(PollsParams {
-            filter: msg.filter.map(|filter| match filter {
-                ProposalStatusFilter::InProgress => PollStatusFilter::InProgress,
-                ProposalStatusFilter::Passed => PollStatusFilter::Passed,
-                ProposalStatusFilter::Rejected => PollStatusFilter::Rejected,
+    let limit = msg
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as u64, |limit| limit as u64)
+        .min(MAX_QUERY_LIMIT as u64);
+
+    let mut proposals: Vec<ProposalResponse> = vec![];
+    let mut start_after = msg.start_after;
+
+    if limit == 0 {
+        return Ok(ProposalsResponse { proposals });
+    }
+
+    loop {
+        let polls: PollsResponse = qctx.deps.querier.query_wasm_smart(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::QueryMsg::Polls(PollsParams {
+                filter: msg.filter.clone().map(|filter| match filter {
+                    ProposalStatusFilter::InProgress => PollStatusFilter::InProgress,
+                    ProposalStatusFilter::Passed => PollStatusFilter::Passed,
+                    ProposalStatusFilter::Rejected => PollStatusFilter::Rejected,
+                    ProposalStatusFilter::Cancelled => PollStatusFilter::Cancelled,
+                }),
+                pagination: Pagination {
+                    start_after: start_after.map(Uint64::from),
+                    end_at: None,
+                    limit: Some(limit),
+                    order_by: None,
+                },
             }),
-            pagination: Pagination {
-                start_after: msg.start_after.map(Uint64::from),
-                end_at: None,
-                limit: Some(
-                    msg.limit
-                        .map_or(DEFAULT_QUERY_LIMIT as u64, |limit| limit as u64)
-                        .min(MAX_QUERY_LIMIT as u64),
-                ),
-                order_by: None,
-            },
-        }),
-    )?;
+        )?;
 
-    let proposals = polls
-        .polls
-        .into_iter()
-        .filter_map(|poll| {
+        let is_last_page = (polls.polls.len() as u64) < limit;
+        start_after = polls.polls.last().map(|poll| poll.id);
+
+        for poll in polls.polls {
             let proposal_response = poll_to_proposal_response(qctx.deps, &qctx.env, &poll);
             // filthy hack: we do not store whether a poll is of type General or Council
             // we listed all polls in poll-engine, but only when we try to add remaining data
             // contained in this contract can we know what their type is and exclude them from
             // the results if they're not of the requested type
-            if let Err(NoSuchProposal) = proposal_response {
-                None
-            } else {
-                Some(proposal_response)
+            match proposal_response {
+                Err(NoSuchProposal) => {}
+                Ok(response) if !has_tag(&response.proposal, msg.tag.as_ref()) => {}
+                _ => proposals.push(proposal_response?),
             }
-        })
-        .collect::<GovernanceControllerResult<Vec<ProposalResponse>>>()?;
 
-    Ok(ProposalsResponse { proposals })
+            if proposals.len() as u64 == limit {
+                return Ok(ProposalsResponse { proposals });
+            }
+        }
+
+        // without a tag, a single page of polls is listed, as it has always been
+        if msg.tag.is_none() || is_last_page {
+            return Ok(ProposalsResponse { proposals });
+        }
+    }
+}
+
+/// Whether the proposal's metadata contains the given tag. Always true if no tag is given.
+fn has_tag(proposal: &Proposal, tag: Option<&String>) -> bool {
+    match tag {
+        None => true,
+        Some(tag) => proposal
+            .metadata
+            .as_ref()
+            .map(|metadata| metadata.tags.contains(tag))
+            .unwrap_or(false),
+    }
 }
 
 pub fn query_proposal_status(
@@ -1683,6 +3011,13 @@ fn query_poll_status(
     Ok(poll_status_response)
 }
 
+fn decode_proposal_actions(proposal_actions: Vec<ProposalAction>) -> Vec<ProposalAction> {
+    proposal_actions
+        .into_iter()
+        .map(ProposalAction::with_decoded_msgs)
+        .collect()
+}
+
 fn poll_to_proposal_response(
     deps: Deps,
     env: &Env,
@@ -1712,10 +3047,23 @@ fn poll_to_proposal_response(
         status: status.clone(),
         started_at: poll.started_at,
         expires: AtTime(poll.ends_at),
-        proposal_actions: proposal_info.proposal_actions,
+        proposal_actions: decode_proposal_actions(proposal_info.proposal_actions.clone()),
+        proposal_options: proposal_info.proposal_options.clone().map(|options| {
+            options
+                .into_iter()
+                .map(|option| ProposalOption {
+                    proposal_actions: decode_proposal_actions(option.proposal_actions),
+                    ..option
+                })
+                .collect()
+        }),
+        execution_mode: proposal_info.execution_mode.clone(),
+        action_results: proposal_info.action_results.clone(),
+        review_ends_at: proposal_info.review_ends_at,
+        metadata: proposal_info.metadata.clone(),
     };
 
-    let expiration = match proposal_info.executed_at {
+    let expiration = match &proposal_info.executed_at {
         Some(executed_block) => match proposal.expires {
             AtHeight(height) => AtHeight(min(height, executed_block.height)),
             AtTime(time) => AtTime(min(time, executed_block.time)),
@@ -1740,8 +3088,7 @@ fn poll_to_proposal_response(
         },
     };
 
-    let total_votes_available =
-        total_available_votes(deps, expiration, proposal_info.proposal_type)?;
+    let total_votes_available = total_available_votes(deps, expiration, &proposal_info)?;
 
     Ok(ProposalResponse {
         proposal,
@@ -1763,6 +3110,8 @@ fn fix_poll_status(
 ) -> GovernanceControllerResult<ProposalStatus> {
     let status = if proposal_info.executed_at.is_some() {
         ProposalStatus::Executed
+    } else if proposal_info.vetoed_at.is_some() {
+        ProposalStatus::Vetoed
     } else {
         match poll_status {
             PollStatus::InProgress { ends_at } => {
@@ -1772,8 +3121,9 @@ fn fix_poll_status(
                     determine_final_status_of_ended_poll(
                         deps,
                         ends_at,
+                        now,
                         poll_id,
-                        proposal_info.proposal_type.clone(),
+                        proposal_info,
                     )?
                 } else {
                     // poll still in progress
@@ -1787,15 +3137,12 @@ fn fix_poll_status(
                         let status = simulate_end_proposal_status(
                             deps,
                             poll_id,
-                            total_available_votes(
-                                deps,
-                                Never {},
-                                proposal_info.proposal_type.clone(),
-                            )?,
+                            total_available_votes(deps, Never {}, proposal_info)?,
                         )?;
                         match status.status {
                             PollStatus::InProgress { .. } => ProposalStatus::InProgress,
                             PollStatus::Passed { .. } => ProposalStatus::InProgressCanExecuteEarly,
+                            PollStatus::Cancelled {} => ProposalStatus::Cancelled,
                             PollStatus::Rejected { reason } => match reason {
                                 IsRejectingOutcome | IsVetoOutcome => {
                                     ProposalStatus::InProgressCanExecuteEarly
@@ -1808,8 +3155,15 @@ fn fix_poll_status(
                     }
                 }
             }
-            PollStatus::Passed { .. } => ProposalStatus::Passed,
+            PollStatus::Passed { .. } => {
+                if proposal_info.is_past_latest_execution(now) {
+                    ProposalStatus::Expired
+                } else {
+                    ProposalStatus::Passed
+                }
+            }
             PollStatus::Rejected { .. } => ProposalStatus::Rejected,
+            PollStatus::Cancelled {} => ProposalStatus::Cancelled,
         }
     };
     Ok(status)
@@ -1818,10 +3172,11 @@ fn fix_poll_status(
 fn determine_final_status_of_ended_poll(
     deps: Deps,
     ended_at: Timestamp,
+    now: Timestamp,
     poll_id: PollId,
-    proposal_type: ProposalType,
+    proposal_info: &ProposalInfo,
 ) -> GovernanceControllerResult<ProposalStatus> {
-    let available_votes = total_available_votes(deps, AtTime(ended_at), proposal_type)?;
+    let available_votes = total_available_votes(deps, AtTime(ended_at), proposal_info)?;
     let status = simulate_end_proposal_status(deps, poll_id, available_votes)?;
 
     match status.status {
@@ -1829,17 +3184,33 @@ fn determine_final_status_of_ended_poll(
             // should be impossible scenario
             Err(StdError::generic_err("internal error simulating proposal's current status").into())
         }
-        PollStatus::Passed { .. } => Ok(ProposalStatus::Passed),
+        // not queued yet, but its execution window counts from the end of voting
+        PollStatus::Passed { .. } => {
+            let gov_config = GOV_CONFIG.load(deps.storage)?;
+
+            if is_too_late_to_queue(&gov_config, &proposal_info.proposal_type, ended_at, now) {
+                Ok(ProposalStatus::Expired)
+            } else {
+                Ok(ProposalStatus::Passed)
+            }
+        }
         PollStatus::Rejected { .. } => Ok(ProposalStatus::Rejected),
+        PollStatus::Cancelled {} => Ok(ProposalStatus::Cancelled),
     }
 }
 
+/// Total votes available in the given proposal at the given moment.
+/// Proposals with a voting power snapshot always use the total weight at the snapshot moment.
 fn total_available_votes(
     deps: Deps,
     expiration: Expiration,
-    proposal_type: ProposalType,
+    proposal_info: &ProposalInfo,
 ) -> GovernanceControllerResult<Uint128> {
-    match proposal_type {
+    let expiration = proposal_info
+        .voting_power_snapshot
+        .map_or(expiration, AtTime);
+
+    match proposal_info.proposal_type {
         General => general_total_available_votes(deps, expiration),
         Council => query_council_total_weight(deps, expiration),
     }
@@ -1920,13 +3291,205 @@ pub fn query_proposal_votes(
     })
 }
 
-fn get_user_available_votes(qctx: QueryContext, user: Addr) -> GovernanceControllerResult<Uint128> {
+pub fn query_delegation(
+    qctx: QueryContext,
+    params: DelegationParams,
+) -> GovernanceControllerResult<DelegationResponse> {
+    let delegator = qctx.deps.api.addr_validate(&params.delegator)?;
+
+    let delegate = DELEGATES.may_load(qctx.deps.storage, delegator)?;
+
+    Ok(DelegationResponse { delegate })
+}
+
+pub fn query_delegators(
+    qctx: QueryContext,
+    params: DelegatorsParams,
+) -> GovernanceControllerResult<DelegatorsResponse> {
+    let delegate = qctx.deps.api.addr_validate(&params.delegate)?;
+    let start_after = params
+        .start_after
+        .map(|start_after| qctx.deps.api.addr_validate(&start_after))
+        .transpose()?;
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32);
+
+    let delegators = load_delegators(
+        qctx.deps.storage,
+        delegate,
+        start_after,
+        Some(limit as usize),
+    )?;
+
+    Ok(DelegatorsResponse { delegators })
+}
+
+pub fn query_delegated_voting_power(
+    qctx: QueryContext,
+    params: DelegatedVotingPowerParams,
+) -> GovernanceControllerResult<DelegatedVotingPowerResponse> {
+    let delegate = qctx.deps.api.addr_validate(&params.delegate)?;
+
+    let delegators = load_delegators(
+        qctx.deps.storage,
+        delegate,
+        None,
+        Some(MAXIMUM_DELEGATORS_PER_DELEGATE as usize),
+    )?;
+
+    let mut delegated_voting_power = Uint128::zero();
+
+    for delegator in delegators {
+        delegated_voting_power += get_user_available_votes(qctx.clone(), delegator, Never {})?;
+    }
+
+    Ok(DelegatedVotingPowerResponse {
+        delegated_voting_power,
+    })
+}
+
+pub fn query_council_spend_budgets(
+    qctx: QueryContext,
+) -> GovernanceControllerResult<CouncilSpendBudgetsResponse> {
+    let budgets = COUNCIL_GOV_CONFIG
+        .load(qctx.deps.storage)?
+        .and_then(|dao_council| dao_council.spend_budgets)
+        .unwrap_or_default()
+        .iter()
+        .map(|budget| remaining_budget(qctx.deps.storage, budget, qctx.env.block.time))
+        .collect::<StdResult<Vec<CouncilRemainingBudget>>>()?;
+
+    Ok(CouncilSpendBudgetsResponse { budgets })
+}
+
+pub fn query_proposal_amendments(
+    qctx: QueryContext,
+    params: ProposalAmendmentsParams,
+) -> GovernanceControllerResult<ProposalAmendmentsResponse> {
+    if !PROPOSAL_INFOS.has(qctx.deps.storage, params.proposal_id) {
+        return Err(NoSuchProposal);
+    }
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32);
+
+    let amendments = load_proposal_amendments(
+        qctx.deps.storage,
+        params.proposal_id,
+        params.start_after,
+        limit as usize,
+    )?;
+
+    Ok(ProposalAmendmentsResponse { amendments })
+}
+
+pub fn query_simulate_proposal_execution(
+    qctx: QueryContext,
+    params: SimulateProposalExecutionParams,
+) -> GovernanceControllerResult<SimulateProposalExecutionResponse> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(qctx.deps.storage, params.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let proposal_actions = match (params.option, &proposal_info.proposal_options) {
+        (None, None) => proposal_info.proposal_actions.clone(),
+        // a multiple-choice proposal only gets its actions once its winning option is resolved,
+        // so we look the winning option up from the poll instead
+        (None, Some(options)) => match query_poll_status(&qctx, params.proposal_id)?.status {
+            PollStatus::Passed { outcome, .. } => options
+                .get(outcome as usize)
+                .map(|option| option.proposal_actions.clone())
+                .unwrap_or_default(),
+            _ => {
+                return Err(InvalidArgument {
+                    msg: "Proposal has no winning option yet, specify an option to simulate"
+                        .to_string(),
+                })
+            }
+        },
+        (Some(option), Some(options)) => options
+            .get(option as usize)
+            .map(|option| option.proposal_actions.clone())
+            .ok_or(InvalidArgument {
+                msg: format!("Proposal has no option {}", option),
+            })?,
+        (Some(_), None) => {
+            return Err(InvalidArgument {
+                msg: "Only multiple-choice proposals have options".to_string(),
+            })
+        }
+    };
+
+    // council proposals have to stay within the council's budgets at the moment of execution
+    let proposal_error = match proposal_info.proposal_type {
+        Council => match COUNCIL_GOV_CONFIG.load(qctx.deps.storage)? {
+            Some(dao_council) => council_spent_assets(qctx.deps, &proposal_actions)
+                .and_then(|spent_assets| {
+                    assert_within_council_budgets(
+                        qctx.deps.storage,
+                        &dao_council.spend_budgets,
+                        &spent_assets,
+                        qctx.env.block.time,
+                    )
+                })
+                .err()
+                .map(|e| e.to_string()),
+            None => None,
+        },
+        General => None,
+    };
+
+    let dao_type = query_dao_type(qctx.deps)?;
+    let components = query_enterprise_components(qctx.deps)?;
+
+    let mut treasury_spends = TreasurySpends::new();
+
+    let actions = proposal_actions
+        .iter()
+        .map(|proposal_action| ProposalActionDiagnostic {
+            action_type: to_proposal_action_type(proposal_action),
+            error: simulate_proposal_action(
+                qctx.deps,
+                &dao_type,
+                &components,
+                proposal_action,
+                &mut treasury_spends,
+            )
+            .err()
+            .map(|e| e.to_string()),
+        })
+        .collect::<Vec<ProposalActionDiagnostic>>();
+
+    let success = proposal_error.is_none() && actions.iter().all(|action| action.error.is_none());
+
+    Ok(SimulateProposalExecutionResponse {
+        proposal_id: params.proposal_id,
+        success,
+        proposal_error,
+        actions,
+    })
+}
+
+fn get_user_available_votes(
+    qctx: QueryContext,
+    user: Addr,
+    expiration: Expiration,
+) -> GovernanceControllerResult<Uint128> {
     let membership_contract = query_membership_addr(qctx.deps)?;
 
     let response: UserWeightResponse = qctx.deps.querier.query_wasm_smart(
         membership_contract.to_string(),
         &membership_common_api::msg::QueryMsg::UserWeight(UserWeightParams {
             user: user.to_string(),
+            // no expiration means the user's current weight
+            expiration: match expiration {
+                Never {} => None,
+                expiration => Some(expiration),
+            },
         }),
     )?;
 
@@ -1950,38 +3513,6 @@ fn query_dao_type(deps: Deps) -> GovernanceControllerResult<DaoType> {
     Ok(response.dao_type)
 }
 
-fn query_enterprise_components(
-    deps: Deps,
-) -> GovernanceControllerResult<ComponentContractsResponse> {
-    let enterprise = ENTERPRISE_CONTRACT.load(deps.storage)?;
-
-    let response: ComponentContractsResponse = deps
-        .querier
-        .query_wasm_smart(enterprise.to_string(), &ComponentContracts {})?;
-
-    Ok(response)
-}
-
-fn query_enterprise_governance_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    Ok(query_enterprise_components(deps)?.enterprise_governance_contract)
-}
-
-fn query_enterprise_treasury_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    Ok(query_enterprise_components(deps)?.enterprise_treasury_contract)
-}
-
-fn query_main_dao_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    query_enterprise_treasury_addr(deps)
-}
-
-fn query_enterprise_outposts_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    Ok(query_enterprise_components(deps)?.enterprise_outposts_contract)
-}
-
-fn query_membership_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    Ok(query_enterprise_components(deps)?.membership_contract)
-}
-
 /// Query the membership contract for its TokenConfig.
 /// Will fail if the DAO is not of type Token.
 fn query_dao_token_config(deps: Deps) -> GovernanceControllerResult<TokenConfigResponse> {
@@ -2018,16 +3549,15 @@ fn query_dao_denom_config(deps: Deps) -> GovernanceControllerResult<DenomConfigR
     Ok(denom_config)
 }
 
-fn query_council_membership_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
-    Ok(query_enterprise_components(deps)?.council_membership_contract)
-}
-
 fn query_council_member_weight(deps: Deps, member: String) -> GovernanceControllerResult<Uint128> {
     let dao_council_membership = query_council_membership_addr(deps)?;
 
     let member_weight: UserWeightResponse = deps.querier.query_wasm_smart(
         dao_council_membership.to_string(),
-        &multisig_membership_api::msg::QueryMsg::UserWeight(UserWeightParams { user: member }),
+        &multisig_membership_api::msg::QueryMsg::UserWeight(UserWeightParams {
+            user: member,
+            expiration: None,
+        }),
     )?;
 
     Ok(member_weight.weight)
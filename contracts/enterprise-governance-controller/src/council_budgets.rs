@@ -0,0 +1,163 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, OverflowError, StdResult, Storage, Timestamp, Uint128};
+use cw_asset::{Asset, AssetInfo, AssetUnchecked};
+use cw_storage_plus::Map;
+use enterprise_governance_controller_api::api::ProposalAction::{
+    CreatePaymentSchedule, CreateStream, DistributeFunds, DistributeFundsTargeted,
+    RequestFundingFromDao,
+};
+use enterprise_governance_controller_api::api::{
+    CouncilRemainingBudget, CouncilSpendBudget, ProposalAction,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::CouncilSpendBudgetExceeded;
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use std::collections::BTreeMap;
+
+#[cw_serde]
+pub struct CouncilSpend {
+    pub spent_at: Timestamp,
+    pub amount: Uint128,
+}
+
+/// Spends made by council proposals, keyed by the asset's string representation.
+/// Only spends that can still fall within an asset's budget period are kept.
+const COUNCIL_SPENDS: Map<String, Vec<CouncilSpend>> = Map::new("council_spends");
+
+/// Total amounts of each asset that the given proposal actions spend from the treasury.
+pub fn council_spent_assets(
+    deps: Deps,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<Vec<Asset>> {
+    let mut spent: BTreeMap<String, Asset> = BTreeMap::new();
+
+    for proposal_action in proposal_actions {
+        let assets = match proposal_action {
+            RequestFundingFromDao(msg) => msg.assets.clone(),
+            DistributeFunds(msg) => msg.funds.clone(),
+            DistributeFundsTargeted(msg) => vec![msg.asset.clone()],
+            CreateStream(msg) => vec![msg.asset.clone()],
+            // all the schedule's payments count towards the budget as soon as it is created
+            CreatePaymentSchedule(msg) => msg
+                .assets
+                .iter()
+                .map(|asset| {
+                    asset
+                        .amount
+                        .checked_mul(Uint128::from(msg.number_of_payments))
+                        .map(|amount| AssetUnchecked::new(asset.info.clone(), amount))
+                })
+                .collect::<Result<Vec<AssetUnchecked>, OverflowError>>()?,
+            _ => continue,
+        };
+
+        for asset in assets {
+            let asset = asset.check(deps.api, None)?;
+
+            spent
+                .entry(asset.info.to_string())
+                .and_modify(|total| total.amount += asset.amount)
+                .or_insert(asset);
+        }
+    }
+
+    Ok(spent.into_values().collect())
+}
+
+pub fn spent_in_period(
+    store: &dyn Storage,
+    asset: &AssetInfo,
+    period: u64,
+    now: Timestamp,
+) -> StdResult<Uint128> {
+    let spent = COUNCIL_SPENDS
+        .may_load(store, asset.to_string())?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|spend| is_within_period(spend, period, now))
+        .map(|spend| spend.amount)
+        .sum();
+
+    Ok(spent)
+}
+
+pub fn remaining_budget(
+    store: &dyn Storage,
+    budget: &CouncilSpendBudget,
+    now: Timestamp,
+) -> StdResult<CouncilRemainingBudget> {
+    let spent = spent_in_period(store, &budget.asset, budget.period, now)?;
+
+    Ok(CouncilRemainingBudget {
+        budget: budget.clone(),
+        spent,
+        remaining: budget.amount.saturating_sub(spent),
+    })
+}
+
+/// Fails if spending the given assets would exceed the council's budgets.
+/// Assets without a budget cannot be spent at all, unless budgets are not configured.
+pub fn assert_within_council_budgets(
+    store: &dyn Storage,
+    budgets: &Option<Vec<CouncilSpendBudget>>,
+    assets: &[Asset],
+    now: Timestamp,
+) -> GovernanceControllerResult<()> {
+    let budgets = match budgets {
+        None => return Ok(()),
+        Some(budgets) => budgets,
+    };
+
+    for asset in assets {
+        let remaining = match budgets.iter().find(|budget| budget.asset == asset.info) {
+            None => Uint128::zero(),
+            Some(budget) => remaining_budget(store, budget, now)?.remaining,
+        };
+
+        if asset.amount > remaining {
+            return Err(CouncilSpendBudgetExceeded {
+                asset: asset.info.to_string(),
+                remaining,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the assets as spent by a council proposal, pruning spends that fell out of their period.
+pub fn record_council_spends(
+    store: &mut dyn Storage,
+    budgets: &Option<Vec<CouncilSpendBudget>>,
+    assets: &[Asset],
+    now: Timestamp,
+) -> StdResult<()> {
+    let budgets = match budgets {
+        None => return Ok(()),
+        Some(budgets) => budgets,
+    };
+
+    for asset in assets {
+        if let Some(budget) = budgets.iter().find(|budget| budget.asset == asset.info) {
+            COUNCIL_SPENDS.update(store, asset.info.to_string(), |spends| -> StdResult<_> {
+                let mut spends: Vec<CouncilSpend> = spends
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|spend| is_within_period(spend, budget.period, now))
+                    .collect();
+
+                spends.push(CouncilSpend {
+                    spent_at: now,
+                    amount: asset.amount,
+                });
+
+                Ok(spends)
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_within_period(spend: &CouncilSpend, period: u64, now: Timestamp) -> bool {
+    spend.spent_at.plus_seconds(period) > now
+}
@@ -0,0 +1,110 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use enterprise_governance_controller_api::api::ProposalId;
+use enterprise_governance_controller_api::error::GovernanceControllerError::MaximumDelegatorsExceeded;
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+
+/// Maximum number of delegators a single delegate can have.
+/// Whenever a delegate votes, votes are also cast for each of their delegators,
+/// so this keeps voting within gas limits.
+pub const MAXIMUM_DELEGATORS_PER_DELEGATE: u32 = 50;
+
+/// Maps delegators to the delegates they gave their voting power to.
+pub const DELEGATES: Map<Addr, Addr> = Map::new("delegates");
+
+/// Reverse index of DELEGATES, (delegate, delegator) -> ().
+const DELEGATORS: Map<(Addr, Addr), ()> = Map::new("delegators");
+
+/// Number of delegators each delegate has.
+const DELEGATOR_COUNTS: Map<Addr, u32> = Map::new("delegator_counts");
+
+/// Votes that were cast by a delegate on behalf of a delegator, (proposal, delegator) -> delegate.
+/// Votes cast directly by the delegator are not present here, and are never overridden by delegates.
+pub const DELEGATED_VOTES: Map<(ProposalId, Addr), Addr> = Map::new("delegated_votes");
+
+pub fn set_delegate(
+    store: &mut dyn Storage,
+    delegator: Addr,
+    delegate: Addr,
+) -> GovernanceControllerResult<()> {
+    remove_delegate(store, delegator.clone())?;
+
+    let delegator_count = DELEGATOR_COUNTS
+        .may_load(store, delegate.clone())?
+        .unwrap_or_default();
+
+    if delegator_count >= MAXIMUM_DELEGATORS_PER_DELEGATE {
+        return Err(MaximumDelegatorsExceeded {
+            maximum: MAXIMUM_DELEGATORS_PER_DELEGATE,
+        });
+    }
+
+    DELEGATES.save(store, delegator.clone(), &delegate)?;
+    DELEGATORS.save(store, (delegate.clone(), delegator), &())?;
+    DELEGATOR_COUNTS.save(store, delegate, &(delegator_count + 1))?;
+
+    Ok(())
+}
+
+/// Removes the delegator's delegation, returning the delegate they had, if any.
+pub fn remove_delegate(store: &mut dyn Storage, delegator: Addr) -> StdResult<Option<Addr>> {
+    let delegate = DELEGATES.may_load(store, delegator.clone())?;
+
+    if let Some(delegate) = &delegate {
+        DELEGATES.remove(store, delegator.clone());
+        DELEGATORS.remove(store, (delegate.clone(), delegator));
+
+        let delegator_count = DELEGATOR_COUNTS
+            .may_load(store, delegate.clone())?
+            .unwrap_or_default();
+
+        if delegator_count <= 1 {
+            DELEGATOR_COUNTS.remove(store, delegate.clone());
+        } else {
+            DELEGATOR_COUNTS.save(store, delegate.clone(), &(delegator_count - 1))?;
+        }
+    }
+
+    Ok(delegate)
+}
+
+pub fn load_delegators(
+    store: &dyn Storage,
+    delegate: Addr,
+    start_after: Option<Addr>,
+    limit: Option<usize>,
+) -> StdResult<Vec<Addr>> {
+    let delegators =
+        DELEGATORS
+            .prefix(delegate)
+            .keys(store, start_after.map(Bound::exclusive), None, Ascending);
+
+    match limit {
+        Some(limit) => delegators.take(limit).collect(),
+        None => delegators.collect(),
+    }
+}
+
+pub fn has_delegators(store: &dyn Storage, delegate: Addr) -> bool {
+    DELEGATORS
+        .prefix(delegate)
+        .keys(store, None, None, Ascending)
+        .next()
+        .is_some()
+}
+
+/// Removes records of votes cast by delegates in the given proposal,
+/// once the proposal can no longer be voted on.
+pub fn remove_delegated_votes(store: &mut dyn Storage, proposal_id: ProposalId) -> StdResult<()> {
+    let delegators = DELEGATED_VOTES
+        .prefix(proposal_id)
+        .keys(store, None, None, Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    for delegator in delegators {
+        DELEGATED_VOTES.remove(store, (proposal_id, delegator));
+    }
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 extern crate core;
 
+pub mod components;
 pub mod contract;
+pub mod council_budgets;
+pub mod delegations;
 pub mod proposals;
+pub mod simulation;
 pub mod state;
 pub mod validate;
 
@@ -1,11 +1,106 @@
-use cosmwasm_std::{BlockInfo, StdResult, Storage};
-use cw_storage_plus::Map;
-use enterprise_governance_controller_api::api::{ProposalAction, ProposalId, ProposalInfo};
+use crate::contract::EXECUTE_PROPOSAL_ACTION_REPLY_ID_OFFSET;
+use cosmwasm_std::Order::{Ascending, Descending};
+use cosmwasm_std::{BlockInfo, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+use enterprise_governance_controller_api::api::ProposalActionResult::Pending;
+use enterprise_governance_controller_api::api::{
+    ProposalAction, ProposalActionResult, ProposalAmendment, ProposalId, ProposalInfo,
+};
 use enterprise_governance_controller_api::error::GovernanceControllerError::NoSuchProposal;
 use enterprise_governance_controller_api::error::GovernanceControllerResult;
 
 pub const PROPOSAL_INFOS: Map<ProposalId, ProposalInfo> = Map::new("proposal_infos");
 
+/// Proposals whose votes are counted by voting power at a snapshot, and whose voting
+/// has not yet ended, mapped to the time their voting ends.
+/// Members' weight changes are not applied to their votes in these proposals.
+const SNAPSHOT_PROPOSALS_IN_VOTING: Map<ProposalId, Timestamp> =
+    Map::new("snapshot_proposals_in_voting");
+
+/// Proposals whose votes follow members' current weights, and whose voting has not yet ended,
+/// mapped to the time their voting ends.
+/// Only while there are any, members' weight changes have to be applied to their votes.
+const NON_SNAPSHOT_PROPOSALS_IN_VOTING: Map<ProposalId, Timestamp> =
+    Map::new("non_snapshot_proposals_in_voting");
+
+pub fn save_proposal_in_voting(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+    voting_ends_at: Timestamp,
+    has_snapshot: bool,
+) -> StdResult<()> {
+    if has_snapshot {
+        SNAPSHOT_PROPOSALS_IN_VOTING.save(store, proposal_id, &voting_ends_at)
+    } else {
+        NON_SNAPSHOT_PROPOSALS_IN_VOTING.save(store, proposal_id, &voting_ends_at)
+    }
+}
+
+pub fn remove_proposal_in_voting(store: &mut dyn Storage, proposal_id: ProposalId) {
+    SNAPSHOT_PROPOSALS_IN_VOTING.remove(store, proposal_id);
+    NON_SNAPSHOT_PROPOSALS_IN_VOTING.remove(store, proposal_id);
+}
+
+/// Checks whether any proposal whose votes follow members' current weights can still be voted on
+/// at the given time, removing the ones found to have ended along the way.
+pub fn has_non_snapshot_proposals_in_voting(
+    store: &mut dyn Storage,
+    now: Timestamp,
+) -> StdResult<bool> {
+    loop {
+        let first = NON_SNAPSHOT_PROPOSALS_IN_VOTING
+            .range(store, None, None, Ascending)
+            .next()
+            .transpose()?;
+
+        match first {
+            Some((_, voting_ends_at)) if voting_ends_at > now => return Ok(true),
+            Some((proposal_id, _)) => NON_SNAPSHOT_PROPOSALS_IN_VOTING.remove(store, proposal_id),
+            None => return Ok(false),
+        }
+    }
+}
+
+/// Loads snapshot proposals that can still be voted on at the given time,
+/// removing the ones whose voting has ended.
+pub fn load_snapshot_proposals_in_voting(
+    store: &mut dyn Storage,
+    now: Timestamp,
+) -> StdResult<Vec<ProposalId>> {
+    let proposals = SNAPSHOT_PROPOSALS_IN_VOTING
+        .range(store, None, None, Ascending)
+        .collect::<StdResult<Vec<(ProposalId, Timestamp)>>>()?;
+
+    let mut in_voting = vec![];
+
+    for (proposal_id, voting_ends_at) in proposals {
+        if voting_ends_at > now {
+            in_voting.push(proposal_id);
+        } else {
+            SNAPSHOT_PROPOSALS_IN_VOTING.remove(store, proposal_id);
+        }
+    }
+
+    Ok(in_voting)
+}
+
+/// Proposal actions being executed separately, as (proposal ID, action index),
+/// keyed by the reply ID of their submessage.
+const PROPOSAL_ACTION_EXECUTIONS: Map<u64, (ProposalId, u32)> =
+    Map::new("proposal_action_executions");
+
+/// Proposals whose deposits are being burned or distributed, keyed by the reply ID of the
+/// submessage doing it. If that submessage fails, the deposit is sent to the treasury instead.
+const PROPOSAL_DEPOSIT_RESOLUTIONS: Map<u64, ProposalId> = Map::new("proposal_deposit_resolutions");
+
+/// Next reply ID for submessages that need to remember what they were sent for,
+/// i.e. separately executed proposal actions and proposal deposit resolutions.
+const NEXT_PROPOSAL_ACTION_REPLY_ID: Item<u64> = Item::new("next_proposal_action_reply_id");
+
+/// Previous versions of amended proposals, keyed by (proposal ID, amendment ID).
+const PROPOSAL_AMENDMENTS: Map<(ProposalId, u32), ProposalAmendment> =
+    Map::new("proposal_amendments");
+
 pub fn set_proposal_executed(
     store: &mut dyn Storage,
     proposal_id: ProposalId,
@@ -26,6 +121,153 @@ pub fn set_proposal_executed(
     Ok(())
 }
 
+/// Sets the winning option's actions as the actions to be executed by a multiple-choice proposal.
+/// No-op for other proposals.
+pub fn set_winning_proposal_option(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+    winning_option: u8,
+) -> GovernanceControllerResult<()> {
+    PROPOSAL_INFOS.update(
+        store,
+        proposal_id,
+        |info| -> GovernanceControllerResult<ProposalInfo> {
+            let info = info.ok_or(NoSuchProposal)?;
+
+            match &info.proposal_options {
+                Some(options) => Ok(ProposalInfo {
+                    proposal_actions: options
+                        .get(winning_option as usize)
+                        .map(|option| option.proposal_actions.clone())
+                        .unwrap_or_default(),
+                    ..info
+                }),
+                None => Ok(info),
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Records the result of executing one of the proposal's actions.
+pub fn set_proposal_action_result(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+    action_index: u32,
+    result: ProposalActionResult,
+) -> GovernanceControllerResult<()> {
+    PROPOSAL_INFOS.update(
+        store,
+        proposal_id,
+        |info| -> GovernanceControllerResult<ProposalInfo> {
+            let mut info = info.ok_or(NoSuchProposal)?;
+
+            if info.action_results.len() < info.proposal_actions.len() {
+                info.action_results
+                    .resize(info.proposal_actions.len(), Pending);
+            }
+
+            if let Some(action_result) = info.action_results.get_mut(action_index as usize) {
+                *action_result = result;
+            }
+
+            Ok(info)
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Stores which proposal action is being executed, returning the reply ID to use for its submessage.
+pub fn register_proposal_action_execution(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+    action_index: u32,
+) -> StdResult<u64> {
+    let reply_id = next_reply_id(store)?;
+
+    PROPOSAL_ACTION_EXECUTIONS.save(store, reply_id, &(proposal_id, action_index))?;
+
+    Ok(reply_id)
+}
+
+/// Stores whose deposit is being burned or distributed, returning the reply ID to use for its submessage.
+pub fn register_proposal_deposit_resolution(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+) -> StdResult<u64> {
+    let reply_id = next_reply_id(store)?;
+
+    PROPOSAL_DEPOSIT_RESOLUTIONS.save(store, reply_id, &proposal_id)?;
+
+    Ok(reply_id)
+}
+
+/// Loads and removes the proposal whose deposit was being resolved with the given reply ID, if any.
+pub fn take_proposal_deposit_resolution(
+    store: &mut dyn Storage,
+    reply_id: u64,
+) -> StdResult<Option<ProposalId>> {
+    let proposal_id = PROPOSAL_DEPOSIT_RESOLUTIONS.may_load(store, reply_id)?;
+    PROPOSAL_DEPOSIT_RESOLUTIONS.remove(store, reply_id);
+
+    Ok(proposal_id)
+}
+
+fn next_reply_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let reply_id = NEXT_PROPOSAL_ACTION_REPLY_ID
+        .may_load(store)?
+        .unwrap_or(EXECUTE_PROPOSAL_ACTION_REPLY_ID_OFFSET);
+    NEXT_PROPOSAL_ACTION_REPLY_ID.save(store, &(reply_id + 1))?;
+
+    Ok(reply_id)
+}
+
+/// Loads and removes the proposal action that was being executed with the given reply ID.
+pub fn take_proposal_action_execution(
+    store: &mut dyn Storage,
+    reply_id: u64,
+) -> StdResult<(ProposalId, u32)> {
+    let execution = PROPOSAL_ACTION_EXECUTIONS.load(store, reply_id)?;
+    PROPOSAL_ACTION_EXECUTIONS.remove(store, reply_id);
+
+    Ok(execution)
+}
+
+/// Stores the proposal's content from before an amendment, assigning it the next amendment ID.
+pub fn save_proposal_amendment(
+    store: &mut dyn Storage,
+    proposal_id: ProposalId,
+    amendment: impl FnOnce(u32) -> ProposalAmendment,
+) -> StdResult<u32> {
+    let last_amendment_id = PROPOSAL_AMENDMENTS
+        .prefix(proposal_id)
+        .keys(store, None, None, Descending)
+        .next()
+        .transpose()?;
+
+    let amendment_id = last_amendment_id.map_or(0, |id| id + 1);
+
+    PROPOSAL_AMENDMENTS.save(store, (proposal_id, amendment_id), &amendment(amendment_id))?;
+
+    Ok(amendment_id)
+}
+
+pub fn load_proposal_amendments(
+    store: &dyn Storage,
+    proposal_id: ProposalId,
+    start_after: Option<u32>,
+    limit: usize,
+) -> StdResult<Vec<ProposalAmendment>> {
+    PROPOSAL_AMENDMENTS
+        .prefix(proposal_id)
+        .range(store, start_after.map(Bound::exclusive), None, Ascending)
+        .take(limit)
+        .map(|res| res.map(|(_, amendment)| amendment))
+        .collect()
+}
+
 pub fn get_proposal_actions(
     store: &dyn Storage,
     proposal_id: ProposalId,
@@ -0,0 +1,177 @@
+use crate::validate::validate_proposal_action;
+use cosmwasm_std::{Addr, Deps, OverflowError, StdResult, Uint128};
+use cw_asset::AssetUnchecked;
+use enterprise_governance_controller_api::api::ProposalAction;
+use enterprise_governance_controller_api::api::ProposalAction::{
+    CreatePaymentSchedule, CreateStream, DistributeFunds, DistributeFundsTargeted,
+    RequestFundingFromDao, TransferNfts, UpgradeDao,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    InsufficientTreasuryBalance, NftNotWhitelistedInTreasury, NoSuchDaoVersion,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use enterprise_protocol::api::{ComponentContractsResponse, DaoType};
+use enterprise_treasury_api::api::{
+    LockedAmountParams, LockedAmountResponse, NftWhitelistParams, NftWhitelistResponse,
+};
+use enterprise_treasury_api::msg::QueryMsg::{LockedAmount, NftWhitelist};
+use enterprise_versioning_api::api::{Version, VersionParams, VersionResponse};
+use std::collections::BTreeMap;
+use std::slice;
+
+const NFT_WHITELIST_QUERY_LIMIT: u32 = 100;
+
+/// Amounts of each asset taken from our own chain's treasury by the actions simulated so far,
+/// keyed by the asset's string representation.
+pub type TreasurySpends = BTreeMap<String, Uint128>;
+
+/// Checks whether the proposal action is expected to execute successfully against current state.
+/// Runs the same validation as when the proposal is created, and additionally checks
+/// the state the action depends on, taking into account what previous actions spent.
+///
+/// Only our own chain's treasury is inspected, remote treasury actions are checked
+/// as far as validation goes.
+pub fn simulate_proposal_action(
+    deps: Deps,
+    dao_type: &DaoType,
+    components: &ComponentContractsResponse,
+    proposal_action: &ProposalAction,
+    treasury_spends: &mut TreasurySpends,
+) -> GovernanceControllerResult<()> {
+    validate_proposal_action(deps, dao_type, proposal_action)?;
+
+    let treasury = &components.enterprise_treasury_contract;
+
+    match proposal_action {
+        RequestFundingFromDao(msg) if msg.remote_treasury_target.is_none() => {
+            spend_from_treasury(deps, treasury, &msg.assets, treasury_spends)?
+        }
+        DistributeFunds(msg) => spend_from_treasury(deps, treasury, &msg.funds, treasury_spends)?,
+        DistributeFundsTargeted(msg) => {
+            spend_from_treasury(deps, treasury, slice::from_ref(&msg.asset), treasury_spends)?
+        }
+        CreateStream(msg) if msg.remote_treasury_target.is_none() => {
+            spend_from_treasury(deps, treasury, slice::from_ref(&msg.asset), treasury_spends)?
+        }
+        // the treasury locks all the schedule's payments as soon as it is created
+        CreatePaymentSchedule(msg) if msg.remote_treasury_target.is_none() => {
+            let total_amounts = msg
+                .assets
+                .iter()
+                .map(|asset| {
+                    asset
+                        .amount
+                        .checked_mul(Uint128::from(msg.number_of_payments))
+                        .map(|amount| AssetUnchecked::new(asset.info.clone(), amount))
+                })
+                .collect::<Result<Vec<AssetUnchecked>, OverflowError>>()?;
+
+            spend_from_treasury(deps, treasury, &total_amounts, treasury_spends)?
+        }
+        TransferNfts(msg) if msg.remote_treasury_target.is_none() => {
+            let nft_contract = deps.api.addr_validate(&msg.nft_contract)?;
+
+            if !is_nft_whitelisted(deps, treasury, &nft_contract)? {
+                return Err(NftNotWhitelistedInTreasury {
+                    nft_contract: nft_contract.to_string(),
+                });
+            }
+        }
+        UpgradeDao(msg) => assert_version_exists(
+            deps,
+            &components.enterprise_versioning_contract,
+            &msg.new_version,
+        )?,
+        _ => {
+            // nothing else to check
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the assets as spent from the treasury, failing if its balance not locked in streams
+/// or payment schedules is not sufficient to cover them on top of what was already spent.
+fn spend_from_treasury(
+    deps: Deps,
+    treasury: &Addr,
+    assets: &[AssetUnchecked],
+    treasury_spends: &mut TreasurySpends,
+) -> GovernanceControllerResult<()> {
+    for asset in assets {
+        let asset = asset.check(deps.api, None)?;
+
+        let already_spent = treasury_spends
+            .get(&asset.info.to_string())
+            .copied()
+            .unwrap_or_default();
+
+        let balance = asset
+            .info
+            .query_balance(&deps.querier, treasury.to_string())?;
+        let locked: LockedAmountResponse = deps.querier.query_wasm_smart(
+            treasury.to_string(),
+            &LockedAmount(LockedAmountParams {
+                asset: asset.info.clone().into(),
+            }),
+        )?;
+        let available = balance
+            .saturating_sub(locked.amount)
+            .saturating_sub(already_spent);
+
+        if asset.amount > available {
+            return Err(InsufficientTreasuryBalance {
+                asset: asset.info.to_string(),
+                available,
+            });
+        }
+
+        treasury_spends.insert(asset.info.to_string(), already_spent + asset.amount);
+    }
+
+    Ok(())
+}
+
+fn is_nft_whitelisted(deps: Deps, treasury: &Addr, nft_contract: &Addr) -> StdResult<bool> {
+    let mut start_after: Option<String> = None;
+
+    loop {
+        let response: NftWhitelistResponse = deps.querier.query_wasm_smart(
+            treasury.to_string(),
+            &NftWhitelist(NftWhitelistParams {
+                start_after: start_after.clone(),
+                limit: Some(NFT_WHITELIST_QUERY_LIMIT),
+            }),
+        )?;
+
+        if response.nfts.contains(nft_contract) {
+            return Ok(true);
+        }
+
+        match response.nfts.last() {
+            Some(last) => start_after = Some(last.to_string()),
+            None => return Ok(false),
+        }
+    }
+}
+
+fn assert_version_exists(
+    deps: Deps,
+    enterprise_versioning: &Addr,
+    version: &Version,
+) -> GovernanceControllerResult<()> {
+    let response: StdResult<VersionResponse> = deps.querier.query_wasm_smart(
+        enterprise_versioning.to_string(),
+        &enterprise_versioning_api::msg::QueryMsg::Version(VersionParams {
+            version: version.clone(),
+        }),
+    );
+
+    if response.is_err() {
+        return Err(NoSuchDaoVersion {
+            version: version.to_string(),
+        });
+    }
+
+    Ok(())
+}
@@ -45,7 +45,7 @@ pub enum ProposalExecutabilityStatus {
 impl From<PollStatus> for ProposalExecutabilityStatus {
     fn from(poll_status: PollStatus) -> Self {
         match poll_status {
-            PollStatus::InProgress { .. } => NotExecutable,
+            PollStatus::InProgress { .. } | PollStatus::Cancelled {} => NotExecutable,
             PollStatus::Passed { outcome, .. } => Passed { outcome },
             PollStatus::Rejected { reason } => match reason {
                 QuorumNotReached | ThresholdNotReached | QuorumAndThresholdNotReached => {
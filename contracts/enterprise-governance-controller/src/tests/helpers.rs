@@ -0,0 +1,347 @@
+use crate::contract::{reply, CREATE_POLL_REPLY_ID};
+use crate::state::{State, COUNCIL_GOV_CONFIG, ENTERPRISE_CONTRACT, GOV_CONFIG, STATE};
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Decimal, Event, OwnedDeps, Reply, StdError, StdResult,
+    SubMsgResponse, SubMsgResult, SystemError, SystemResult, Timestamp, Uint128, WasmQuery,
+};
+use enterprise_governance_controller_api::api::{
+    CouncilGovConfig, GovConfig, ProposalActionType, ProposalExecutionMode, ProposalId,
+    ProposalInfo, ProposalType,
+};
+use enterprise_protocol::api::{
+    ComponentContractsResponse, DaoInfoResponse, DaoMetadata, DaoSocialData, DaoType,
+    IsRestrictedUserResponse, Logo,
+};
+use enterprise_treasury_api::api::LockedAmountResponse;
+use enterprise_versioning_api::api::Version;
+use membership_common_api::api::{TotalWeightResponse, UserWeightResponse};
+use poll_engine_api::api::{
+    Poll, PollResponse, PollStatus, PollStatusResponse, PollVoterResponse, PollsResponse, Vote,
+    VotingScheme,
+};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+pub const ENTERPRISE: &str = "enterprise";
+pub const GOVERNANCE: &str = "governance";
+pub const MEMBERSHIP: &str = "membership";
+pub const COUNCIL_MEMBERSHIP: &str = "council_membership";
+pub const TREASURY: &str = "treasury";
+pub const FUNDS_DISTRIBUTOR: &str = "funds_distributor";
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// State of the DAO's mocked component contracts, shared with the querier.
+#[derive(Default)]
+pub struct MockDaoState {
+    /// Polls of the governance contract, their status is also returned when simulating their end
+    pub polls: BTreeMap<ProposalId, Poll>,
+    /// Votes of the governance contract, keyed by (poll, voter)
+    pub votes: BTreeMap<(ProposalId, String), Vote>,
+    /// Current weights of the membership contract's members
+    pub user_weights: BTreeMap<String, Uint128>,
+    /// Current weights of the council membership contract's members
+    pub council_member_weights: BTreeMap<String, Uint128>,
+    /// Statuses polls would end with, where they differ from the polls' current status
+    pub simulated_end_statuses: BTreeMap<ProposalId, PollStatus>,
+    /// Amounts locked in the treasury's streams and payment schedules, keyed by the asset's
+    /// string representation
+    pub treasury_locked_amounts: BTreeMap<String, Uint128>,
+}
+
+pub type MockDao = Rc<RefCell<MockDaoState>>;
+
+pub fn component_contracts() -> ComponentContractsResponse {
+    ComponentContractsResponse {
+        enterprise_factory_contract: Addr::unchecked("enterprise_factory"),
+        enterprise_versioning_contract: Addr::unchecked("enterprise_versioning"),
+        enterprise_governance_contract: Addr::unchecked(GOVERNANCE),
+        enterprise_governance_controller_contract: mock_env().contract.address,
+        enterprise_outposts_contract: Addr::unchecked("enterprise_outposts"),
+        enterprise_treasury_contract: Addr::unchecked(TREASURY),
+        funds_distributor_contract: Addr::unchecked(FUNDS_DISTRIBUTOR),
+        membership_contract: Addr::unchecked(MEMBERSHIP),
+        council_membership_contract: Addr::unchecked(COUNCIL_MEMBERSHIP),
+        attestation_contract: None,
+    }
+}
+
+pub fn mock_gov_config() -> GovConfig {
+    GovConfig {
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        veto_threshold: None,
+        vote_duration: 1000,
+        minimum_deposit: None,
+        allow_early_proposal_execution: false,
+        snapshot_voting: false,
+        proposal_cancellation_cutoff: None,
+        proposal_deposit_outcomes: Default::default(),
+        general_execution_timelock: Default::default(),
+        council_execution_timelock: Default::default(),
+        action_type_gov_params: vec![],
+        review_period: None,
+    }
+}
+
+pub fn mock_proposal_info() -> ProposalInfo {
+    ProposalInfo {
+        proposal_type: ProposalType::General,
+        executed_at: None,
+        earliest_execution: None,
+        proposal_deposit: None,
+        proposal_actions: vec![],
+        voting_power_snapshot: None,
+        proposal_options: None,
+        latest_execution: None,
+        vetoed_at: None,
+        execution_mode: ProposalExecutionMode::Atomic,
+        action_results: vec![],
+        review_ends_at: None,
+        metadata: None,
+    }
+}
+
+pub fn mock_poll(id: ProposalId, ends_at: Timestamp) -> Poll {
+    Poll {
+        id,
+        proposer: Addr::unchecked("proposer"),
+        deposit_amount: 0,
+        label: "some label".to_string(),
+        description: "some description".to_string(),
+        scheme: VotingScheme::CoinVoting,
+        status: PollStatus::InProgress { ends_at },
+        started_at: mock_env().block.time,
+        ends_at,
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        veto_threshold: None,
+        options: vec![],
+        results: Default::default(),
+    }
+}
+
+/// Sets up a DAO whose component contracts are mocked by the querier,
+/// returning the state of those mocked contracts.
+pub fn mock_dao(deps: &mut MockDeps) -> MockDao {
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked(ENTERPRISE))
+        .unwrap();
+    GOV_CONFIG
+        .save(deps.as_mut().storage, &mock_gov_config())
+        .unwrap();
+    COUNCIL_GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Some(CouncilGovConfig {
+                allowed_proposal_action_types: vec![ProposalActionType::VetoProposal],
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(50),
+                spend_budgets: None,
+            }),
+        )
+        .unwrap();
+    STATE
+        .save(
+            deps.as_mut().storage,
+            &State {
+                proposal_being_created: None,
+                proposal_being_executed: None,
+                proposal_being_voted_on: None,
+            },
+        )
+        .unwrap();
+
+    let dao: MockDao = Rc::new(RefCell::new(MockDaoState::default()));
+
+    let querier_dao = dao.clone();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let dao = querier_dao.borrow();
+
+            let response = match contract_addr.as_str() {
+                ENTERPRISE => query_enterprise(msg),
+                GOVERNANCE => query_governance(&dao, msg),
+                MEMBERSHIP => query_membership(&dao.user_weights, msg),
+                COUNCIL_MEMBERSHIP => query_membership(&dao.council_member_weights, msg),
+                TREASURY => query_treasury(&dao, msg),
+                _ => Err(StdError::generic_err(format!(
+                    "unsupported contract {}",
+                    contract_addr
+                ))),
+            };
+
+            SystemResult::Ok(response.into())
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: format!("{:?}", query),
+        }),
+    });
+
+    dao
+}
+
+fn query_enterprise(msg: &Binary) -> StdResult<Binary> {
+    match from_json(msg)? {
+        enterprise_protocol::msg::QueryMsg::ComponentContracts {} => {
+            to_json_binary(&component_contracts())
+        }
+        enterprise_protocol::msg::QueryMsg::DaoInfo {} => to_json_binary(&DaoInfoResponse {
+            creation_date: Timestamp::from_seconds(0),
+            metadata: DaoMetadata {
+                name: "dao".to_string(),
+                description: None,
+                logo: Logo::None,
+                socials: DaoSocialData {
+                    github_username: None,
+                    discord_username: None,
+                    twitter_username: None,
+                    telegram_username: None,
+                },
+            },
+            dao_type: DaoType::Token,
+            dao_version: Version::new(1, 0, 0),
+        }),
+        enterprise_protocol::msg::QueryMsg::IsRestrictedUser(_) => {
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+        }
+    }
+}
+
+fn query_governance(dao: &MockDaoState, msg: &Binary) -> StdResult<Binary> {
+    let load_poll = |poll_id: ProposalId| {
+        dao.polls
+            .get(&poll_id)
+            .cloned()
+            .ok_or_else(|| StdError::not_found("poll"))
+    };
+
+    match from_json(msg)? {
+        enterprise_governance_api::msg::QueryMsg::Poll(params) => to_json_binary(&PollResponse {
+            poll: load_poll(params.poll_id)?,
+        }),
+        enterprise_governance_api::msg::QueryMsg::Polls(params) => {
+            let start_after = params.pagination.start_after.map_or(0, |id| id.u64());
+            let limit = params.pagination.limit.unwrap_or(u64::MAX) as usize;
+
+            to_json_binary(&PollsResponse {
+                polls: dao
+                    .polls
+                    .range(start_after + 1..)
+                    .map(|(_, poll)| poll.clone())
+                    .take(limit)
+                    .collect(),
+            })
+        }
+        enterprise_governance_api::msg::QueryMsg::PollStatus { poll_id } => {
+            let poll = load_poll(poll_id)?;
+
+            to_json_binary(&PollStatusResponse {
+                status: poll.status,
+                ends_at: poll.ends_at,
+                results: poll.results,
+            })
+        }
+        enterprise_governance_api::msg::QueryMsg::SimulateEndPollStatus { poll_id, .. } => {
+            let poll = load_poll(poll_id)?;
+
+            to_json_binary(&PollStatusResponse {
+                status: dao
+                    .simulated_end_statuses
+                    .get(&poll_id)
+                    .cloned()
+                    .unwrap_or(poll.status),
+                ends_at: poll.ends_at,
+                results: poll.results,
+            })
+        }
+        enterprise_governance_api::msg::QueryMsg::PollVoter(params) => {
+            to_json_binary(&PollVoterResponse {
+                vote: dao
+                    .votes
+                    .get(&(params.poll_id.u64(), params.voter_addr))
+                    .cloned(),
+            })
+        }
+        _ => Err(StdError::generic_err("unsupported governance query")),
+    }
+}
+
+fn query_treasury(dao: &MockDaoState, msg: &Binary) -> StdResult<Binary> {
+    match from_json(msg)? {
+        enterprise_treasury_api::msg::QueryMsg::LockedAmount(params) => {
+            let asset = params.asset.check(&MockApi::default(), None)?;
+            let amount = dao
+                .treasury_locked_amounts
+                .get(&asset.to_string())
+                .copied()
+                .unwrap_or_default();
+
+            to_json_binary(&LockedAmountResponse { asset, amount })
+        }
+        _ => Err(StdError::generic_err("unsupported treasury query")),
+    }
+}
+
+fn query_membership(user_weights: &BTreeMap<String, Uint128>, msg: &Binary) -> StdResult<Binary> {
+    match from_json(msg)? {
+        membership_common_api::msg::QueryMsg::UserWeight(params) => {
+            to_json_binary(&UserWeightResponse {
+                user: Addr::unchecked(&params.user),
+                weight: user_weights.get(&params.user).cloned().unwrap_or_default(),
+            })
+        }
+        membership_common_api::msg::QueryMsg::TotalWeight(_) => {
+            to_json_binary(&TotalWeightResponse {
+                total_weight: user_weights.values().sum(),
+            })
+        }
+        _ => Err(StdError::generic_err("unsupported membership query")),
+    }
+}
+
+/// Creates a proposal with the given info through the create-poll reply,
+/// backed by a poll in the mocked governance contract that ends at the given time.
+pub fn create_mock_proposal(
+    deps: &mut MockDeps,
+    dao: &MockDao,
+    proposal_info: ProposalInfo,
+    ends_at: Timestamp,
+) -> ProposalId {
+    let proposal_id = dao.borrow().polls.len() as ProposalId + 1;
+    dao.borrow_mut()
+        .polls
+        .insert(proposal_id, mock_poll(proposal_id, ends_at));
+
+    let state = STATE.load(deps.as_ref().storage).unwrap();
+    STATE
+        .save(
+            deps.as_mut().storage,
+            &State {
+                proposal_being_created: Some(proposal_info),
+                ..state
+            },
+        )
+        .unwrap();
+
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: CREATE_POLL_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![Event::new("wasm")
+                    .add_attribute("action", "create_poll")
+                    .add_attribute("poll_id", proposal_id.to_string())],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+
+    proposal_id
+}
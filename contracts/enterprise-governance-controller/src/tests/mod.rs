@@ -1 +1,2 @@
+mod helpers;
 mod unit;
@@ -1,4 +1,51 @@
+use crate::contract::{
+    execute, query, reply, weights_changed, END_POLL_REPLY_ID, EXECUTE_PROPOSAL_ACTIONS_REPLY_ID,
+};
+use crate::delegations::{
+    remove_delegated_votes, DELEGATED_VOTES, MAXIMUM_DELEGATORS_PER_DELEGATE,
+};
+use crate::proposals::PROPOSAL_INFOS;
+use crate::state::{COUNCIL_GOV_CONFIG, GOV_CONFIG};
+use crate::tests::helpers::{
+    create_mock_proposal, mock_dao, mock_gov_config, mock_proposal_info, MockDao, MockDeps,
+    GOVERNANCE, MEMBERSHIP, TREASURY,
+};
+use common::cw::Context;
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    coins, from_json, wasm_execute, Addr, CosmosMsg, Decimal, Env, Order, Record, Reply, ReplyOn,
+    Response, StdResult, Storage, SubMsg, SubMsgResponse, SubMsgResult, Timestamp, Uint128, Uint64,
+    WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_asset::{Asset, AssetInfo};
+use enterprise_governance_controller_api::api::{
+    AmendProposalMsg, CancelProposalMsg, CastVoteMsg, CouncilGovConfig, CouncilSpendBudget,
+    CouncilSpendBudgetsResponse, CreatePaymentScheduleProposalActionMsg, CreateProposalMsg,
+    DelegateMsg, ExecuteProposalActionMsg, ExecuteProposalMsg, ExecutionTimelock, GovConfig,
+    ProposalAction, ProposalActionResult, ProposalActionType, ProposalAmendment,
+    ProposalAmendmentsParams, ProposalAmendmentsResponse, ProposalDeposit, ProposalDepositAsset,
+    ProposalDepositOutcome, ProposalDepositOutcomes, ProposalExecutionMode, ProposalId,
+    ProposalInfo, ProposalMetadata, ProposalOption, ProposalStatus, ProposalStatusParams,
+    ProposalStatusResponse, ProposalType, ProposalsParams, ProposalsResponse,
+    RequestFundingFromDaoMsg, RetryProposalActionsMsg, SimulateProposalExecutionParams,
+    SimulateProposalExecutionResponse, VetoProposalMsg,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    CouncilSpendBudgetExceeded, InsufficientTreasuryBalance, InvalidArgument,
+    MaximumDelegatorsExceeded, NoVotingPowerToDelegate, ProposalActionsRetryWindowPassed,
+    ProposalCancelled, ProposalCannotBeCancelled, ProposalCannotBeExecutedYet,
+    ProposalCannotBeVetoed, ProposalExpired, ProposalInReview, ProposalNotInReview, ProposalVetoed,
+    Unauthorized,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use enterprise_governance_controller_api::msg::{ExecuteMsg, QueryMsg};
 use enterprise_protocol::error::DaoResult;
+use membership_common_api::api::{UserWeightChange, WeightsChangedMsg};
+use poll_engine_api::api::{
+    CancelPollParams, CastVoteParams, PollStatus, UpdatePollParams, UpdateVotesParams, Vote,
+    VoteOutcome,
+};
 
 #[test]
 fn initial_test() -> DaoResult<()> {
@@ -6,3 +53,1683 @@ fn initial_test() -> DaoResult<()> {
 
     Ok(())
 }
+
+/// Returns the polls excluded from updating votes by the UpdateVotes message in the response.
+fn update_votes_excluded_polls(response: &Response) -> Vec<Uint64> {
+    response
+        .messages
+        .iter()
+        .find_map(|submsg| match &submsg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) if contract_addr == GOVERNANCE => match from_json(msg).unwrap() {
+                enterprise_governance_api::msg::ExecuteMsg::UpdateVotes(UpdateVotesParams {
+                    excluded_polls,
+                    ..
+                }) => Some(excluded_polls),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("no UpdateVotes message in the response")
+}
+
+#[test]
+fn weight_changes_follow_each_proposals_snapshot_when_snapshot_voting_is_toggled() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let env = mock_env();
+    let ends_at = env.block.time.plus_seconds(1000);
+
+    let snapshot_proposal = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            voting_power_snapshot: Some(env.block.time),
+            ..mock_proposal_info()
+        },
+        ends_at,
+    );
+    create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+
+    let weights_changed_msg = WeightsChangedMsg {
+        weight_changes: vec![UserWeightChange {
+            user: "user".to_string(),
+            old_weight: Uint128::new(10),
+            new_weight: Uint128::zero(),
+        }],
+    };
+
+    for snapshot_voting in [true, false] {
+        GOV_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &GovConfig {
+                    snapshot_voting,
+                    ..mock_gov_config()
+                },
+            )
+            .unwrap();
+
+        let mut ctx = Context {
+            deps: deps.as_mut(),
+            env: env.clone(),
+            info: mock_info(MEMBERSHIP, &[]),
+        };
+        let response = weights_changed(&mut ctx, weights_changed_msg.clone()).unwrap();
+
+        // the proposal without a snapshot is updated, the one with a snapshot is not
+        assert_eq!(
+            update_votes_excluded_polls(&response),
+            vec![Uint64::from(snapshot_proposal)]
+        );
+    }
+
+    // once voting ends, the snapshot proposal no longer needs to be excluded
+    let mut ctx = Context {
+        deps: deps.as_mut(),
+        env: mock_env(),
+        info: mock_info(MEMBERSHIP, &[]),
+    };
+    ctx.env.block.time = ends_at;
+    let response = weights_changed(&mut ctx, weights_changed_msg).unwrap();
+
+    assert!(update_votes_excluded_polls(&response).is_empty());
+}
+
+#[test]
+fn weight_changes_do_not_update_votes_when_all_proposals_in_voting_have_snapshots() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &GovConfig {
+                snapshot_voting: true,
+                ..mock_gov_config()
+            },
+        )
+        .unwrap();
+
+    let env = mock_env();
+    let snapshot_ends_at = env.block.time.plus_seconds(2000);
+    let non_snapshot_ends_at = env.block.time.plus_seconds(1000);
+
+    create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            voting_power_snapshot: Some(env.block.time),
+            ..mock_proposal_info()
+        },
+        snapshot_ends_at,
+    );
+    create_mock_proposal(&mut deps, &dao, mock_proposal_info(), non_snapshot_ends_at);
+
+    let weights_changed_msg = WeightsChangedMsg {
+        weight_changes: vec![UserWeightChange {
+            user: "user".to_string(),
+            old_weight: Uint128::new(10),
+            new_weight: Uint128::zero(),
+        }],
+    };
+
+    let sends_update_votes = |deps: &mut MockDeps, env: Env| {
+        let mut ctx = Context {
+            deps: deps.as_mut(),
+            env,
+            info: mock_info(MEMBERSHIP, &[]),
+        };
+        let response = weights_changed(&mut ctx, weights_changed_msg.clone()).unwrap();
+
+        response.messages.iter().any(|submsg| {
+            matches!(&submsg.msg, CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == GOVERNANCE)
+        })
+    };
+
+    // the proposal without a snapshot is still in voting
+    assert!(sends_update_votes(&mut deps, env.clone()));
+
+    // only the snapshot proposal is left in voting
+    assert!(!sends_update_votes(&mut deps, env_at(non_snapshot_ends_at)));
+}
+
+fn set_user_weight(dao: &MockDao, user: &str, weight: u128) {
+    dao.borrow_mut()
+        .user_weights
+        .insert(user.to_string(), Uint128::new(weight));
+}
+
+fn delegate(
+    deps: &mut MockDeps,
+    delegator: &str,
+    delegate: &str,
+) -> GovernanceControllerResult<Response> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(delegator, &[]),
+        ExecuteMsg::Delegate(DelegateMsg {
+            delegate: delegate.to_string(),
+        }),
+    )
+}
+
+/// Returns (voter, amount) of each CastVote message sent to the governance contract.
+fn cast_votes(response: &Response) -> Vec<(String, Uint128)> {
+    response
+        .messages
+        .iter()
+        .filter_map(|submsg| match &submsg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) if contract_addr == GOVERNANCE => match from_json(msg).unwrap() {
+                enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
+                    voter,
+                    amount,
+                    ..
+                }) => Some((voter, amount)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn delegating_requires_voting_power() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let result = delegate(&mut deps, "delegator", "delegate");
+    assert_eq!(result.unwrap_err(), NoVotingPowerToDelegate);
+
+    set_user_weight(&dao, "delegator", 1);
+
+    delegate(&mut deps, "delegator", "delegate").unwrap();
+}
+
+#[test]
+fn delegate_cannot_exceed_maximum_number_of_delegators() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    for i in 0..=MAXIMUM_DELEGATORS_PER_DELEGATE {
+        set_user_weight(&dao, &format!("delegator{}", i), 1);
+    }
+
+    for i in 0..MAXIMUM_DELEGATORS_PER_DELEGATE {
+        delegate(&mut deps, &format!("delegator{}", i), "delegate").unwrap();
+    }
+
+    let last_delegator = format!("delegator{}", MAXIMUM_DELEGATORS_PER_DELEGATE);
+
+    let result = delegate(&mut deps, &last_delegator, "delegate");
+    assert_eq!(
+        result.unwrap_err(),
+        MaximumDelegatorsExceeded {
+            maximum: MAXIMUM_DELEGATORS_PER_DELEGATE
+        }
+    );
+
+    // re-delegating to the same delegate doesn't count the delegator twice
+    delegate(&mut deps, "delegator0", "delegate").unwrap();
+
+    // moving a delegation elsewhere frees up a spot
+    delegate(&mut deps, "delegator0", "other_delegate").unwrap();
+    delegate(&mut deps, &last_delegator, "delegate").unwrap();
+}
+
+#[test]
+fn delegate_votes_for_delegators_that_did_not_vote_directly() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    set_user_weight(&dao, "delegator1", 10);
+    set_user_weight(&dao, "delegator2", 5);
+
+    delegate(&mut deps, "delegator1", "delegate").unwrap();
+    delegate(&mut deps, "delegator2", "delegate").unwrap();
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let proposal_id = create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+
+    dao.borrow_mut().votes.insert(
+        (proposal_id, "delegator2".to_string()),
+        Vote {
+            poll_id: proposal_id,
+            voter: Addr::unchecked("delegator2"),
+            outcome: 1,
+            amount: 5,
+            split: vec![],
+        },
+    );
+
+    // the delegate has no voting power of their own, but can vote for their delegators
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("delegate", &[]),
+        ExecuteMsg::CastVote(CastVoteMsg {
+            proposal_id,
+            outcome: VoteOutcome::Yes.into(),
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        cast_votes(&response),
+        vec![
+            ("delegator1".to_string(), Uint128::new(10)),
+            ("delegate".to_string(), Uint128::zero()),
+        ]
+    );
+    assert!(DELEGATED_VOTES.has(
+        deps.as_ref().storage,
+        (proposal_id, Addr::unchecked("delegator1"))
+    ));
+
+    remove_delegated_votes(deps.as_mut().storage, proposal_id).unwrap();
+
+    assert!(!DELEGATED_VOTES.has(
+        deps.as_ref().storage,
+        (proposal_id, Addr::unchecked("delegator1"))
+    ));
+}
+
+#[test]
+fn cancelled_proposal_deposit_that_cannot_be_burned_goes_to_treasury() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &GovConfig {
+                proposal_deposit_outcomes: ProposalDepositOutcomes {
+                    cancelled: ProposalDepositOutcome::Burn,
+                    ..Default::default()
+                },
+                ..mock_gov_config()
+            },
+        )
+        .unwrap();
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_deposit: Some(ProposalDeposit {
+                depositor: Addr::unchecked("proposer"),
+                asset: ProposalDepositAsset::Cw20 {
+                    token_addr: Addr::unchecked("token"),
+                    amount: Uint128::new(100),
+                },
+            }),
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CancelProposal(CancelProposalMsg { proposal_id }),
+    )
+    .unwrap();
+
+    let burn_submsg = response
+        .messages
+        .iter()
+        .find(|submsg| {
+            matches!(
+                &submsg.msg,
+                CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. })
+                    if contract_addr == "token"
+                        && from_json::<Cw20ExecuteMsg>(msg).unwrap()
+                            == Cw20ExecuteMsg::Burn { amount: Uint128::new(100) }
+            )
+        })
+        .expect("deposit should be burned");
+    assert_eq!(burn_submsg.reply_on, ReplyOn::Always);
+
+    // the token does not support burning
+    let response = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: burn_submsg.id,
+            result: SubMsgResult::Err("burn not supported".to_string()),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.messages,
+        vec![SubMsg::new(
+            Asset::cw20(Addr::unchecked("token"), 100u128)
+                .transfer_msg(TREASURY)
+                .unwrap()
+        )]
+    );
+
+    // the burn reply is only handled once
+    let response = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: burn_submsg.id,
+            result: SubMsgResult::Err("burn not supported".to_string()),
+        },
+    );
+    assert!(response.is_err());
+}
+
+fn cancel_proposal(
+    deps: &mut MockDeps,
+    env: Env,
+    sender: &str,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<Response> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(sender, &[]),
+        ExecuteMsg::CancelProposal(CancelProposalMsg { proposal_id }),
+    )
+}
+
+fn cancel_poll_submsg(proposal_id: ProposalId) -> SubMsg {
+    SubMsg::new(
+        wasm_execute(
+            GOVERNANCE,
+            &enterprise_governance_api::msg::ExecuteMsg::CancelPoll(CancelPollParams {
+                poll_id: proposal_id.into(),
+            }),
+            vec![],
+        )
+        .unwrap(),
+    )
+}
+
+#[test]
+fn only_proposer_can_cancel_proposal_nobody_voted_on() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        mock_proposal_info(),
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let result = cancel_proposal(&mut deps, mock_env(), "member", proposal_id);
+    assert_eq!(result.unwrap_err(), Unauthorized);
+
+    let response = cancel_proposal(&mut deps, mock_env(), "proposer", proposal_id).unwrap();
+    assert_eq!(response.messages, vec![cancel_poll_submsg(proposal_id)]);
+
+    // the governance contract cancels the poll
+    dao.borrow_mut().polls.get_mut(&proposal_id).unwrap().status = PollStatus::Cancelled {};
+
+    assert_eq!(
+        proposal_status(&deps, proposal_id, mock_env()),
+        ProposalStatus::Cancelled
+    );
+
+    let result = cancel_proposal(&mut deps, mock_env(), "proposer", proposal_id);
+    assert_eq!(result.unwrap_err(), ProposalCancelled);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+    );
+    assert_eq!(result.unwrap_err(), ProposalCancelled);
+}
+
+#[test]
+fn proposal_with_votes_can_only_be_cancelled_before_cancellation_cutoff() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let proposal_id = create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+    dao.borrow_mut()
+        .polls
+        .get_mut(&proposal_id)
+        .unwrap()
+        .results
+        .insert(VoteOutcome::Yes.into(), 10);
+
+    // without a cutoff, proposals cannot be cancelled once voted on
+    let result = cancel_proposal(&mut deps, mock_env(), "proposer", proposal_id);
+    assert_eq!(result.unwrap_err(), ProposalCannotBeCancelled);
+
+    GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &GovConfig {
+                proposal_cancellation_cutoff: Some(100),
+                ..mock_gov_config()
+            },
+        )
+        .unwrap();
+
+    let started_at = mock_env().block.time;
+
+    let result = cancel_proposal(
+        &mut deps,
+        env_at(started_at.plus_seconds(100)),
+        "proposer",
+        proposal_id,
+    );
+    assert_eq!(result.unwrap_err(), ProposalCannotBeCancelled);
+
+    cancel_proposal(
+        &mut deps,
+        env_at(started_at.plus_seconds(99)),
+        "proposer",
+        proposal_id,
+    )
+    .unwrap();
+
+    // voting that has ended cannot be cancelled, regardless of the cutoff
+    let proposal_id = create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+    let result = cancel_proposal(&mut deps, env_at(ends_at), "proposer", proposal_id);
+    assert_eq!(result.unwrap_err(), ProposalCannotBeCancelled);
+}
+
+#[test]
+fn council_can_cancel_proposal() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let proposal_id = create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+    // cancelling through the council is not limited by votes already cast
+    dao.borrow_mut()
+        .polls
+        .get_mut(&proposal_id)
+        .unwrap()
+        .results
+        .insert(VoteOutcome::Yes.into(), 10);
+
+    let cancel_proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_type: ProposalType::Council,
+            proposal_actions: vec![ProposalAction::CancelProposal(CancelProposalMsg {
+                proposal_id,
+            })],
+            ..mock_proposal_info()
+        },
+        ends_at,
+    );
+
+    let contract = mock_env().contract.address;
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(contract.as_str(), &[]),
+        ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg {
+            proposal_id: cancel_proposal_id,
+        }),
+    )
+    .unwrap();
+
+    assert!(response.messages.contains(&cancel_poll_submsg(proposal_id)));
+}
+
+fn env_at(time: Timestamp) -> Env {
+    let mut env = mock_env();
+    env.block.time = time;
+    env
+}
+
+/// Sets up a DAO where general proposals are queued for 100 seconds before they can be
+/// executed, and expire 150 seconds after their voting ends. Creates a general proposal that
+/// passed, but was not executed until 40 seconds after its voting ended.
+/// Returns the proposal's ID and the time it gets queued at.
+fn create_passed_proposal_with_execution_delay(
+    deps: &mut MockDeps,
+    dao: &MockDao,
+) -> (ProposalId, Timestamp) {
+    GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &GovConfig {
+                general_execution_timelock: ExecutionTimelock {
+                    execution_delay: 100,
+                    execution_window: Some(50),
+                },
+                ..mock_gov_config()
+            },
+        )
+        .unwrap();
+
+    set_user_weight(dao, "member", 10);
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let proposal_id = create_mock_proposal(deps, dao, mock_proposal_info(), ends_at);
+
+    // the delay would end 40 seconds earlier if it was counted from the end of voting
+    let queued_at = ends_at.plus_seconds(40);
+
+    let passed = PollStatus::Passed {
+        outcome: VoteOutcome::Yes.into(),
+        count: Uint128::new(10),
+    };
+    dao.borrow_mut()
+        .simulated_end_statuses
+        .insert(proposal_id, passed.clone());
+
+    let response = execute(
+        deps.as_mut(),
+        env_at(queued_at),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+    )
+    .unwrap();
+    assert_eq!(response.messages[0].id, END_POLL_REPLY_ID);
+
+    dao.borrow_mut().polls.get_mut(&proposal_id).unwrap().status = passed;
+
+    let response = reply(
+        deps.as_mut(),
+        env_at(queued_at),
+        Reply {
+            id: END_POLL_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+
+    // queued rather than executed
+    assert!(response.messages.is_empty());
+
+    (proposal_id, queued_at)
+}
+
+/// Executes a council proposal vetoing the given proposal, returning the result.
+fn council_veto(
+    deps: &mut MockDeps,
+    dao: &MockDao,
+    proposal_id: ProposalId,
+    env: Env,
+) -> GovernanceControllerResult<Response> {
+    let veto_proposal_id = create_mock_proposal(
+        deps,
+        dao,
+        ProposalInfo {
+            proposal_type: ProposalType::Council,
+            proposal_actions: vec![ProposalAction::VetoProposal(VetoProposalMsg {
+                proposal_id,
+            })],
+            ..mock_proposal_info()
+        },
+        env.block.time,
+    );
+
+    let contract = env.contract.address.clone();
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(contract.as_str(), &[]),
+        ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg {
+            proposal_id: veto_proposal_id,
+        }),
+    )
+}
+
+fn proposal_status(deps: &MockDeps, proposal_id: ProposalId, env: Env) -> ProposalStatus {
+    let response: ProposalStatusResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ProposalStatus(ProposalStatusParams { proposal_id }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response.status
+}
+
+#[test]
+fn council_can_veto_proposal_within_execution_delay_counted_from_queueing() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let (proposal_id, queued_at) = create_passed_proposal_with_execution_delay(&mut deps, &dao);
+
+    let proposal_info = PROPOSAL_INFOS
+        .load(deps.as_ref().storage, proposal_id)
+        .unwrap();
+    assert_eq!(
+        proposal_info.earliest_execution,
+        Some(queued_at.plus_seconds(100))
+    );
+    // the execution window still counts from the end of voting
+    assert_eq!(
+        proposal_info.latest_execution,
+        Some(queued_at.plus_seconds(110))
+    );
+
+    let result = execute(
+        deps.as_mut(),
+        env_at(queued_at.plus_seconds(99)),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+    );
+    assert_eq!(result.unwrap_err(), ProposalCannotBeExecutedYet);
+
+    council_veto(
+        &mut deps,
+        &dao,
+        proposal_id,
+        env_at(queued_at.plus_seconds(99)),
+    )
+    .unwrap();
+
+    let result = execute(
+        deps.as_mut(),
+        env_at(queued_at.plus_seconds(100)),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+    );
+    assert_eq!(result.unwrap_err(), ProposalVetoed);
+
+    assert_eq!(
+        proposal_status(&deps, proposal_id, env_at(queued_at.plus_seconds(100))),
+        ProposalStatus::Vetoed
+    );
+}
+
+#[test]
+fn passed_proposal_that_nobody_queues_expires() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    for execution_delay in [0, 100] {
+        GOV_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &GovConfig {
+                    general_execution_timelock: ExecutionTimelock {
+                        execution_delay,
+                        execution_window: Some(50),
+                    },
+                    ..mock_gov_config()
+                },
+            )
+            .unwrap();
+
+        set_user_weight(&dao, "member", 10);
+
+        let ends_at = mock_env().block.time.plus_seconds(1000);
+        let proposal_id = create_mock_proposal(&mut deps, &dao, mock_proposal_info(), ends_at);
+        dao.borrow_mut().simulated_end_statuses.insert(
+            proposal_id,
+            PollStatus::Passed {
+                outcome: VoteOutcome::Yes.into(),
+                count: Uint128::new(10),
+            },
+        );
+
+        assert_eq!(
+            proposal_status(&deps, proposal_id, env_at(ends_at.plus_seconds(10))),
+            ProposalStatus::Passed
+        );
+
+        let year_later = ends_at.plus_seconds(365 * 24 * 60 * 60);
+
+        assert_eq!(
+            proposal_status(&deps, proposal_id, env_at(year_later)),
+            ProposalStatus::Expired
+        );
+
+        let result = execute(
+            deps.as_mut(),
+            env_at(year_later),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+        );
+        assert_eq!(result.unwrap_err(), ProposalExpired);
+    }
+}
+
+#[test]
+fn council_cannot_veto_proposal_after_its_execution_delay() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let (proposal_id, queued_at) = create_passed_proposal_with_execution_delay(&mut deps, &dao);
+
+    let result = council_veto(
+        &mut deps,
+        &dao,
+        proposal_id,
+        env_at(queued_at.plus_seconds(100)),
+    );
+    assert_eq!(result.unwrap_err(), ProposalCannotBeVetoed);
+
+    assert_eq!(
+        proposal_status(&deps, proposal_id, env_at(queued_at.plus_seconds(100))),
+        ProposalStatus::Passed
+    );
+
+    let response = execute(
+        deps.as_mut(),
+        env_at(queued_at.plus_seconds(100)),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+    )
+    .unwrap();
+    assert_eq!(response.messages[0].id, EXECUTE_PROPOSAL_ACTIONS_REPLY_ID);
+}
+
+fn request_funding(amount: u128) -> ProposalAction {
+    ProposalAction::RequestFundingFromDao(RequestFundingFromDaoMsg {
+        remote_treasury_target: None,
+        recipient: "recipient".to_string(),
+        assets: vec![Asset::native("uluna", amount).into()],
+    })
+}
+
+fn simulate_proposal_execution(
+    deps: &MockDeps,
+    proposal_id: ProposalId,
+    option: Option<u8>,
+) -> GovernanceControllerResult<SimulateProposalExecutionResponse> {
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SimulateProposalExecution(SimulateProposalExecutionParams {
+            proposal_id,
+            option,
+        }),
+    )?;
+
+    Ok(from_json(response)?)
+}
+
+#[test]
+fn simulation_accumulates_treasury_spends_across_actions() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    deps.querier.update_balance(TREASURY, coins(100, "uluna"));
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_actions: vec![
+                request_funding(60),
+                request_funding(30),
+                request_funding(20),
+            ],
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let response = simulate_proposal_execution(&deps, proposal_id, None).unwrap();
+
+    assert!(!response.success);
+    assert_eq!(response.proposal_error, None);
+    assert_eq!(
+        response
+            .actions
+            .into_iter()
+            .map(|action| action.error)
+            .collect::<Vec<Option<String>>>(),
+        vec![
+            None,
+            None,
+            Some(
+                InsufficientTreasuryBalance {
+                    asset: "native:uluna".to_string(),
+                    available: Uint128::new(10),
+                }
+                .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn simulation_fails_action_exceeding_treasury_balance() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    deps.querier.update_balance(TREASURY, coins(100, "uluna"));
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_actions: vec![request_funding(101)],
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let response = simulate_proposal_execution(&deps, proposal_id, None).unwrap();
+
+    assert!(!response.success);
+    assert_eq!(
+        response.actions[0].error,
+        Some(
+            InsufficientTreasuryBalance {
+                asset: "native:uluna".to_string(),
+                available: Uint128::new(100),
+            }
+            .to_string()
+        )
+    );
+
+    // spending the whole balance is fine
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_actions: vec![request_funding(100)],
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let response = simulate_proposal_execution(&deps, proposal_id, None).unwrap();
+
+    assert!(response.success);
+    assert_eq!(response.actions[0].error, None);
+}
+
+#[test]
+fn simulation_does_not_spend_treasury_amounts_locked_in_streams_and_schedules() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    deps.querier.update_balance(TREASURY, coins(100, "uluna"));
+    dao.borrow_mut()
+        .treasury_locked_amounts
+        .insert("native:uluna".to_string(), Uint128::new(40));
+
+    let create_payment_schedule = |number_of_payments: u32| {
+        ProposalAction::CreatePaymentSchedule(CreatePaymentScheduleProposalActionMsg {
+            remote_treasury_target: None,
+            recipient: "recipient".to_string(),
+            assets: vec![Asset::native("uluna", 20u128).into()],
+            start_time: None,
+            interval: 100,
+            number_of_payments,
+        })
+    };
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_actions: vec![
+                request_funding(70),
+                create_payment_schedule(3),
+                create_payment_schedule(4),
+            ],
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    let response = simulate_proposal_execution(&deps, proposal_id, None).unwrap();
+
+    assert!(!response.success);
+    assert_eq!(
+        response
+            .actions
+            .into_iter()
+            .map(|action| action.error)
+            .collect::<Vec<Option<String>>>(),
+        vec![
+            Some(
+                InsufficientTreasuryBalance {
+                    asset: "native:uluna".to_string(),
+                    available: Uint128::new(60),
+                }
+                .to_string()
+            ),
+            // all 3 payments are locked by the schedule
+            None,
+            Some(
+                InsufficientTreasuryBalance {
+                    asset: "native:uluna".to_string(),
+                    available: Uint128::zero(),
+                }
+                .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn simulation_of_multiple_choice_proposal_uses_winning_option() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    deps.querier.update_balance(TREASURY, coins(100, "uluna"));
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_options: Some(vec![
+                ProposalOption {
+                    label: "small".to_string(),
+                    proposal_actions: vec![request_funding(50)],
+                },
+                ProposalOption {
+                    label: "large".to_string(),
+                    proposal_actions: vec![request_funding(500)],
+                },
+            ]),
+            ..mock_proposal_info()
+        },
+        mock_env().block.time.plus_seconds(1000),
+    );
+
+    // there is no winning option before the proposal passes
+    let result = simulate_proposal_execution(&deps, proposal_id, None);
+    assert!(matches!(result, Err(InvalidArgument { .. })));
+
+    let response = simulate_proposal_execution(&deps, proposal_id, Some(1)).unwrap();
+    assert!(!response.success);
+
+    dao.borrow_mut().polls.get_mut(&proposal_id).unwrap().status = PollStatus::Passed {
+        outcome: 0,
+        count: Uint128::new(10),
+    };
+
+    let response = simulate_proposal_execution(&deps, proposal_id, None).unwrap();
+    assert!(response.success);
+    assert_eq!(response.actions.len(), 1);
+}
+
+fn amend_proposal(
+    deps: &mut MockDeps,
+    env: Env,
+    sender: &str,
+    msg: AmendProposalMsg,
+) -> GovernanceControllerResult<Response> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(sender, &[]),
+        ExecuteMsg::AmendProposal(msg),
+    )
+}
+
+fn proposal_amendments(
+    deps: &MockDeps,
+    proposal_id: ProposalId,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> Vec<ProposalAmendment> {
+    let response: ProposalAmendmentsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalAmendments(ProposalAmendmentsParams {
+                proposal_id,
+                start_after,
+                limit,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response.amendments
+}
+
+#[test]
+fn only_proposer_can_amend_proposal_during_its_review_period() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    set_user_weight(&dao, "member", 10);
+
+    let review_ends_at = mock_env().block.time.plus_seconds(100);
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            review_ends_at: Some(review_ends_at),
+            ..mock_proposal_info()
+        },
+        review_ends_at.plus_seconds(1000),
+    );
+
+    let amend_actions = |proposal_actions: Vec<ProposalAction>| AmendProposalMsg {
+        proposal_id,
+        title: None,
+        description: None,
+        proposal_actions: Some(proposal_actions),
+    };
+
+    let result = amend_proposal(
+        &mut deps,
+        mock_env(),
+        "member",
+        amend_actions(vec![request_funding(10)]),
+    );
+    assert_eq!(result.unwrap_err(), Unauthorized);
+
+    let response = amend_proposal(
+        &mut deps,
+        mock_env(),
+        "proposer",
+        amend_actions(vec![request_funding(10)]),
+    )
+    .unwrap();
+    // the poll is only updated when the title or the description change
+    assert!(response.messages.is_empty());
+
+    let response = amend_proposal(
+        &mut deps,
+        mock_env(),
+        "proposer",
+        AmendProposalMsg {
+            title: Some("new title".to_string()),
+            ..amend_actions(vec![request_funding(20)])
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages,
+        vec![SubMsg::new(
+            wasm_execute(
+                GOVERNANCE,
+                &enterprise_governance_api::msg::ExecuteMsg::UpdatePoll(UpdatePollParams {
+                    poll_id: proposal_id.into(),
+                    label: Some("new title".to_string()),
+                    description: None,
+                }),
+                vec![],
+            )
+            .unwrap()
+        )]
+    );
+
+    let proposal_info = PROPOSAL_INFOS
+        .load(deps.as_ref().storage, proposal_id)
+        .unwrap();
+    assert_eq!(proposal_info.proposal_actions, vec![request_funding(20)]);
+
+    // nobody can vote while the proposal is in review
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("member", &[]),
+        ExecuteMsg::CastVote(CastVoteMsg {
+            proposal_id,
+            outcome: VoteOutcome::Yes.into(),
+        }),
+    );
+    assert_eq!(result.unwrap_err(), ProposalInReview);
+
+    // once the review period is over, the proposal can no longer be amended
+    let result = amend_proposal(
+        &mut deps,
+        env_at(review_ends_at),
+        "proposer",
+        amend_actions(vec![request_funding(30)]),
+    );
+    assert_eq!(result.unwrap_err(), ProposalNotInReview);
+}
+
+#[test]
+fn proposal_amendments_keep_previous_contents_in_order() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    let review_ends_at = mock_env().block.time.plus_seconds(100);
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            review_ends_at: Some(review_ends_at),
+            ..mock_proposal_info()
+        },
+        review_ends_at.plus_seconds(1000),
+    );
+
+    for amount in [10, 20, 30] {
+        amend_proposal(
+            &mut deps,
+            mock_env(),
+            "proposer",
+            AmendProposalMsg {
+                proposal_id,
+                title: None,
+                description: None,
+                proposal_actions: Some(vec![request_funding(amount)]),
+            },
+        )
+        .unwrap();
+    }
+
+    let amendments = proposal_amendments(&deps, proposal_id, None, None);
+    assert_eq!(
+        amendments
+            .iter()
+            .map(|amendment| (amendment.amendment_id, amendment.proposal_actions.clone()))
+            .collect::<Vec<(u32, Vec<ProposalAction>)>>(),
+        vec![
+            (0, vec![]),
+            (1, vec![request_funding(10)]),
+            (2, vec![request_funding(20)]),
+        ]
+    );
+    assert!(amendments
+        .iter()
+        .all(|amendment| amendment.title == "some label"
+            && amendment.description == "some description"));
+
+    assert_eq!(
+        proposal_amendments(&deps, proposal_id, None, Some(1)),
+        amendments[..1]
+    );
+    assert_eq!(
+        proposal_amendments(&deps, proposal_id, Some(0), Some(1)),
+        amendments[1..2]
+    );
+    assert_eq!(
+        proposal_amendments(&deps, proposal_id, Some(1), None),
+        amendments[2..]
+    );
+}
+
+fn reply_to_action(deps: &mut MockDeps, env: Env, submsg: &SubMsg, result: SubMsgResult) {
+    reply(
+        deps.as_mut(),
+        env,
+        Reply {
+            id: submsg.id,
+            result,
+        },
+    )
+    .unwrap();
+}
+
+fn action_results(deps: &MockDeps, proposal_id: ProposalId) -> Vec<ProposalActionResult> {
+    PROPOSAL_INFOS
+        .load(deps.as_ref().storage, proposal_id)
+        .unwrap()
+        .action_results
+}
+
+#[test]
+fn partial_failure_tolerant_execution_records_each_action_result_and_retries_failed_ones() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &GovConfig {
+                general_execution_timelock: ExecutionTimelock {
+                    execution_delay: 0,
+                    execution_window: Some(100),
+                },
+                ..mock_gov_config()
+            },
+        )
+        .unwrap();
+
+    let executed_at = mock_env().block.time.plus_seconds(1000);
+
+    let proposal_id = create_mock_proposal(
+        &mut deps,
+        &dao,
+        ProposalInfo {
+            proposal_actions: vec![request_funding(1), request_funding(2), request_funding(3)],
+            execution_mode: ProposalExecutionMode::PartialFailureTolerant,
+            ..mock_proposal_info()
+        },
+        executed_at,
+    );
+    PROPOSAL_INFOS
+        .update(deps.as_mut().storage, proposal_id, |info| -> StdResult<_> {
+            Ok(ProposalInfo {
+                executed_at: Some(env_at(executed_at).block),
+                ..info.unwrap()
+            })
+        })
+        .unwrap();
+
+    let contract = mock_env().contract.address;
+    let response = execute(
+        deps.as_mut(),
+        env_at(executed_at),
+        mock_info(contract.as_str(), &[]),
+        ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
+    )
+    .unwrap();
+
+    // each action is executed in a submessage of its own
+    assert_eq!(response.messages.len(), 3);
+    assert!(response
+        .messages
+        .iter()
+        .all(|submsg| submsg.reply_on == ReplyOn::Always));
+    assert_eq!(
+        action_results(&deps, proposal_id),
+        vec![ProposalActionResult::Pending; 3]
+    );
+
+    let success = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+    });
+    let failure = SubMsgResult::Err("codespace: wasm, code: 5".to_string());
+
+    // replies do not necessarily arrive in order of the actions
+    let env = env_at(executed_at);
+    reply_to_action(
+        &mut deps,
+        env.clone(),
+        &response.messages[2],
+        success.clone(),
+    );
+    reply_to_action(
+        &mut deps,
+        env.clone(),
+        &response.messages[1],
+        failure.clone(),
+    );
+    reply_to_action(&mut deps, env, &response.messages[0], success.clone());
+
+    assert_eq!(
+        action_results(&deps, proposal_id),
+        vec![
+            ProposalActionResult::Succeeded,
+            ProposalActionResult::Failed {
+                error: "codespace: wasm, code: 5".to_string()
+            },
+            ProposalActionResult::Succeeded,
+        ]
+    );
+
+    // only the failed action is retried, and it fails again
+    let retry_env = env_at(executed_at.plus_seconds(100));
+    let response = execute(
+        deps.as_mut(),
+        retry_env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RetryProposalActions(RetryProposalActionsMsg { proposal_id }),
+    )
+    .unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        action_results(&deps, proposal_id)[1],
+        ProposalActionResult::Pending
+    );
+
+    reply_to_action(&mut deps, retry_env, &response.messages[0], failure);
+
+    // the retry window counts from the proposal's execution
+    let result = execute(
+        deps.as_mut(),
+        env_at(executed_at.plus_seconds(101)),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RetryProposalActions(RetryProposalActionsMsg { proposal_id }),
+    );
+    assert_eq!(result.unwrap_err(), ProposalActionsRetryWindowPassed);
+}
+
+/// Lets the council request funding and create payment schedules, spending at most 100 uluna
+/// within any 1000 seconds.
+fn set_council_uluna_budget(deps: &mut MockDeps, dao: &MockDao) {
+    COUNCIL_GOV_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Some(CouncilGovConfig {
+                allowed_proposal_action_types: vec![
+                    ProposalActionType::RequestFundingFromDao,
+                    ProposalActionType::CreatePaymentSchedule,
+                ],
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(50),
+                spend_budgets: Some(vec![CouncilSpendBudget {
+                    asset: AssetInfo::native("uluna"),
+                    amount: Uint128::new(100),
+                    period: 1000,
+                }]),
+            }),
+        )
+        .unwrap();
+
+    dao.borrow_mut()
+        .council_member_weights
+        .insert("council_member".to_string(), Uint128::one());
+}
+
+fn create_council_proposal(
+    deps: &mut MockDeps,
+    proposal_actions: Vec<ProposalAction>,
+) -> GovernanceControllerResult<Response> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("council_member", &[]),
+        ExecuteMsg::CreateCouncilProposal(CreateProposalMsg {
+            title: "council proposal".to_string(),
+            description: None,
+            proposal_actions,
+            deposit_owner: None,
+            options: None,
+            execution_mode: None,
+            metadata: None,
+        }),
+    )
+}
+
+fn remaining_council_budget(deps: &MockDeps, env: Env) -> Uint128 {
+    let response: CouncilSpendBudgetsResponse =
+        from_json(query(deps.as_ref(), env, QueryMsg::CouncilSpendBudgets {}).unwrap()).unwrap();
+
+    response.budgets[0].remaining
+}
+
+fn create_passed_council_proposal(
+    deps: &mut MockDeps,
+    dao: &MockDao,
+    proposal_actions: Vec<ProposalAction>,
+    execution_mode: ProposalExecutionMode,
+) -> ProposalId {
+    create_mock_proposal(
+        deps,
+        dao,
+        ProposalInfo {
+            proposal_type: ProposalType::Council,
+            proposal_actions,
+            execution_mode,
+            ..mock_proposal_info()
+        },
+        mock_env().block.time,
+    )
+}
+
+fn execute_proposal_actions(
+    deps: &mut MockDeps,
+    env: Env,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<Response> {
+    let contract = env.contract.address.clone();
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(contract.as_str(), &[]),
+        ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
+    )
+}
+
+fn create_payment_schedule(amount: u128, number_of_payments: u32) -> ProposalAction {
+    ProposalAction::CreatePaymentSchedule(CreatePaymentScheduleProposalActionMsg {
+        remote_treasury_target: None,
+        recipient: "recipient".to_string(),
+        assets: vec![Asset::native("uluna", amount).into()],
+        start_time: None,
+        interval: 100,
+        number_of_payments,
+    })
+}
+
+#[test]
+fn council_proposals_exceeding_spend_budgets_cannot_be_created() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    set_council_uluna_budget(&mut deps, &dao);
+
+    let budget_exceeded = |asset: &str, remaining: u128| CouncilSpendBudgetExceeded {
+        asset: asset.to_string(),
+        remaining: Uint128::new(remaining),
+    };
+
+    let result = create_council_proposal(&mut deps, vec![request_funding(101)]);
+    assert_eq!(result.unwrap_err(), budget_exceeded("native:uluna", 100));
+
+    // spends of all the proposal's actions are summed up
+    let result = create_council_proposal(&mut deps, vec![request_funding(60), request_funding(50)]);
+    assert_eq!(result.unwrap_err(), budget_exceeded("native:uluna", 100));
+
+    // all the payments of a schedule count towards the budget
+    let result = create_council_proposal(&mut deps, vec![create_payment_schedule(20, 6)]);
+    assert_eq!(result.unwrap_err(), budget_exceeded("native:uluna", 100));
+
+    // assets without a budget cannot be spent by the council
+    let result = create_council_proposal(
+        &mut deps,
+        vec![ProposalAction::RequestFundingFromDao(
+            RequestFundingFromDaoMsg {
+                remote_treasury_target: None,
+                recipient: "recipient".to_string(),
+                assets: vec![Asset::native("uusd", 1u128).into()],
+            },
+        )],
+    );
+    assert_eq!(result.unwrap_err(), budget_exceeded("native:uusd", 0));
+
+    create_council_proposal(&mut deps, vec![request_funding(60), request_funding(40)]).unwrap();
+    create_council_proposal(&mut deps, vec![create_payment_schedule(20, 5)]).unwrap();
+
+    // creating proposals does not spend the budget, only executing them does
+    assert_eq!(
+        remaining_council_budget(&deps, mock_env()),
+        Uint128::new(100)
+    );
+}
+
+#[test]
+fn council_spend_budgets_are_rechecked_and_spent_at_execution() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    set_council_uluna_budget(&mut deps, &dao);
+
+    // both proposals fit the budget on their own
+    let first_proposal = create_passed_council_proposal(
+        &mut deps,
+        &dao,
+        vec![request_funding(60)],
+        ProposalExecutionMode::Atomic,
+    );
+    let second_proposal = create_passed_council_proposal(
+        &mut deps,
+        &dao,
+        vec![create_payment_schedule(20, 3)],
+        ProposalExecutionMode::Atomic,
+    );
+
+    execute_proposal_actions(&mut deps, mock_env(), first_proposal).unwrap();
+    assert_eq!(
+        remaining_council_budget(&deps, mock_env()),
+        Uint128::new(40)
+    );
+
+    let result = execute_proposal_actions(&mut deps, mock_env(), second_proposal);
+    assert_eq!(
+        result.unwrap_err(),
+        CouncilSpendBudgetExceeded {
+            asset: "native:uluna".to_string(),
+            remaining: Uint128::new(40),
+        }
+    );
+
+    // the first proposal's spend falls out of the rolling period
+    let period_later = env_at(mock_env().block.time.plus_seconds(1000));
+    assert_eq!(
+        remaining_council_budget(&deps, period_later.clone()),
+        Uint128::new(100)
+    );
+
+    execute_proposal_actions(&mut deps, period_later.clone(), second_proposal).unwrap();
+    assert_eq!(
+        remaining_council_budget(&deps, period_later),
+        Uint128::new(40)
+    );
+}
+
+/// Contents of the whole contract storage, to restore when emulating a reverted submessage.
+fn storage_snapshot(deps: &MockDeps) -> Vec<Record> {
+    deps.storage.range(None, None, Order::Ascending).collect()
+}
+
+fn revert_storage(deps: &mut MockDeps, snapshot: Vec<Record>) {
+    let keys: Vec<Vec<u8>> = deps
+        .storage
+        .range(None, None, Order::Ascending)
+        .map(|(key, _)| key)
+        .collect();
+
+    for key in keys {
+        deps.storage.remove(&key);
+    }
+    for (key, value) in snapshot {
+        deps.storage.set(&key, &value);
+    }
+}
+
+#[test]
+fn failed_partial_failure_tolerant_council_action_does_not_spend_budget() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+    set_council_uluna_budget(&mut deps, &dao);
+
+    let proposal_id = create_passed_council_proposal(
+        &mut deps,
+        &dao,
+        vec![request_funding(60), request_funding(30)],
+        ProposalExecutionMode::PartialFailureTolerant,
+    );
+
+    let response = execute_proposal_actions(&mut deps, mock_env(), proposal_id).unwrap();
+    assert_eq!(response.messages.len(), 2);
+
+    let contract = mock_env().contract.address;
+    let execute_action = |deps: &mut MockDeps, action_index: u32| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(contract.as_str(), &[]),
+            ExecuteMsg::ExecuteProposalAction(ExecuteProposalActionMsg {
+                proposal_id,
+                action_index,
+            }),
+        )
+        .unwrap();
+    };
+
+    execute_action(&mut deps, 0);
+    reply_to_action(
+        &mut deps,
+        mock_env(),
+        &response.messages[0],
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    );
+
+    // the treasury fails to send the funds, reverting everything the action's submessage did
+    let snapshot = storage_snapshot(&deps);
+    execute_action(&mut deps, 1);
+    revert_storage(&mut deps, snapshot);
+    reply_to_action(
+        &mut deps,
+        mock_env(),
+        &response.messages[1],
+        SubMsgResult::Err("insufficient funds".to_string()),
+    );
+
+    assert_eq!(
+        remaining_council_budget(&deps, mock_env()),
+        Uint128::new(40)
+    );
+    assert!(matches!(
+        action_results(&deps, proposal_id)[1],
+        ProposalActionResult::Failed { .. }
+    ));
+}
+
+fn proposal_ids(
+    deps: &MockDeps,
+    tag: Option<&str>,
+    start_after: Option<ProposalId>,
+    limit: Option<u32>,
+) -> Vec<ProposalId> {
+    let response: ProposalsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Proposals(ProposalsParams {
+                filter: None,
+                tag: tag.map(|tag| tag.to_string()),
+                start_after,
+                limit,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response
+        .proposals
+        .into_iter()
+        .map(|proposal| proposal.proposal.id)
+        .collect()
+}
+
+#[test]
+fn proposals_filtered_by_tag_fill_the_whole_page() {
+    let mut deps = mock_dependencies();
+    let dao = mock_dao(&mut deps);
+
+    for tag in ["treasury", "other", "other", "other", "other", "treasury"] {
+        create_mock_proposal(
+            &mut deps,
+            &dao,
+            ProposalInfo {
+                metadata: Some(ProposalMetadata {
+                    discussion_url: None,
+                    content_hash: None,
+                    tags: vec![tag.to_string()],
+                }),
+                ..mock_proposal_info()
+            },
+            mock_env().block.time.plus_seconds(1000),
+        );
+    }
+
+    // tagged proposals are further apart than the page limit
+    assert_eq!(
+        proposal_ids(&deps, Some("treasury"), None, Some(2)),
+        vec![1, 6]
+    );
+    assert_eq!(
+        proposal_ids(&deps, Some("treasury"), None, Some(1)),
+        vec![1]
+    );
+    assert_eq!(
+        proposal_ids(&deps, Some("treasury"), Some(1), Some(1)),
+        vec![6]
+    );
+    assert!(proposal_ids(&deps, Some("treasury"), Some(6), Some(1)).is_empty());
+    assert!(proposal_ids(&deps, Some("none"), None, Some(2)).is_empty());
+
+    assert_eq!(proposal_ids(&deps, None, None, Some(2)), vec![1, 2]);
+}
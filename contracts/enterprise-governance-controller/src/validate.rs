@@ -1,28 +1,36 @@
+use crate::components::query_enterprise_treasury_addr;
+use crate::proposals::PROPOSAL_INFOS;
 use crate::state::{ENTERPRISE_CONTRACT, GOV_CONFIG};
 use common::commons::ModifyValue::Change;
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, StdError, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, StdError, Uint128};
+use cw721::Cw721QueryMsg::OwnerOf;
+use cw721::OwnerOfResponse;
 use cw_asset::{AssetInfo, AssetInfoBase, AssetInfoUnchecked};
 use cw_utils::Duration;
 use enterprise_governance_controller_api::api::ProposalAction::{
-    DistributeFunds, ExecuteMsgs, ModifyMultisigMembership, RequestFundingFromDao,
-    UpdateAssetWhitelist, UpdateCouncil, UpdateGovConfig, UpdateMetadata,
+    DistributeFunds, DistributeFundsTargeted, ExecuteMsgs, ModifyMultisigMembership,
+    RequestFundingFromDao, UpdateAssetWhitelist, UpdateCouncil, UpdateGovConfig, UpdateMetadata,
     UpdateMinimumWeightForRewards, UpdateNftWhitelist, UpgradeDao,
 };
 use enterprise_governance_controller_api::api::{
-    CouncilGovConfig, DaoCouncilSpec, DistributeFundsMsg, ExecuteEnterpriseMsgsMsg, ExecuteMsgsMsg,
-    ExecuteTreasuryMsgsMsg, GovConfig, ModifyMultisigMembershipMsg, ProposalAction,
-    ProposalActionType, RequestFundingFromDaoMsg, UpdateGovConfigMsg,
+    CouncilGovConfig, CouncilSpendBudget, CouncilSpendBudgetSpec,
+    CreatePaymentScheduleProposalActionMsg, CreateProposalMsg, CreateStreamProposalActionMsg,
+    DaoCouncilSpec, DistributeFundsMsg, DistributeFundsTargetedMsg, ExecuteEnterpriseMsgsMsg,
+    ExecuteMsgsMsg, ExecuteTreasuryMsgsMsg, GovConfig, ModifyMultisigMembershipMsg, ProposalAction,
+    ProposalActionType, ProposalCosmosMsg, ProposalDepositOutcome, ProposalMetadata,
+    RequestFundingFromDaoMsg, TransferNftsProposalActionMsg, UpdateGovConfigMsg,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerError::{
-    Dao, DuplicateCouncilMember, InvalidArgument, InvalidCosmosMessage,
-    MaximumProposalActionsExceeded, Std, UnsupportedCouncilProposalAction, UnsupportedCw1155Asset,
-    ZeroVoteDuration,
+    Dao, DuplicateCouncilMember, InvalidArgument, InvalidNumberOfProposalOptions,
+    MaximumProposalActionsExceeded, NftNotHeldByTreasury, NoSuchProposal,
+    ProposalActionsOutsideOfOptions, Std, UnsupportedCouncilProposalAction,
+    UnsupportedProposalDepositOutcome, ZeroVoteDuration,
 };
 use enterprise_governance_controller_api::error::{
     GovernanceControllerError, GovernanceControllerResult,
 };
 use enterprise_outposts_api::api::RemoteTreasuryTarget;
-use enterprise_protocol::api::DaoType::Multisig;
+use enterprise_protocol::api::DaoType::{Multisig, Nft};
 use enterprise_protocol::api::{DaoInfoResponse, DaoType, UpgradeDaoMsg};
 use enterprise_protocol::error::DaoError::{
     MigratingToLowerVersion, VoteDurationLongerThanUnstaking,
@@ -33,6 +41,10 @@ use GovernanceControllerError::{MinimumDepositNotAllowed, UnsupportedOperationFo
 use ProposalAction::ExecuteTreasuryMsgs;
 
 const MAXIMUM_PROPOSAL_ACTIONS: u8 = 10;
+const MAXIMUM_PROPOSAL_OPTIONS: u8 = 10;
+const MAXIMUM_PROPOSAL_TAGS: usize = 10;
+const MAXIMUM_PROPOSAL_TAG_LENGTH: usize = 32;
+const MAXIMUM_PROPOSAL_METADATA_LENGTH: usize = 256;
 
 pub fn validate_dao_gov_config(
     dao_type: &DaoType,
@@ -54,11 +66,71 @@ pub fn validate_dao_gov_config(
         }
     }
 
+    if dao_gov_config.review_period == Some(0) {
+        return Err(InvalidArgument {
+            msg: "Review period cannot be zero".to_string(),
+        });
+    }
+
     // no minimum deposits allowed for multisig DAOs
     if dao_gov_config.minimum_deposit.is_some() && dao_type == &Multisig {
         return Err(MinimumDepositNotAllowed {});
     }
 
+    for timelock in [
+        &dao_gov_config.general_execution_timelock,
+        &dao_gov_config.council_execution_timelock,
+    ] {
+        if timelock.execution_window == Some(0) {
+            return Err(InvalidArgument {
+                msg: "Execution window cannot be zero".to_string(),
+            });
+        }
+    }
+
+    let mut action_types_with_params = HashSet::new();
+
+    for params in &dao_gov_config.action_type_gov_params {
+        if !action_types_with_params.insert(params.action_type.to_string()) {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Duplicate governance parameters for action type {}",
+                    params.action_type
+                ),
+            });
+        }
+
+        if params.vote_duration == Some(0) {
+            return Err(ZeroVoteDuration);
+        }
+
+        if let Some(quorum) = params.quorum {
+            validate_quorum_value(quorum)?;
+        }
+
+        if let Some(threshold) = params.threshold {
+            validate_threshold_value(threshold)?;
+        }
+
+        if let Some(veto_threshold) = params.veto_threshold {
+            validate_gt_zero_lte_one(veto_threshold, "veto threshold".to_string())?;
+        }
+    }
+
+    // NFT deposits can neither be burned nor distributed
+    if dao_type == &Nft {
+        for outcome in dao_gov_config.proposal_deposit_outcomes.all() {
+            if let ProposalDepositOutcome::Burn | ProposalDepositOutcome::DistributeToMembers =
+                outcome
+            {
+                return Err(UnsupportedProposalDepositOutcome {
+                    outcome: outcome.clone(),
+                    dao_type: dao_type.to_string(),
+                });
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -67,7 +139,7 @@ pub fn validate_unlocking_period(
     unlocking_period: Duration,
 ) -> GovernanceControllerResult<()> {
     if let Duration::Time(unlocking_time) = unlocking_period {
-        if unlocking_time < dao_gov_config.vote_duration {
+        if unlocking_time < dao_gov_config.longest_vote_duration() {
             return Err(Dao(VoteDurationLongerThanUnstaking));
         }
     }
@@ -93,6 +165,84 @@ fn validate_gt_zero_lte_one(value: Decimal, value_name: String) -> GovernanceCon
     Ok(())
 }
 
+/// Validates the proposal's actions, or the actions of each of its options
+/// if it is a multiple-choice proposal.
+pub fn validate_proposal_content(
+    deps: Deps,
+    dao_type: DaoType,
+    msg: &CreateProposalMsg,
+) -> GovernanceControllerResult<()> {
+    if let Some(metadata) = &msg.metadata {
+        validate_proposal_metadata(metadata)?;
+    }
+
+    match &msg.options {
+        None => validate_proposal_actions(deps, dao_type, &msg.proposal_actions),
+        Some(options) => {
+            if !msg.proposal_actions.is_empty() {
+                return Err(ProposalActionsOutsideOfOptions);
+            }
+
+            if options.len() < 2 || options.len() > MAXIMUM_PROPOSAL_OPTIONS as usize {
+                return Err(InvalidNumberOfProposalOptions {
+                    maximum: MAXIMUM_PROPOSAL_OPTIONS,
+                });
+            }
+
+            for option in options {
+                validate_proposal_actions(deps, dao_type.clone(), &option.proposal_actions)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn validate_proposal_metadata(metadata: &ProposalMetadata) -> GovernanceControllerResult<()> {
+    for (name, value) in [
+        ("Discussion URL", &metadata.discussion_url),
+        ("Content hash", &metadata.content_hash),
+    ] {
+        if let Some(value) = value {
+            if value.is_empty() || value.len() > MAXIMUM_PROPOSAL_METADATA_LENGTH {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "{} must be between 1 and {} characters long",
+                        name, MAXIMUM_PROPOSAL_METADATA_LENGTH
+                    ),
+                });
+            }
+        }
+    }
+
+    if metadata.tags.len() > MAXIMUM_PROPOSAL_TAGS {
+        return Err(InvalidArgument {
+            msg: format!("Proposal can have at most {} tags", MAXIMUM_PROPOSAL_TAGS),
+        });
+    }
+
+    let mut tags = HashSet::new();
+
+    for tag in &metadata.tags {
+        if tag.is_empty() || tag.len() > MAXIMUM_PROPOSAL_TAG_LENGTH {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Proposal tags must be between 1 and {} characters long",
+                    MAXIMUM_PROPOSAL_TAG_LENGTH
+                ),
+            });
+        }
+
+        if !tags.insert(tag) {
+            return Err(InvalidArgument {
+                msg: format!("Duplicate proposal tag {}", tag),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn validate_proposal_actions(
     deps: Deps,
     dao_type: DaoType,
@@ -105,38 +255,68 @@ pub fn validate_proposal_actions(
     }
 
     for proposal_action in proposal_actions {
-        match proposal_action {
-            UpdateAssetWhitelist(msg) => validate_asset_whitelist_changes(
-                deps,
-                &msg.remote_treasury_target,
-                &msg.add,
-                &msg.remove,
-            )?,
-            UpdateNftWhitelist(msg) => validate_nft_whitelist_changes(deps, &msg.add, &msg.remove)?,
-            UpgradeDao(msg) => validate_upgrade_dao(deps, msg)?,
-            ExecuteMsgs(msg) => validate_execute_msgs(msg)?,
-            ExecuteTreasuryMsgs(msg) => validate_execute_treasury_msgs(msg)?,
-            ProposalAction::ExecuteEnterpriseMsgs(msg) => validate_execute_enterprise_msgs(msg)?,
-            ModifyMultisigMembership(msg) => {
-                validate_modify_multisig_membership(deps, dao_type.clone(), msg)?
-            }
-            UpdateCouncil(msg) => {
-                validate_dao_council(deps, msg.dao_council.clone())?;
-            }
-            DistributeFunds(msg) => validate_distribute_funds(deps, msg)?,
-            RequestFundingFromDao(msg) => validate_request_funding_from_dao(deps, msg)?,
-            UpdateGovConfig(msg) => {
-                let gov_config = GOV_CONFIG.load(deps.storage)?;
+        validate_proposal_action(deps, &dao_type, proposal_action)?;
+    }
 
-                let updated_gov_config = apply_gov_config_changes(gov_config, msg);
+    Ok(())
+}
 
-                validate_dao_gov_config(&dao_type, &updated_gov_config)?;
-            }
-            UpdateMetadata(_) | UpdateMinimumWeightForRewards(_) => {
-                // no-op
+pub fn validate_proposal_action(
+    deps: Deps,
+    dao_type: &DaoType,
+    proposal_action: &ProposalAction,
+) -> GovernanceControllerResult<()> {
+    match proposal_action {
+        UpdateAssetWhitelist(msg) => validate_asset_whitelist_changes(
+            deps,
+            &msg.remote_treasury_target,
+            &msg.add,
+            &msg.remove,
+        )?,
+        UpdateNftWhitelist(msg) => validate_nft_whitelist_changes(deps, &msg.add, &msg.remove)?,
+        UpgradeDao(msg) => validate_upgrade_dao(deps, msg)?,
+        ExecuteMsgs(msg) => validate_execute_msgs(msg)?,
+        ExecuteTreasuryMsgs(msg) => validate_execute_treasury_msgs(msg)?,
+        ProposalAction::ExecuteEnterpriseMsgs(msg) => validate_execute_enterprise_msgs(msg)?,
+        ModifyMultisigMembership(msg) => {
+            validate_modify_multisig_membership(deps, dao_type.clone(), msg)?
+        }
+        UpdateCouncil(msg) => {
+            validate_dao_council(deps, msg.dao_council.clone())?;
+        }
+        DistributeFunds(msg) => validate_distribute_funds(deps, msg)?,
+        DistributeFundsTargeted(msg) => validate_distribute_funds_targeted(deps, msg)?,
+        RequestFundingFromDao(msg) => validate_request_funding_from_dao(deps, msg)?,
+        ProposalAction::TransferNfts(msg) => validate_transfer_nfts(deps, msg)?,
+        ProposalAction::CreateStream(msg) => validate_create_stream(deps, msg)?,
+        ProposalAction::CancelStream(_) => {
+            // no-op, the treasury checks whether the stream exists
+        }
+        ProposalAction::CreatePaymentSchedule(msg) => validate_create_payment_schedule(deps, msg)?,
+        ProposalAction::CancelPaymentSchedule(_) => {
+            // no-op, the treasury checks whether the schedule exists
+        }
+        UpdateGovConfig(msg) => {
+            let gov_config = GOV_CONFIG.load(deps.storage)?;
+
+            let updated_gov_config = apply_gov_config_changes(gov_config, msg);
+
+            validate_dao_gov_config(dao_type, &updated_gov_config)?;
+        }
+        UpdateMetadata(_) | UpdateMinimumWeightForRewards(_) => {
+            // no-op
+        }
+        ProposalAction::DeployCrossChainTreasury(_) => {
+            // TODO: no-op for now, can we even validate anything here?
+        }
+        ProposalAction::CancelProposal(msg) => {
+            if !PROPOSAL_INFOS.has(deps.storage, msg.proposal_id) {
+                return Err(NoSuchProposal);
             }
-            ProposalAction::DeployCrossChainTreasury(_) => {
-                // TODO: no-op for now, can we even validate anything here?
+        }
+        ProposalAction::VetoProposal(msg) => {
+            if !PROPOSAL_INFOS.has(deps.storage, msg.proposal_id) {
+                return Err(NoSuchProposal);
             }
         }
     }
@@ -171,6 +351,35 @@ pub fn apply_gov_config_changes(gov_config: GovConfig, msg: &UpdateGovConfigMsg)
         gov_config.allow_early_proposal_execution = allow_early_proposal_execution;
     }
 
+    if let Change(snapshot_voting) = msg.snapshot_voting {
+        gov_config.snapshot_voting = snapshot_voting;
+    }
+
+    if let Change(proposal_cancellation_cutoff) = msg.proposal_cancellation_cutoff {
+        gov_config.proposal_cancellation_cutoff =
+            proposal_cancellation_cutoff.map(|cutoff| cutoff.u64());
+    }
+
+    if let Change(review_period) = msg.review_period {
+        gov_config.review_period = review_period.map(|period| period.u64());
+    }
+
+    if let Change(proposal_deposit_outcomes) = &msg.proposal_deposit_outcomes {
+        gov_config.proposal_deposit_outcomes = proposal_deposit_outcomes.clone();
+    }
+
+    if let Change(general_execution_timelock) = &msg.general_execution_timelock {
+        gov_config.general_execution_timelock = general_execution_timelock.clone();
+    }
+
+    if let Change(council_execution_timelock) = &msg.council_execution_timelock {
+        gov_config.council_execution_timelock = council_execution_timelock.clone();
+    }
+
+    if let Change(action_type_gov_params) = &msg.action_type_gov_params {
+        gov_config.action_type_gov_params = action_type_gov_params.clone();
+    }
+
     gov_config
 }
 
@@ -356,9 +565,9 @@ fn validate_execute_enterprise_msgs(
     validate_custom_execute_msgs(&msg.msgs)
 }
 
-fn validate_custom_execute_msgs(msgs: &[String]) -> GovernanceControllerResult<()> {
+fn validate_custom_execute_msgs(msgs: &[ProposalCosmosMsg]) -> GovernanceControllerResult<()> {
     for msg in msgs.iter() {
-        serde_json_wasm::from_str::<CosmosMsg>(msg.as_str()).map_err(|_| InvalidCosmosMessage)?;
+        msg.decode()?;
     }
     Ok(())
 }
@@ -400,26 +609,71 @@ pub fn validate_dao_council(
             validate_no_duplicate_council_members(deps, dao_council.members)?;
             validate_allowed_council_proposal_types(
                 dao_council.allowed_proposal_action_types.clone(),
+                dao_council.spend_budgets.is_some(),
             )?;
 
             validate_quorum_value(dao_council.quorum)?;
             validate_threshold_value(dao_council.threshold)?;
 
+            let spend_budgets = dao_council
+                .spend_budgets
+                .map(|budgets| validate_council_spend_budgets(deps, budgets))
+                .transpose()?;
+
             Ok(Some(CouncilGovConfig {
                 allowed_proposal_action_types: dao_council
                     .allowed_proposal_action_types
                     .unwrap_or_else(|| vec![ProposalActionType::UpgradeDao]),
                 quorum: dao_council.quorum,
                 threshold: dao_council.threshold,
+                spend_budgets,
             }))
         }
     }
 }
 
+fn validate_council_spend_budgets(
+    deps: Deps,
+    budgets: Vec<CouncilSpendBudgetSpec>,
+) -> GovernanceControllerResult<Vec<CouncilSpendBudget>> {
+    let mut budgeted_assets = HashSet::new();
+
+    budgets
+        .into_iter()
+        .map(|budget| {
+            let asset = budget.asset.check(deps.api, None)?;
+
+            if !budgeted_assets.insert(asset.to_string()) {
+                return Err(InvalidArgument {
+                    msg: format!("Duplicate council spend budget for {}", asset),
+                });
+            }
+
+            if budget.period == 0 {
+                return Err(InvalidArgument {
+                    msg: "Council spend budget period cannot be zero".to_string(),
+                });
+            }
+
+            Ok(CouncilSpendBudget {
+                asset,
+                amount: budget.amount,
+                period: budget.period,
+            })
+        })
+        .collect()
+}
+
 pub fn validate_distribute_funds(
     deps: Deps,
     msg: &DistributeFundsMsg,
 ) -> GovernanceControllerResult<()> {
+    if msg.stream_duration == Some(0) {
+        return Err(InvalidArgument {
+            msg: "Stream duration must be greater than zero".to_string(),
+        });
+    }
+
     for asset in &msg.funds {
         match &asset.info {
             AssetInfoBase::Native(_) => {
@@ -428,10 +682,8 @@ pub fn validate_distribute_funds(
             AssetInfoBase::Cw20(addr) => {
                 deps.api.addr_validate(addr)?;
             }
-            AssetInfoBase::Cw1155(_, _) => {
-                return Err(Std(StdError::generic_err(
-                    "cw1155 is not supported at this time",
-                )));
+            AssetInfoBase::Cw1155(addr, _) => {
+                deps.api.addr_validate(addr)?;
             }
             _ => return Err(Std(StdError::generic_err("unknown asset type"))),
         }
@@ -440,6 +692,30 @@ pub fn validate_distribute_funds(
     Ok(())
 }
 
+pub fn validate_distribute_funds_targeted(
+    deps: Deps,
+    msg: &DistributeFundsTargetedMsg,
+) -> GovernanceControllerResult<()> {
+    validate_distribute_funds(
+        deps,
+        &DistributeFundsMsg {
+            funds: vec![msg.asset.clone()],
+            stream_duration: None,
+        },
+    )?;
+
+    let allocations = msg
+        .allocations
+        .to_amounts(msg.asset.amount)
+        .map_err(|e| InvalidArgument { msg: e.to_string() })?;
+
+    for allocation in allocations {
+        deps.api.addr_validate(&allocation.user)?;
+    }
+
+    Ok(())
+}
+
 pub fn validate_request_funding_from_dao(
     deps: Deps,
     msg: &RequestFundingFromDaoMsg,
@@ -449,12 +725,56 @@ pub fn validate_request_funding_from_dao(
         deps.api.addr_validate(&msg.recipient)?;
 
         for asset in &msg.assets {
-            // first validate the asset info
-            let checked_asset = asset.check(deps.api, None)?;
+            asset.check(deps.api, None)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates an NFT transfer out of the treasury.
+/// For our own chain's treasury, also checks that it holds all the tokens being transferred.
+/// The treasury itself checks that the NFT contract is whitelisted.
+pub fn validate_transfer_nfts(
+    deps: Deps,
+    msg: &TransferNftsProposalActionMsg,
+) -> GovernanceControllerResult<()> {
+    if msg.token_ids.is_empty() {
+        return Err(InvalidArgument {
+            msg: "No NFT tokens to transfer were given".to_string(),
+        });
+    }
+
+    let mut token_ids = HashSet::new();
+    for token_id in &msg.token_ids {
+        if !token_ids.insert(token_id) {
+            return Err(InvalidArgument {
+                msg: format!("NFT token {} is transferred multiple times", token_id),
+            });
+        }
+    }
+
+    // in case it's for our own chain, we can validate all the parameters
+    if msg.remote_treasury_target.is_none() {
+        let nft_contract = deps.api.addr_validate(&msg.nft_contract)?;
+        deps.api.addr_validate(&msg.recipient)?;
+
+        let treasury = query_enterprise_treasury_addr(deps)?;
 
-            // CW1155 are not supported in treasury operations for now
-            if let AssetInfo::Cw1155(_, _) = checked_asset.info {
-                return Err(UnsupportedCw1155Asset);
+        for token_id in &msg.token_ids {
+            let owner_response: OwnerOfResponse = deps.querier.query_wasm_smart(
+                nft_contract.to_string(),
+                &OwnerOf {
+                    token_id: token_id.clone(),
+                    include_expired: Some(false),
+                },
+            )?;
+
+            if owner_response.owner != treasury.as_ref() {
+                return Err(NftNotHeldByTreasury {
+                    nft_contract: nft_contract.to_string(),
+                    token_id: token_id.clone(),
+                });
             }
         }
     }
@@ -462,6 +782,50 @@ pub fn validate_request_funding_from_dao(
     Ok(())
 }
 
+pub fn validate_create_stream(
+    deps: Deps,
+    msg: &CreateStreamProposalActionMsg,
+) -> GovernanceControllerResult<()> {
+    if msg.duration == 0 || msg.cliff.unwrap_or_default() > msg.duration {
+        return Err(InvalidArgument {
+            msg:
+                "Stream duration must be greater than zero, and its cliff cannot be longer than it"
+                    .to_string(),
+        });
+    }
+
+    // in case it's for our own chain, we can validate all the parameters
+    if msg.remote_treasury_target.is_none() {
+        deps.api.addr_validate(&msg.recipient)?;
+        msg.asset.check(deps.api, None)?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_create_payment_schedule(
+    deps: Deps,
+    msg: &CreatePaymentScheduleProposalActionMsg,
+) -> GovernanceControllerResult<()> {
+    if msg.interval == 0 || msg.number_of_payments == 0 || msg.assets.is_empty() {
+        return Err(InvalidArgument {
+            msg: "Payment schedules must have a non-zero interval, number of payments, and assets"
+                .to_string(),
+        });
+    }
+
+    // in case it's for our own chain, we can validate all the parameters
+    if msg.remote_treasury_target.is_none() {
+        deps.api.addr_validate(&msg.recipient)?;
+
+        for asset in &msg.assets {
+            asset.check(deps.api, None)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn validate_no_duplicate_council_members(
     deps: Deps,
     members: Vec<String>,
@@ -484,23 +848,40 @@ pub fn validate_no_duplicate_council_members(
 
 /// Check if allowed council proposal types contain dangerous types of actions that a council
 /// shouldn't be allowed to do.
+/// Spending actions are only allowed if the council's spending is limited by budgets.
 pub fn validate_allowed_council_proposal_types(
     proposal_action_types: Option<Vec<ProposalActionType>>,
+    has_spend_budgets: bool,
 ) -> GovernanceControllerResult<()> {
     match proposal_action_types {
         None => Ok(()),
         Some(action_types) => {
             for action_type in action_types {
                 match action_type {
+                    ProposalActionType::RequestFundingFromDao
+                    | ProposalActionType::DistributeFunds
+                    | ProposalActionType::DistributeFundsTargeted
+                    | ProposalActionType::CreateStream
+                    | ProposalActionType::CreatePaymentSchedule
+                        if has_spend_budgets =>
+                    {
+                        // allowed, since the council can only spend within its budgets
+                    }
                     ProposalActionType::UpdateGovConfig
                     | ProposalActionType::UpdateCouncil
                     | ProposalActionType::RequestFundingFromDao
+                    | ProposalActionType::TransferNfts
                     | ProposalActionType::ExecuteMsgs
                     | ProposalActionType::ExecuteTreasuryMsgs
                     | ProposalActionType::ExecuteEnterpriseMsgs
                     | ProposalActionType::ModifyMultisigMembership
                     | ProposalActionType::DistributeFunds
-                    | ProposalActionType::UpdateMinimumWeightForRewards => {
+                    | ProposalActionType::DistributeFundsTargeted
+                    | ProposalActionType::UpdateMinimumWeightForRewards
+                    | ProposalActionType::CreateStream
+                    | ProposalActionType::CancelStream
+                    | ProposalActionType::CreatePaymentSchedule
+                    | ProposalActionType::CancelPaymentSchedule => {
                         return Err(UnsupportedCouncilProposalAction {
                             action: action_type,
                         });
@@ -509,7 +890,9 @@ pub fn validate_allowed_council_proposal_types(
                     | ProposalActionType::UpdateAssetWhitelist
                     | ProposalActionType::UpdateNftWhitelist
                     | ProposalActionType::UpgradeDao
-                    | ProposalActionType::DeployCrossChainTreasury => {
+                    | ProposalActionType::DeployCrossChainTreasury
+                    | ProposalActionType::CancelProposal
+                    | ProposalActionType::VetoProposal => {
                         // allowed proposal action types
                     }
                 }
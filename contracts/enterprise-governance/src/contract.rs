@@ -12,7 +12,8 @@ use poll_engine::query::{
     query_simulate_end_poll_status, query_voter,
 };
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, PollStatus, UpdateVotesParams, VoteOutcome,
+    CancelPollParams, CastVoteParams, CastWeightedVoteParams, CreatePollParams, EndPollParams,
+    PollParams, PollStatus, UpdatePollParams, UpdateVotesParams, VoteChoice, WeightedVoteChoice,
 };
 use poll_engine_api::error::PollError::Unauthorized;
 use poll_engine_api::error::PollResult;
@@ -59,8 +60,11 @@ pub fn execute(
     match msg {
         ExecuteMsg::CreatePoll(params) => create_poll(ctx, params),
         ExecuteMsg::CastVote(params) => cast_vote(ctx, params),
+        ExecuteMsg::CastWeightedVote(params) => cast_weighted_vote(ctx, params),
         ExecuteMsg::UpdateVotes(params) => update_votes(ctx, params),
         ExecuteMsg::EndPoll(params) => end_poll(ctx, params),
+        ExecuteMsg::CancelPoll(params) => cancel_poll(ctx, params),
+        ExecuteMsg::UpdatePoll(params) => update_poll(ctx, params),
     }
 }
 
@@ -79,6 +83,12 @@ fn cast_vote(ctx: &mut Context, params: CastVoteParams) -> PollResult<Response>
     Ok(Response::new().add_attribute("action", "cast_vote"))
 }
 
+fn cast_weighted_vote(ctx: &mut Context, params: CastWeightedVoteParams) -> PollResult<Response> {
+    poll_engine::execute::cast_weighted_vote(ctx, params)?;
+
+    Ok(Response::new().add_attribute("action", "cast_weighted_vote"))
+}
+
 fn update_votes(ctx: &mut Context, params: UpdateVotesParams) -> PollResult<Response> {
     let qctx = QueryContext {
         deps: ctx.deps.as_ref(),
@@ -87,19 +97,49 @@ fn update_votes(ctx: &mut Context, params: UpdateVotesParams) -> PollResult<Resp
     let votes = query_voter(&qctx, &params.voter, None, None)?;
 
     for vote in votes.votes {
+        if params.excluded_polls.contains(&vote.poll_id.into()) {
+            continue;
+        }
+
         let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
-        let status = query_poll_status(&qctx, vote.poll_id)?;
-        if let PollStatus::InProgress { ends_at } = status.status {
+        let poll = query_poll(
+            &qctx,
+            PollParams {
+                poll_id: vote.poll_id,
+            },
+        )?
+        .poll;
+        if let PollStatus::InProgress { ends_at } = poll.status {
             if ends_at > ctx.env.block.time {
-                poll_engine::execute::cast_vote(
-                    ctx,
-                    CastVoteParams {
-                        poll_id: vote.poll_id.into(),
-                        outcome: VoteOutcome::from(vote.outcome),
-                        voter: params.voter.to_string(),
-                        amount: params.new_amount,
-                    },
-                )?;
+                if vote.split.is_empty() {
+                    poll_engine::execute::cast_vote(
+                        ctx,
+                        CastVoteParams {
+                            poll_id: vote.poll_id.into(),
+                            outcome: VoteChoice::from_outcome(poll.scheme, vote.outcome),
+                            voter: params.voter.to_string(),
+                            amount: params.new_amount,
+                        },
+                    )?;
+                } else {
+                    // re-cast the split vote with the same weights, rescaling each share
+                    poll_engine::execute::cast_weighted_vote(
+                        ctx,
+                        CastWeightedVoteParams {
+                            poll_id: vote.poll_id.into(),
+                            outcomes: vote
+                                .split
+                                .into_iter()
+                                .map(|share| WeightedVoteChoice {
+                                    outcome: VoteChoice::from_outcome(poll.scheme, share.outcome),
+                                    weight: share.weight,
+                                })
+                                .collect(),
+                            voter: params.voter.to_string(),
+                            amount: params.new_amount,
+                        },
+                    )?;
+                }
             }
         }
     }
@@ -113,6 +153,18 @@ fn end_poll(ctx: &mut Context, params: EndPollParams) -> PollResult<Response> {
     Ok(Response::new().add_attribute("action", "end_poll"))
 }
 
+fn cancel_poll(ctx: &mut Context, params: CancelPollParams) -> PollResult<Response> {
+    poll_engine::execute::cancel_poll(ctx, params)?;
+
+    Ok(Response::new().add_attribute("action", "cancel_poll"))
+}
+
+fn update_poll(ctx: &mut Context, params: UpdatePollParams) -> PollResult<Response> {
+    poll_engine::execute::update_poll(ctx, params)?;
+
+    Ok(Response::new().add_attribute("action", "update_poll"))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, _msg: Reply) -> PollResult<Response> {
     Ok(Response::new())
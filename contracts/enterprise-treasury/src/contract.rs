@@ -8,37 +8,62 @@ use crate::migration_copy_storage::MIGRATED_USER_WEIGHTS;
 use crate::migration_stages::MigrationStage::MigrationInProgress;
 use crate::migration_stages::{MigrationStage, MIGRATION_TO_V_1_0_0_STAGE};
 use crate::nft_staking::NFT_STAKES;
+use crate::payment_schedules::{
+    due_payments, load_payment_schedules, next_payment_schedule_id, next_payment_time,
+    payment_time, remove_payment_schedule, save_payment_schedule, PAYMENT_SCHEDULES,
+};
 use crate::staking::{
     load_total_staked, load_total_staked_at_height, load_total_staked_at_time, CW20_STAKES,
 };
 use crate::state::{Config, CONFIG, NFT_WHITELIST};
+use crate::streams::{
+    assert_locked_amounts_held, assert_unlocked_balance, assert_unlocked_balances,
+    load_locked_amount, load_recipient_streams, lock_amount, next_stream_id, remove_stream,
+    save_stream, unlock_amount, vested_amount, STREAMS,
+};
 use crate::validate::admin_only;
 use common::cw::{Context, QueryContext};
+use common::nft::query_owned_token_ids;
 use cosmwasm_std::Order::Ascending;
 use cosmwasm_std::{
     coin, entry_point, to_json_binary, wasm_execute, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    Env, MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128,
 };
+use cw1155::Cw1155ExecuteMsg;
 use cw2::set_contract_version;
-use cw_asset::{Asset, AssetInfoUnchecked};
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, OwnerOfResponse};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked};
 use cw_storage_plus::Bound;
 use cw_utils::Expiration;
 use enterprise_treasury_api::api::{
-    AssetWhitelistParams, AssetWhitelistResponse, ConfigResponse, DistributeFundsMsg,
+    AssetWhitelistParams, AssetWhitelistResponse, BalancesParams, BalancesResponse,
+    CancelPaymentScheduleMsg, CancelStreamMsg, ClaimScheduledPaymentsMsg, ConfigResponse,
+    CreatePaymentScheduleMsg, CreateStreamMsg, DistributeFundsMsg, DistributeFundsTargetedMsg,
     ExecuteCosmosMsgsMsg, HasIncompleteV2MigrationResponse, HasUnmovedStakesOrClaimsResponse,
-    NftWhitelistParams, NftWhitelistResponse, SetAdminMsg, SpendMsg, UpdateAssetWhitelistMsg,
-    UpdateNftWhitelistMsg,
+    LockedAmountParams, LockedAmountResponse, NftBalance, NftWhitelistParams, NftWhitelistResponse,
+    PaymentSchedule, PaymentScheduleResponse, PaymentSchedulesParams, PaymentSchedulesResponse,
+    SetAdminMsg, SpendMsg, Stream, StreamResponse, StreamsParams, StreamsResponse, TransferNftsMsg,
+    UpdateAssetWhitelistMsg, UpdateNftWhitelistMsg, WithdrawFromStreamMsg,
+};
+use enterprise_treasury_api::error::EnterpriseTreasuryError::{
+    InvalidCosmosMessage, InvalidPaymentSchedule, InvalidStreamDuration, NftNotOwned,
+    NftNotWhitelisted, NoPaymentsDue, NoSuchPaymentSchedule, NoSuchStream, NothingToWithdraw, Std,
 };
-use enterprise_treasury_api::error::EnterpriseTreasuryError::{InvalidCosmosMessage, Std};
 use enterprise_treasury_api::error::EnterpriseTreasuryResult;
 use enterprise_treasury_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use enterprise_treasury_api::response::{
-    execute_distribute_funds_response, execute_execute_cosmos_msgs_response,
-    execute_set_admin_response, execute_spend_response, execute_update_asset_whitelist_response,
-    execute_update_nft_whitelist_response, instantiate_response,
+    execute_cancel_payment_schedule_response, execute_cancel_stream_response,
+    execute_claim_scheduled_payments_response, execute_create_payment_schedule_response,
+    execute_create_stream_response, execute_distribute_funds_response,
+    execute_distribute_funds_targeted_response, execute_execute_cosmos_msgs_response,
+    execute_set_admin_response, execute_spend_response, execute_transfer_nfts_response,
+    execute_update_asset_whitelist_response, execute_update_nft_whitelist_response,
+    execute_withdraw_from_stream_response, instantiate_response,
 };
-use funds_distributor_api::msg::Cw20HookMsg::Distribute;
-use funds_distributor_api::msg::ExecuteMsg::DistributeNative;
+use funds_distributor_api::api::{DistributeTargetedMsg, StreamNativeMsg};
+use funds_distributor_api::msg::Cw1155HookMsg;
+use funds_distributor_api::msg::Cw20HookMsg;
+use funds_distributor_api::msg::ExecuteMsg::{DistributeNative, StreamNative};
 use membership_common_api::api::{
     TotalWeightParams, TotalWeightResponse, UserWeightParams, UserWeightResponse,
 };
@@ -55,6 +80,7 @@ pub const COUNCIL_MEMBERSHIP_CONTRACT_INSTANTIATE_REPLY_ID: u64 = 3;
 pub const ENTERPRISE_GOVERNANCE_CONTROLLER_INSTANTIATE_REPLY_ID: u64 = 4;
 pub const ENTERPRISE_OUTPOSTS_INSTANTIATE_REPLY_ID: u64 = 5;
 pub const EXECUTE_PROPOSAL_ACTIONS_REPLY_ID: u64 = 6;
+pub const EXECUTE_COSMOS_MSGS_REPLY_ID: u64 = 7;
 
 const DEFAULT_QUERY_LIMIT: u8 = 30;
 const MAX_QUERY_LIMIT: u8 = 100;
@@ -100,8 +126,16 @@ pub fn execute(
         ExecuteMsg::UpdateAssetWhitelist(msg) => update_asset_whitelist(ctx, msg),
         ExecuteMsg::UpdateNftWhitelist(msg) => update_nft_whitelist(ctx, msg),
         ExecuteMsg::Spend(msg) => spend(ctx, msg),
+        ExecuteMsg::TransferNfts(msg) => transfer_nfts(ctx, msg),
         ExecuteMsg::DistributeFunds(msg) => distribute_funds(ctx, msg),
+        ExecuteMsg::DistributeFundsTargeted(msg) => distribute_funds_targeted(ctx, msg),
         ExecuteMsg::ExecuteCosmosMsgs(msg) => execute_cosmos_msgs(ctx, msg),
+        ExecuteMsg::CreateStream(msg) => create_stream(ctx, msg),
+        ExecuteMsg::CancelStream(msg) => cancel_stream(ctx, msg),
+        ExecuteMsg::WithdrawFromStream(msg) => withdraw_from_stream(ctx, msg),
+        ExecuteMsg::CreatePaymentSchedule(msg) => create_payment_schedule(ctx, msg),
+        ExecuteMsg::CancelPaymentSchedule(msg) => cancel_payment_schedule(ctx, msg),
+        ExecuteMsg::ClaimScheduledPayments(msg) => claim_scheduled_payments(ctx, msg),
 
         ExecuteMsg::PerformNextMigrationStep { submsgs_limit } => {
             perform_next_migration_step(ctx, submsgs_limit)
@@ -158,20 +192,90 @@ fn update_nft_whitelist(
 fn spend(ctx: &mut Context, msg: SpendMsg) -> EnterpriseTreasuryResult<Response> {
     admin_only(ctx)?;
 
-    // TODO: does not work with CW1155, make sure it does in the future
-    let spend_submsgs = msg
+    let assets = msg
         .assets
         .into_iter()
         .map(|asset_unchecked| asset_unchecked.check(ctx.deps.api, None))
-        .map(|asset_res| match asset_res {
-            Ok(asset) => asset.transfer_msg(msg.recipient.clone()).map(SubMsg::new),
-            Err(e) => Err(e),
-        })
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    assert_unlocked_balances(
+        ctx.deps.as_ref(),
+        &ctx.env,
+        assets.iter().map(|asset| (&asset.info, asset.amount)),
+    )?;
+
+    let spend_submsgs = assets
+        .into_iter()
+        .map(|asset| transfer_asset_msg(&ctx.env, &asset, &msg.recipient).map(SubMsg::new))
         .collect::<StdResult<Vec<SubMsg>>>()?;
 
     Ok(execute_spend_response().add_submessages(spend_submsgs))
 }
 
+/// Creates a message transferring the asset from the treasury to the recipient.
+/// CW1155 tokens have no plain transfer, so the treasury sends them from itself instead.
+fn transfer_asset_msg(env: &Env, asset: &Asset, recipient: &str) -> StdResult<CosmosMsg> {
+    match asset.info {
+        AssetInfo::Cw1155(..) => asset.transfer_from_msg(env.contract.address.clone(), recipient),
+        _ => asset.transfer_msg(recipient),
+    }
+}
+
+fn transfer_nfts(ctx: &mut Context, msg: TransferNftsMsg) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let nft_contract = ctx.deps.api.addr_validate(&msg.nft_contract)?;
+    let recipient = ctx.deps.api.addr_validate(&msg.recipient)?;
+
+    if !NFT_WHITELIST.has(ctx.deps.storage, nft_contract.clone()) {
+        return Err(NftNotWhitelisted {
+            nft_contract: nft_contract.to_string(),
+        });
+    }
+
+    let mut submsgs: Vec<SubMsg> = vec![];
+
+    for token_id in msg.token_ids {
+        let owner_response: OwnerOfResponse = ctx.deps.querier.query_wasm_smart(
+            nft_contract.to_string(),
+            &Cw721QueryMsg::OwnerOf {
+                token_id: token_id.clone(),
+                include_expired: Some(false),
+            },
+        )?;
+
+        if owner_response.owner != ctx.env.contract.address.as_ref() {
+            return Err(NftNotOwned {
+                nft_contract: nft_contract.to_string(),
+                token_id,
+            });
+        }
+
+        let transfer_msg = match &msg.msg {
+            Some(msg) => Cw721ExecuteMsg::SendNft {
+                contract: recipient.to_string(),
+                token_id,
+                msg: msg.clone(),
+            },
+            None => Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id,
+            },
+        };
+
+        submsgs.push(SubMsg::new(wasm_execute(
+            nft_contract.to_string(),
+            &transfer_msg,
+            vec![],
+        )?));
+    }
+
+    Ok(
+        execute_transfer_nfts_response(nft_contract.to_string(), recipient.to_string())
+            .add_submessages(submsgs),
+    )
+}
+
 fn distribute_funds(
     ctx: &mut Context,
     msg: DistributeFundsMsg,
@@ -183,6 +287,20 @@ fn distribute_funds(
         .api
         .addr_validate(&msg.funds_distributor_contract)?;
 
+    let checked_funds = msg
+        .funds
+        .iter()
+        .map(|asset| asset.check(ctx.deps.api, None))
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    assert_unlocked_balances(
+        ctx.deps.as_ref(),
+        &ctx.env,
+        checked_funds
+            .iter()
+            .map(|asset| (&asset.info, asset.amount)),
+    )?;
+
     let mut native_funds: Vec<Coin> = vec![];
     let mut submsgs: Vec<SubMsg> = vec![];
 
@@ -194,24 +312,45 @@ fn distribute_funds(
             AssetInfoUnchecked::Cw20(addr) => {
                 let addr = ctx.deps.api.addr_validate(&addr)?;
                 let asset = Asset::cw20(addr, asset.amount);
+                let hook_msg = match msg.stream_duration {
+                    Some(duration) => Cw20HookMsg::Stream { duration },
+                    None => Cw20HookMsg::Distribute {},
+                };
                 submsgs.push(SubMsg::new(asset.send_msg(
                     funds_distributor.to_string(),
-                    to_json_binary(&Distribute {})?,
+                    to_json_binary(&hook_msg)?,
                 )?))
             }
-            AssetInfoUnchecked::Cw1155(_, _) => {
-                return Err(Std(StdError::generic_err(
-                    "cw1155 assets are not supported at this time",
-                )))
+            AssetInfoUnchecked::Cw1155(addr, token_id) => {
+                let addr = ctx.deps.api.addr_validate(&addr)?;
+                let hook_msg = match msg.stream_duration {
+                    Some(duration) => Cw1155HookMsg::Stream { duration },
+                    None => Cw1155HookMsg::Distribute {},
+                };
+                submsgs.push(SubMsg::new(wasm_execute(
+                    addr.to_string(),
+                    &Cw1155ExecuteMsg::SendFrom {
+                        from: ctx.env.contract.address.to_string(),
+                        to: funds_distributor.to_string(),
+                        token_id,
+                        value: asset.amount,
+                        msg: Some(to_json_binary(&hook_msg)?),
+                    },
+                    vec![],
+                )?))
             }
             _ => return Err(Std(StdError::generic_err("unknown asset type"))),
         }
     }
 
     if native_funds.is_empty().not() {
+        let distribute_msg = match msg.stream_duration {
+            Some(duration) => StreamNative(StreamNativeMsg { duration }),
+            None => DistributeNative {},
+        };
         submsgs.push(SubMsg::new(wasm_execute(
             funds_distributor.to_string(),
-            &DistributeNative {},
+            &distribute_msg,
             native_funds,
         )?));
     }
@@ -219,6 +358,68 @@ fn distribute_funds(
     Ok(execute_distribute_funds_response().add_submessages(submsgs))
 }
 
+fn distribute_funds_targeted(
+    ctx: &mut Context,
+    msg: DistributeFundsTargetedMsg,
+) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let funds_distributor = ctx
+        .deps
+        .api
+        .addr_validate(&msg.funds_distributor_contract)?;
+
+    let checked_asset = msg.asset.check(ctx.deps.api, None)?;
+    assert_unlocked_balance(
+        ctx.deps.as_ref(),
+        &ctx.env,
+        &checked_asset.info,
+        checked_asset.amount,
+    )?;
+
+    // fail early instead of in the funds distributor if the allocations don't add up
+    msg.allocations
+        .to_amounts(checked_asset.amount)
+        .map_err(|e| e.std_err())?;
+
+    let distribute_msg = DistributeTargetedMsg {
+        allocations: msg.allocations,
+    };
+
+    let submsg = match &checked_asset.info {
+        AssetInfo::Native(denom) => SubMsg::new(wasm_execute(
+            funds_distributor.to_string(),
+            &funds_distributor_api::msg::ExecuteMsg::DistributeNativeTargeted(distribute_msg),
+            vec![coin(checked_asset.amount.u128(), denom)],
+        )?),
+        AssetInfo::Cw20(_) => SubMsg::new(checked_asset.send_msg(
+            funds_distributor.to_string(),
+            to_json_binary(
+                &funds_distributor_api::msg::Cw20HookMsg::DistributeTargeted(distribute_msg),
+            )?,
+        )?),
+        AssetInfo::Cw1155(addr, token_id) => SubMsg::new(wasm_execute(
+            addr.to_string(),
+            &Cw1155ExecuteMsg::SendFrom {
+                from: ctx.env.contract.address.to_string(),
+                to: funds_distributor.to_string(),
+                token_id: token_id.clone(),
+                value: checked_asset.amount,
+                msg: Some(to_json_binary(&Cw1155HookMsg::DistributeTargeted(
+                    distribute_msg,
+                ))?),
+            },
+            vec![],
+        )?),
+        _ => return Err(Std(StdError::generic_err("unknown asset type"))),
+    };
+
+    Ok(
+        execute_distribute_funds_targeted_response(checked_asset.info.to_string())
+            .add_submessage(submsg),
+    )
+}
+
 fn execute_cosmos_msgs(
     ctx: &mut Context,
     msg: ExecuteCosmosMsgsMsg,
@@ -233,12 +434,256 @@ fn execute_cosmos_msgs(
         ))
     }
 
+    // once all the messages are executed, check that they did not spend any funds locked in streams
+    if let Some(last_submsg) = submsgs.pop() {
+        submsgs.push(SubMsg::reply_on_success(
+            last_submsg.msg,
+            EXECUTE_COSMOS_MSGS_REPLY_ID,
+        ));
+    }
+
     Ok(execute_execute_cosmos_msgs_response().add_submessages(submsgs))
 }
 
+fn create_stream(ctx: &mut Context, msg: CreateStreamMsg) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let recipient = ctx.deps.api.addr_validate(&msg.recipient)?;
+    let asset = msg.asset.check(ctx.deps.api, None)?;
+
+    if msg.duration == 0 || msg.cliff.unwrap_or_default() > msg.duration {
+        return Err(InvalidStreamDuration);
+    }
+
+    assert_unlocked_balance(ctx.deps.as_ref(), &ctx.env, &asset.info, asset.amount)?;
+
+    let start_time = msg.start_time.unwrap_or(ctx.env.block.time);
+
+    let stream = Stream {
+        id: next_stream_id(ctx.deps.storage)?,
+        recipient: recipient.clone(),
+        asset: asset.info.clone(),
+        amount: asset.amount,
+        withdrawn: Uint128::zero(),
+        start_time,
+        end_time: start_time.plus_seconds(msg.duration),
+        cliff_time: msg.cliff.map(|cliff| start_time.plus_seconds(cliff)),
+    };
+
+    save_stream(ctx.deps.storage, &stream)?;
+    lock_amount(ctx.deps.storage, &asset.info, asset.amount)?;
+
+    Ok(execute_create_stream_response(
+        stream.id,
+        recipient.to_string(),
+    ))
+}
+
+/// Cancels the stream, sending what was vested so far to the recipient.
+/// The unvested remainder stays in the treasury, no longer locked.
+fn cancel_stream(ctx: &mut Context, msg: CancelStreamMsg) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let stream = STREAMS
+        .may_load(ctx.deps.storage, msg.stream_id)?
+        .ok_or(NoSuchStream)?;
+
+    let withdrawable = vested_amount(&stream, ctx.env.block.time)? - stream.withdrawn;
+
+    remove_stream(ctx.deps.storage, &stream);
+    unlock_amount(
+        ctx.deps.storage,
+        &stream.asset,
+        stream.amount - stream.withdrawn,
+    )?;
+
+    let mut response = execute_cancel_stream_response(stream.id);
+
+    if !withdrawable.is_zero() {
+        let asset = Asset::new(stream.asset, withdrawable);
+        response = response.add_submessage(SubMsg::new(transfer_asset_msg(
+            &ctx.env,
+            &asset,
+            stream.recipient.as_str(),
+        )?));
+    }
+
+    Ok(response)
+}
+
+fn withdraw_from_stream(
+    ctx: &mut Context,
+    msg: WithdrawFromStreamMsg,
+) -> EnterpriseTreasuryResult<Response> {
+    let stream = STREAMS
+        .may_load(ctx.deps.storage, msg.stream_id)?
+        .ok_or(NoSuchStream)?;
+
+    let withdrawable = vested_amount(&stream, ctx.env.block.time)? - stream.withdrawn;
+
+    if withdrawable.is_zero() {
+        return Err(NothingToWithdraw);
+    }
+
+    let stream = Stream {
+        withdrawn: stream.withdrawn + withdrawable,
+        ..stream
+    };
+
+    // fully withdrawn streams are no longer active
+    if stream.withdrawn == stream.amount {
+        remove_stream(ctx.deps.storage, &stream);
+    } else {
+        save_stream(ctx.deps.storage, &stream)?;
+    }
+
+    unlock_amount(ctx.deps.storage, &stream.asset, withdrawable)?;
+
+    let asset = Asset::new(stream.asset.clone(), withdrawable);
+
+    Ok(
+        execute_withdraw_from_stream_response(stream.id, withdrawable).add_submessage(SubMsg::new(
+            transfer_asset_msg(&ctx.env, &asset, stream.recipient.as_str())?,
+        )),
+    )
+}
+
+fn create_payment_schedule(
+    ctx: &mut Context,
+    msg: CreatePaymentScheduleMsg,
+) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let recipient = ctx.deps.api.addr_validate(&msg.recipient)?;
+
+    if msg.interval == 0 || msg.number_of_payments == 0 || msg.assets.is_empty() {
+        return Err(InvalidPaymentSchedule);
+    }
+
+    let assets = msg
+        .assets
+        .into_iter()
+        .map(|asset_unchecked| asset_unchecked.check(ctx.deps.api, None))
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    let schedule = PaymentSchedule {
+        id: next_payment_schedule_id(ctx.deps.storage)?,
+        recipient: recipient.clone(),
+        assets,
+        start_time: msg.start_time.unwrap_or(ctx.env.block.time),
+        interval: msg.interval,
+        number_of_payments: msg.number_of_payments,
+        payments_made: 0,
+    };
+
+    // the last payment has to be representable
+    payment_time(&schedule, schedule.number_of_payments).map_err(|_| InvalidPaymentSchedule)?;
+
+    // everything the schedule will pay out is locked in the treasury until paid out or cancelled
+    let total_amounts = schedule
+        .assets
+        .iter()
+        .map(|asset| {
+            let total_amount = asset
+                .amount
+                .checked_mul(Uint128::from(schedule.number_of_payments))?;
+            Ok((&asset.info, total_amount))
+        })
+        .collect::<StdResult<Vec<(&AssetInfo, Uint128)>>>()?;
+
+    assert_unlocked_balances(ctx.deps.as_ref(), &ctx.env, total_amounts.iter().copied())?;
+
+    for (asset, total_amount) in total_amounts {
+        lock_amount(ctx.deps.storage, asset, total_amount)?;
+    }
+
+    save_payment_schedule(ctx.deps.storage, &schedule)?;
+
+    Ok(execute_create_payment_schedule_response(
+        schedule.id,
+        recipient.to_string(),
+    ))
+}
+
+/// Cancels the schedule. Payments that are due, but not yet claimed, are not paid out.
+/// Everything the schedule has not paid out stays in the treasury, no longer locked.
+fn cancel_payment_schedule(
+    ctx: &mut Context,
+    msg: CancelPaymentScheduleMsg,
+) -> EnterpriseTreasuryResult<Response> {
+    admin_only(ctx)?;
+
+    let schedule = PAYMENT_SCHEDULES
+        .may_load(ctx.deps.storage, msg.schedule_id)?
+        .ok_or(NoSuchPaymentSchedule)?;
+
+    remove_payment_schedule(ctx.deps.storage, &schedule);
+
+    let remaining_payments = schedule.number_of_payments - schedule.payments_made;
+
+    for asset in &schedule.assets {
+        let remaining_amount = asset
+            .amount
+            .checked_mul(Uint128::from(remaining_payments))?;
+        unlock_amount(ctx.deps.storage, &asset.info, remaining_amount)?;
+    }
+
+    Ok(execute_cancel_payment_schedule_response(schedule.id))
+}
+
+fn claim_scheduled_payments(
+    ctx: &mut Context,
+    msg: ClaimScheduledPaymentsMsg,
+) -> EnterpriseTreasuryResult<Response> {
+    let schedule = PAYMENT_SCHEDULES
+        .may_load(ctx.deps.storage, msg.schedule_id)?
+        .ok_or(NoSuchPaymentSchedule)?;
+
+    let payments = due_payments(&schedule, ctx.env.block.time);
+
+    if payments == 0 {
+        return Err(NoPaymentsDue);
+    }
+
+    let mut submsgs = vec![];
+
+    for asset in &schedule.assets {
+        let amount = asset.amount.checked_mul(Uint128::from(payments))?;
+
+        // the payments were locked when the schedule was created
+        unlock_amount(ctx.deps.storage, &asset.info, amount)?;
+
+        let payment = Asset::new(asset.info.clone(), amount);
+        submsgs.push(SubMsg::new(transfer_asset_msg(
+            &ctx.env,
+            &payment,
+            schedule.recipient.as_str(),
+        )?));
+    }
+
+    let schedule = PaymentSchedule {
+        payments_made: schedule.payments_made + payments,
+        ..schedule
+    };
+
+    // schedules that paid out everything are no longer active
+    if schedule.payments_made == schedule.number_of_payments {
+        remove_payment_schedule(ctx.deps.storage, &schedule);
+    } else {
+        save_payment_schedule(ctx.deps.storage, &schedule)?;
+    }
+
+    Ok(execute_claim_scheduled_payments_response(schedule.id, payments).add_submessages(submsgs))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> EnterpriseTreasuryResult<Response> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> EnterpriseTreasuryResult<Response> {
     match msg.id {
+        EXECUTE_COSMOS_MSGS_REPLY_ID => {
+            assert_locked_amounts_held(deps.as_ref(), &env)?;
+
+            Ok(Response::new())
+        }
         // TODO: remove this too
         EXECUTE_PROPOSAL_ACTIONS_REPLY_ID => {
             // no actions, regardless of the result
@@ -257,6 +702,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> EnterpriseTreasuryResult<Bi
         QueryMsg::Config {} => to_json_binary(&query_config(qctx)?)?,
         QueryMsg::AssetWhitelist(params) => to_json_binary(&query_asset_whitelist(qctx, params)?)?,
         QueryMsg::NftWhitelist(params) => to_json_binary(&query_nft_whitelist(qctx, params)?)?,
+        QueryMsg::Balances(params) => to_json_binary(&query_balances(qctx, params)?)?,
+        QueryMsg::Streams(params) => to_json_binary(&query_streams(qctx, params)?)?,
+        QueryMsg::PaymentSchedules(params) => {
+            to_json_binary(&query_payment_schedules(qctx, params)?)?
+        }
+        QueryMsg::LockedAmount(params) => to_json_binary(&query_locked_amount(qctx, params)?)?,
         QueryMsg::HasIncompleteV2Migration {} => {
             to_json_binary(&query_has_incomplete_v2_migration(qctx)?)?
         }
@@ -287,7 +738,17 @@ pub fn query_asset_whitelist(
         .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
         .min(MAX_QUERY_LIMIT as u32) as usize;
 
-    let assets = if let Some(start_after) = params.start_after {
+    let assets = get_whitelisted_assets(qctx, params.start_after, limit)?;
+
+    Ok(AssetWhitelistResponse { assets })
+}
+
+fn get_whitelisted_assets(
+    qctx: QueryContext,
+    start_after: Option<AssetInfoUnchecked>,
+    limit: usize,
+) -> EnterpriseTreasuryResult<Vec<AssetInfo>> {
+    let assets = if let Some(start_after) = start_after {
         match start_after {
             AssetInfoUnchecked::Native(denom) => {
                 get_whitelisted_assets_starting_with_native(qctx, Some(denom), limit)?
@@ -306,7 +767,60 @@ pub fn query_asset_whitelist(
         get_whitelisted_assets_starting_with_native(qctx, None, limit)?
     };
 
-    Ok(AssetWhitelistResponse { assets })
+    Ok(assets)
+}
+
+pub fn query_balances(
+    qctx: QueryContext,
+    params: BalancesParams,
+) -> EnterpriseTreasuryResult<BalancesResponse> {
+    let asset_limit = params
+        .asset_limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32) as usize;
+    let nft_limit = params
+        .nft_limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32) as usize;
+
+    let treasury = qctx.env.contract.address.clone();
+
+    let assets = get_whitelisted_assets(qctx.clone(), params.asset_start_after, asset_limit)?
+        .into_iter()
+        .map(|asset| {
+            let balance = asset.query_balance(&qctx.deps.querier, treasury.to_string())?;
+            Ok(Asset::new(asset, balance))
+        })
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    let nft_start_after = params
+        .nft_start_after
+        .map(|addr| qctx.deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let nfts = NFT_WHITELIST
+        .keys(qctx.deps.storage, nft_start_after, None, Ascending)
+        .take(nft_limit)
+        .collect::<StdResult<Vec<Addr>>>()?
+        .into_iter()
+        .map(|nft_contract| {
+            let owned_token_ids = query_owned_token_ids(
+                qctx.deps,
+                &nft_contract,
+                &treasury,
+                None,
+                params.token_ids_limit,
+            )?;
+            Ok(NftBalance {
+                nft_contract,
+                token_ids: owned_token_ids.token_ids,
+                token_ids_start_after: owned_token_ids.next_start_after,
+            })
+        })
+        .collect::<StdResult<Vec<NftBalance>>>()?;
+
+    Ok(BalancesResponse { assets, nfts })
 }
 
 pub fn query_nft_whitelist(
@@ -335,6 +849,76 @@ pub fn query_nft_whitelist(
     Ok(NftWhitelistResponse { nfts })
 }
 
+pub fn query_streams(
+    qctx: QueryContext,
+    params: StreamsParams,
+) -> EnterpriseTreasuryResult<StreamsResponse> {
+    let recipient = qctx.deps.api.addr_validate(&params.recipient)?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32) as usize;
+
+    let now = qctx.env.block.time;
+
+    let streams = load_recipient_streams(qctx.deps.storage, recipient, params.start_after, limit)?
+        .into_iter()
+        .map(|stream| {
+            let vested = vested_amount(&stream, now)?;
+
+            Ok(StreamResponse {
+                withdrawable: vested - stream.withdrawn,
+                vested,
+                stream,
+            })
+        })
+        .collect::<StdResult<Vec<StreamResponse>>>()?;
+
+    Ok(StreamsResponse { streams })
+}
+
+pub fn query_locked_amount(
+    qctx: QueryContext,
+    params: LockedAmountParams,
+) -> EnterpriseTreasuryResult<LockedAmountResponse> {
+    let asset = params.asset.check(qctx.deps.api, None)?;
+    let amount = load_locked_amount(qctx.deps.storage, &asset)?;
+
+    Ok(LockedAmountResponse { asset, amount })
+}
+
+pub fn query_payment_schedules(
+    qctx: QueryContext,
+    params: PaymentSchedulesParams,
+) -> EnterpriseTreasuryResult<PaymentSchedulesResponse> {
+    let recipient = params
+        .recipient
+        .map(|recipient| qctx.deps.api.addr_validate(&recipient))
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32) as usize;
+
+    let now = qctx.env.block.time;
+
+    let schedules =
+        load_payment_schedules(qctx.deps.storage, recipient, params.start_after, limit)?
+            .into_iter()
+            .map(|schedule| -> StdResult<PaymentScheduleResponse> {
+                Ok(PaymentScheduleResponse {
+                    claimable_payments: due_payments(&schedule, now),
+                    next_payment_time: next_payment_time(&schedule, now)?,
+                    schedule,
+                })
+            })
+            .collect::<StdResult<Vec<PaymentScheduleResponse>>>()?;
+
+    Ok(PaymentSchedulesResponse { schedules })
+}
+
 pub fn query_user_weight(
     qctx: QueryContext,
     params: UserWeightParams,
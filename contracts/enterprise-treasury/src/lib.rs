@@ -6,8 +6,10 @@ pub mod migration;
 pub mod migration_copy_storage;
 pub mod migration_stages;
 mod nft_staking;
+mod payment_schedules;
 mod staking;
 pub mod state;
+mod streams;
 pub mod validate;
 
 #[cfg(test)]
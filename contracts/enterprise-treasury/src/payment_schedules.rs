@@ -0,0 +1,96 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, OverflowError, OverflowOperation, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+use enterprise_treasury_api::api::{PaymentSchedule, PaymentScheduleId};
+
+pub const PAYMENT_SCHEDULES: Map<PaymentScheduleId, PaymentSchedule> =
+    Map::new("payment_schedules");
+
+/// Index of payment schedules by their recipient, (recipient, schedule ID) -> ().
+const RECIPIENT_PAYMENT_SCHEDULES: Map<(Addr, PaymentScheduleId), ()> =
+    Map::new("recipient_payment_schedules");
+
+const LAST_PAYMENT_SCHEDULE_ID: Item<PaymentScheduleId> = Item::new("last_payment_schedule_id");
+
+pub fn next_payment_schedule_id(store: &mut dyn Storage) -> StdResult<PaymentScheduleId> {
+    let schedule_id = LAST_PAYMENT_SCHEDULE_ID
+        .may_load(store)?
+        .unwrap_or_default()
+        + 1;
+    LAST_PAYMENT_SCHEDULE_ID.save(store, &schedule_id)?;
+
+    Ok(schedule_id)
+}
+
+pub fn save_payment_schedule(store: &mut dyn Storage, schedule: &PaymentSchedule) -> StdResult<()> {
+    PAYMENT_SCHEDULES.save(store, schedule.id, schedule)?;
+    RECIPIENT_PAYMENT_SCHEDULES.save(store, (schedule.recipient.clone(), schedule.id), &())?;
+
+    Ok(())
+}
+
+pub fn remove_payment_schedule(store: &mut dyn Storage, schedule: &PaymentSchedule) {
+    PAYMENT_SCHEDULES.remove(store, schedule.id);
+    RECIPIENT_PAYMENT_SCHEDULES.remove(store, (schedule.recipient.clone(), schedule.id));
+}
+
+pub fn load_payment_schedules(
+    store: &dyn Storage,
+    recipient: Option<Addr>,
+    start_after: Option<PaymentScheduleId>,
+    limit: usize,
+) -> StdResult<Vec<PaymentSchedule>> {
+    let start_after = start_after.map(Bound::exclusive);
+
+    match recipient {
+        Some(recipient) => RECIPIENT_PAYMENT_SCHEDULES
+            .prefix(recipient)
+            .keys(store, start_after, None, Ascending)
+            .take(limit)
+            .map(|schedule_id| PAYMENT_SCHEDULES.load(store, schedule_id?))
+            .collect(),
+        None => PAYMENT_SCHEDULES
+            .range(store, start_after, None, Ascending)
+            .take(limit)
+            .map(|res| res.map(|(_, schedule)| schedule))
+            .collect(),
+    }
+}
+
+/// Number of the schedule's payments that are due at the given time, but not yet paid out.
+pub fn due_payments(schedule: &PaymentSchedule, now: Timestamp) -> u32 {
+    if now < schedule.start_time {
+        return 0;
+    }
+
+    let elapsed_intervals = (now.seconds() - schedule.start_time.seconds()) / schedule.interval;
+    let elapsed_payments = elapsed_intervals.min(schedule.number_of_payments as u64) as u32;
+
+    elapsed_payments.saturating_sub(schedule.payments_made)
+}
+
+/// When the schedule's next payment that is not due at the given time becomes due.
+pub fn next_payment_time(
+    schedule: &PaymentSchedule,
+    now: Timestamp,
+) -> StdResult<Option<Timestamp>> {
+    let next_payment = schedule.payments_made + due_payments(schedule, now) + 1;
+
+    if next_payment > schedule.number_of_payments {
+        Ok(None)
+    } else {
+        payment_time(schedule, next_payment).map(Some)
+    }
+}
+
+/// When the schedule's given payment (counting from 1) becomes due.
+pub fn payment_time(schedule: &PaymentSchedule, payment: u32) -> StdResult<Timestamp> {
+    let nanos = schedule
+        .interval
+        .checked_mul(payment as u64)
+        .and_then(|offset| offset.checked_mul(1_000_000_000))
+        .and_then(|offset| schedule.start_time.nanos().checked_add(offset))
+        .ok_or_else(|| OverflowError::new(OverflowOperation::Mul, schedule.interval, payment))?;
+
+    Ok(Timestamp::from_nanos(nanos))
+}
@@ -0,0 +1,167 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Deps, Env, StdResult, Storage, Timestamp, Uint128};
+use cw_asset::{AssetInfo, AssetInfoUnchecked};
+use cw_storage_plus::{Bound, Item, Map};
+use enterprise_treasury_api::api::{Stream, StreamId};
+use enterprise_treasury_api::error::EnterpriseTreasuryError::{
+    InsufficientUnlockedBalance, LockedBalanceSpent,
+};
+use enterprise_treasury_api::error::EnterpriseTreasuryResult;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+pub const STREAMS: Map<StreamId, Stream> = Map::new("streams");
+
+/// Index of streams by their recipient, (recipient, stream ID) -> ().
+const RECIPIENT_STREAMS: Map<(Addr, StreamId), ()> = Map::new("recipient_streams");
+
+const LAST_STREAM_ID: Item<StreamId> = Item::new("last_stream_id");
+
+/// Amounts of assets locked in streams and payment schedules, keyed by the asset's string representation.
+const LOCKED_AMOUNTS: Map<String, Uint128> = Map::new("stream_locked_amounts");
+
+pub fn next_stream_id(store: &mut dyn Storage) -> StdResult<StreamId> {
+    let stream_id = LAST_STREAM_ID.may_load(store)?.unwrap_or_default() + 1;
+    LAST_STREAM_ID.save(store, &stream_id)?;
+
+    Ok(stream_id)
+}
+
+pub fn save_stream(store: &mut dyn Storage, stream: &Stream) -> StdResult<()> {
+    STREAMS.save(store, stream.id, stream)?;
+    RECIPIENT_STREAMS.save(store, (stream.recipient.clone(), stream.id), &())?;
+
+    Ok(())
+}
+
+pub fn remove_stream(store: &mut dyn Storage, stream: &Stream) {
+    STREAMS.remove(store, stream.id);
+    RECIPIENT_STREAMS.remove(store, (stream.recipient.clone(), stream.id));
+}
+
+pub fn load_recipient_streams(
+    store: &dyn Storage,
+    recipient: Addr,
+    start_after: Option<StreamId>,
+    limit: usize,
+) -> StdResult<Vec<Stream>> {
+    RECIPIENT_STREAMS
+        .prefix(recipient)
+        .keys(store, start_after.map(Bound::exclusive), None, Ascending)
+        .take(limit)
+        .map(|stream_id| STREAMS.load(store, stream_id?))
+        .collect()
+}
+
+/// Amount of the stream vested at the given time, including the amount already withdrawn.
+/// Nothing is vested before the cliff passes.
+pub fn vested_amount(stream: &Stream, now: Timestamp) -> StdResult<Uint128> {
+    let vesting_from = stream.cliff_time.unwrap_or(stream.start_time);
+
+    if now < vesting_from || now <= stream.start_time {
+        return Ok(Uint128::zero());
+    }
+
+    if now >= stream.end_time {
+        return Ok(stream.amount);
+    }
+
+    let elapsed = now.seconds() - stream.start_time.seconds();
+    let duration = stream.end_time.seconds() - stream.start_time.seconds();
+
+    Ok(stream.amount.multiply_ratio(elapsed, duration))
+}
+
+pub fn lock_amount(store: &mut dyn Storage, asset: &AssetInfo, amount: Uint128) -> StdResult<()> {
+    LOCKED_AMOUNTS.update(store, asset.to_string(), |locked| -> StdResult<_> {
+        Ok(locked.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    Ok(())
+}
+
+pub fn unlock_amount(store: &mut dyn Storage, asset: &AssetInfo, amount: Uint128) -> StdResult<()> {
+    LOCKED_AMOUNTS.update(store, asset.to_string(), |locked| -> StdResult<_> {
+        Ok(locked.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    Ok(())
+}
+
+/// Amount of the asset locked in streams and payment schedules.
+pub fn load_locked_amount(store: &dyn Storage, asset: &AssetInfo) -> StdResult<Uint128> {
+    Ok(LOCKED_AMOUNTS
+        .may_load(store, asset.to_string())?
+        .unwrap_or_default())
+}
+
+/// Fails if the treasury's balance of the asset not locked in streams or payment schedules
+/// is lower than the amount.
+pub fn assert_unlocked_balance(
+    deps: Deps,
+    env: &Env,
+    asset: &AssetInfo,
+    amount: Uint128,
+) -> EnterpriseTreasuryResult<()> {
+    let locked = load_locked_amount(deps.storage, asset)?;
+
+    let balance = asset.query_balance(&deps.querier, env.contract.address.to_string())?;
+    let available = balance.saturating_sub(locked);
+
+    if amount > available {
+        return Err(InsufficientUnlockedBalance {
+            asset: asset.to_string(),
+            available,
+        });
+    }
+
+    Ok(())
+}
+
+/// Fails if the treasury's balance of any of the assets not locked in streams or payment schedules
+/// is lower than the total amount of that asset.
+/// Assets listed more than once are summed up before checking.
+pub fn assert_unlocked_balances<'a>(
+    deps: Deps,
+    env: &Env,
+    assets: impl IntoIterator<Item = (&'a AssetInfo, Uint128)>,
+) -> EnterpriseTreasuryResult<()> {
+    let mut total_amounts: BTreeMap<String, (&AssetInfo, Uint128)> = BTreeMap::new();
+
+    for (asset, amount) in assets {
+        let (_, total_amount) = total_amounts
+            .entry(asset.to_string())
+            .or_insert((asset, Uint128::zero()));
+        *total_amount = total_amount.checked_add(amount)?;
+    }
+
+    for (asset, total_amount) in total_amounts.into_values() {
+        assert_unlocked_balance(deps, env, asset, total_amount)?;
+    }
+
+    Ok(())
+}
+
+/// Fails if the treasury's balance of any asset is lower than the amount of it locked
+/// in streams and payment schedules.
+/// Checked after executing arbitrary Cosmos messages, since what they spend is not known upfront.
+pub fn assert_locked_amounts_held(deps: Deps, env: &Env) -> EnterpriseTreasuryResult<()> {
+    for locked_amount in LOCKED_AMOUNTS.range(deps.storage, None, None, Ascending) {
+        let (asset, locked) = locked_amount?;
+
+        if locked.is_zero() {
+            continue;
+        }
+
+        let asset = AssetInfoUnchecked::from_str(&asset)?.check(deps.api, None)?;
+        let balance = asset.query_balance(&deps.querier, env.contract.address.to_string())?;
+
+        if balance < locked {
+            return Err(LockedBalanceSpent {
+                asset: asset.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
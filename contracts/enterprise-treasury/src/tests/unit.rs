@@ -1 +1,552 @@
+use crate::contract::{execute, instantiate, query, reply, EXECUTE_COSMOS_MSGS_REPLY_ID};
+use crate::payment_schedules::next_payment_time;
+use crate::streams::vested_amount;
+use cosmwasm_std::testing::{
+    mock_dependencies_with_balance, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, to_json_string, Addr, BankMsg, ContractResult, CosmosMsg,
+    OwnedDeps, Reply, ReplyOn, SubMsgResponse, SubMsgResult, SystemResult, Timestamp, Uint128,
+    WasmMsg, WasmQuery,
+};
+use cw1155::{BalanceResponse, Cw1155ExecuteMsg, Cw1155QueryMsg};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_asset::{AssetInfo, AssetInfoUnchecked, AssetUnchecked};
+use enterprise_treasury_api::api::{
+    BalancesParams, BalancesResponse, CancelPaymentScheduleMsg, ClaimScheduledPaymentsMsg,
+    CreatePaymentScheduleMsg, CreateStreamMsg, DistributeFundsMsg, ExecuteCosmosMsgsMsg,
+    LockedAmountParams, LockedAmountResponse, NftBalance, PaymentSchedule, SpendMsg, Stream,
+    WithdrawFromStreamMsg,
+};
+use enterprise_treasury_api::error::EnterpriseTreasuryError::{
+    InsufficientUnlockedBalance, InvalidPaymentSchedule, LockedBalanceSpent,
+};
+use enterprise_treasury_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use funds_distributor_api::api::StreamNativeMsg;
+use funds_distributor_api::msg::{Cw1155HookMsg, Cw20HookMsg};
 
+const ADMIN: &str = "admin";
+
+fn mock_stream(start_time: Timestamp, duration: u64, cliff: Option<u64>) -> Stream {
+    Stream {
+        id: 1,
+        recipient: Addr::unchecked("recipient"),
+        asset: AssetInfo::native("uluna"),
+        amount: Uint128::new(1000),
+        withdrawn: Uint128::zero(),
+        start_time,
+        end_time: start_time.plus_seconds(duration),
+        cliff_time: cliff.map(|cliff| start_time.plus_seconds(cliff)),
+    }
+}
+
+fn instantiate_treasury(balance: u128) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies_with_balance(&coins(balance, "uluna"));
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            asset_whitelist: None,
+            nft_whitelist: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+#[test]
+fn stream_vests_linearly_between_start_and_end() {
+    let start = Timestamp::from_seconds(1000);
+    let stream = mock_stream(start, 100, None);
+
+    let vested = |seconds: u64| vested_amount(&stream, start.plus_seconds(seconds)).unwrap();
+
+    assert_eq!(
+        vested_amount(&stream, Timestamp::from_seconds(500)).unwrap(),
+        Uint128::zero()
+    );
+    assert_eq!(vested(0), Uint128::zero());
+    assert_eq!(vested(25), Uint128::new(250));
+    assert_eq!(vested(99), Uint128::new(990));
+    assert_eq!(vested(100), Uint128::new(1000));
+    assert_eq!(vested(1000), Uint128::new(1000));
+}
+
+#[test]
+fn nothing_vests_before_stream_cliff() {
+    let start = Timestamp::from_seconds(1000);
+    let stream = mock_stream(start, 100, Some(40));
+
+    let vested = |seconds: u64| vested_amount(&stream, start.plus_seconds(seconds)).unwrap();
+
+    assert_eq!(vested(39), Uint128::zero());
+    // everything vested since the start becomes available at once when the cliff passes
+    assert_eq!(vested(40), Uint128::new(400));
+    assert_eq!(vested(70), Uint128::new(700));
+    assert_eq!(vested(100), Uint128::new(1000));
+}
+
+#[test]
+fn cosmos_msgs_cannot_spend_funds_locked_in_streams() {
+    let mut deps = instantiate_treasury(1000);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CreateStream(CreateStreamMsg {
+            recipient: "recipient".to_string(),
+            asset: AssetUnchecked::native("uluna", 600u128),
+            start_time: None,
+            duration: 100,
+            cliff: None,
+        }),
+    )
+    .unwrap();
+
+    let send_msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: "someone".to_string(),
+        amount: coins(500, "uluna"),
+    });
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&send_msg).unwrap()],
+        }),
+    )
+    .unwrap();
+
+    let last_submsg = response.messages.last().unwrap();
+    assert_eq!(last_submsg.msg, send_msg);
+    assert_eq!(last_submsg.id, EXECUTE_COSMOS_MSGS_REPLY_ID);
+    assert_eq!(last_submsg.reply_on, ReplyOn::Success);
+
+    let executed_reply = || Reply {
+        id: EXECUTE_COSMOS_MSGS_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+
+    // the messages spent part of the 600 locked in the stream
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(500, "uluna"));
+    let result = reply(deps.as_mut(), mock_env(), executed_reply());
+    assert_eq!(
+        result.unwrap_err(),
+        LockedBalanceSpent {
+            asset: "native:uluna".to_string()
+        }
+    );
+
+    // the messages only spent funds that were not locked
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(600, "uluna"));
+    assert!(reply(deps.as_mut(), mock_env(), executed_reply()).is_ok());
+}
+
+fn spend(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, amount: u128) -> bool {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Spend(SpendMsg {
+            recipient: "someone".to_string(),
+            assets: vec![AssetUnchecked::native("uluna", amount)],
+        }),
+    )
+    .is_ok()
+}
+
+fn create_payment_schedule_msg(interval: u64, number_of_payments: u32) -> ExecuteMsg {
+    ExecuteMsg::CreatePaymentSchedule(CreatePaymentScheduleMsg {
+        recipient: "recipient".to_string(),
+        assets: vec![AssetUnchecked::native("uluna", 100u128)],
+        start_time: None,
+        interval,
+        number_of_payments,
+    })
+}
+
+#[test]
+fn next_payment_time_does_not_overflow() {
+    let schedule = PaymentSchedule {
+        id: 1,
+        recipient: Addr::unchecked("recipient"),
+        assets: vec![],
+        start_time: Timestamp::from_seconds(1000),
+        interval: 10_000_000_000,
+        number_of_payments: 3,
+        payments_made: 0,
+    };
+
+    assert_eq!(
+        next_payment_time(&schedule, Timestamp::from_seconds(1000)).unwrap(),
+        Some(Timestamp::from_seconds(10_000_001_000))
+    );
+    // the second payment is further in the future than timestamps can represent
+    assert!(next_payment_time(&schedule, Timestamp::from_seconds(10_000_001_000)).is_err());
+}
+
+#[test]
+fn payment_schedule_ending_too_far_in_future_is_invalid() {
+    let mut deps = instantiate_treasury(1000);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        create_payment_schedule_msg(10_000_000_000, 3),
+    );
+
+    assert_eq!(result.unwrap_err(), InvalidPaymentSchedule);
+}
+
+#[test]
+fn payment_schedule_locks_its_payments_until_paid_out_or_cancelled() {
+    let mut deps = instantiate_treasury(1000);
+
+    // 4 payments of 100 each
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        create_payment_schedule_msg(10, 4),
+    )
+    .unwrap();
+
+    let locked: LockedAmountResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::LockedAmount(LockedAmountParams {
+                asset: AssetInfoUnchecked::native("uluna"),
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(locked.amount, Uint128::new(400));
+
+    assert!(!spend(&mut deps, 601));
+    assert!(spend(&mut deps, 600));
+
+    // cannot create schedules for more than is unlocked
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(600, "uluna"));
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        create_payment_schedule_msg(10, 3),
+    );
+    assert_eq!(
+        result.unwrap_err(),
+        InsufficientUnlockedBalance {
+            asset: "native:uluna".to_string(),
+            available: Uint128::new(200),
+        }
+    );
+
+    // claiming 2 payments unlocks them, as they leave the treasury
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(25);
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ClaimScheduledPayments(ClaimScheduledPaymentsMsg { schedule_id: 1 }),
+    )
+    .unwrap();
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(400, "uluna"));
+
+    assert!(!spend(&mut deps, 201));
+
+    // cancelling unlocks the 2 payments that were not paid out
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CancelPaymentSchedule(CancelPaymentScheduleMsg { schedule_id: 1 }),
+    )
+    .unwrap();
+
+    assert!(spend(&mut deps, 400));
+}
+
+#[test]
+fn assets_listed_more_than_once_are_summed_up_against_unlocked_balance() {
+    let mut deps = instantiate_treasury(1000);
+
+    // 4 payments of 100 each
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        create_payment_schedule_msg(10, 4),
+    )
+    .unwrap();
+
+    let insufficient_unlocked_balance = InsufficientUnlockedBalance {
+        asset: "native:uluna".to_string(),
+        available: Uint128::new(600),
+    };
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Spend(SpendMsg {
+            recipient: "someone".to_string(),
+            assets: vec![
+                AssetUnchecked::native("uluna", 400u128),
+                AssetUnchecked::native("uluna", 400u128),
+            ],
+        }),
+    );
+    assert_eq!(result.unwrap_err(), insufficient_unlocked_balance);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeFunds(DistributeFundsMsg {
+            funds: vec![
+                AssetUnchecked::native("uluna", 400u128),
+                AssetUnchecked::native("uluna", 400u128),
+            ],
+            funds_distributor_contract: "funds_distributor".to_string(),
+            stream_duration: None,
+        }),
+    );
+    assert_eq!(result.unwrap_err(), insufficient_unlocked_balance);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Spend(SpendMsg {
+            recipient: "someone".to_string(),
+            assets: vec![
+                AssetUnchecked::native("uluna", 300u128),
+                AssetUnchecked::native("uluna", 300u128),
+            ],
+        }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn balances_list_limited_number_of_token_ids_per_nft_contract() {
+    let mut deps = mock_dependencies_with_balance(&[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            asset_whitelist: None,
+            nft_whitelist: Some(vec!["nft1".to_string(), "nft2".to_string()]),
+        },
+    )
+    .unwrap();
+
+    // nft1 holds 5 tokens, nft2 holds 2
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } => match from_json(msg).unwrap() {
+            Cw721QueryMsg::Tokens {
+                start_after, limit, ..
+            } => {
+                let held = if contract_addr == "nft1" { 5 } else { 2 };
+                let tokens = (1..=held)
+                    .map(|id| id.to_string())
+                    .filter(|id| start_after.as_ref().is_none_or(|start| id > start))
+                    .take(limit.unwrap() as usize)
+                    .collect();
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&TokensResponse { tokens }).unwrap(),
+                ))
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let response: BalancesResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Balances(BalancesParams {
+                asset_start_after: None,
+                asset_limit: None,
+                nft_start_after: None,
+                nft_limit: Some(2),
+                token_ids_limit: Some(3),
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.nfts,
+        vec![
+            NftBalance {
+                nft_contract: Addr::unchecked("nft1"),
+                token_ids: vec!["1".to_string(), "2".to_string(), "3".to_string()],
+                token_ids_start_after: Some("3".to_string()),
+            },
+            NftBalance {
+                nft_contract: Addr::unchecked("nft2"),
+                token_ids: vec!["1".to_string(), "2".to_string()],
+                token_ids_start_after: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn cw1155_stream_is_paid_out_from_treasury() {
+    let mut deps = instantiate_treasury(0);
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { msg, .. } => match from_json(msg).unwrap() {
+            Cw1155QueryMsg::Balance { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&BalanceResponse {
+                    balance: Uint128::new(1000),
+                })
+                .unwrap(),
+            )),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CreateStream(CreateStreamMsg {
+            recipient: "recipient".to_string(),
+            asset: AssetUnchecked::cw1155("game_items", "sword", 100u128),
+            start_time: None,
+            duration: 100,
+            cliff: None,
+        }),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(40);
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::WithdrawFromStream(WithdrawFromStreamMsg { stream_id: 1 }),
+    )
+    .unwrap();
+
+    // CW1155 tokens have no plain transfer, so the treasury sends them from itself
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "game_items".to_string(),
+            msg: to_json_binary(&Cw1155ExecuteMsg::SendFrom {
+                from: MOCK_CONTRACT_ADDR.to_string(),
+                to: "recipient".to_string(),
+                token_id: "sword".to_string(),
+                value: Uint128::new(40),
+                msg: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn funds_distributed_with_stream_duration_are_streamed() {
+    let mut deps = instantiate_treasury(1000);
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } => {
+            let response = match contract_addr.as_str() {
+                "token" => to_json_binary(&cw20::BalanceResponse {
+                    balance: Uint128::new(1000),
+                }),
+                "game_items" => to_json_binary(&BalanceResponse {
+                    balance: Uint128::new(1000),
+                }),
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeFunds(DistributeFundsMsg {
+            funds: vec![
+                AssetUnchecked::native("uluna", 100u128),
+                AssetUnchecked::cw20("token", 200u128),
+                AssetUnchecked::cw1155("game_items", "sword", 300u128),
+            ],
+            funds_distributor_contract: "funds_distributor".to_string(),
+            stream_duration: Some(500),
+        }),
+    )
+    .unwrap();
+
+    let messages = response
+        .messages
+        .into_iter()
+        .map(|submsg| submsg.msg)
+        .collect::<Vec<CosmosMsg>>();
+    assert_eq!(
+        messages,
+        vec![
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Send {
+                    contract: "funds_distributor".to_string(),
+                    amount: Uint128::new(200),
+                    msg: to_json_binary(&Cw20HookMsg::Stream { duration: 500 }).unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "game_items".to_string(),
+                msg: to_json_binary(&Cw1155ExecuteMsg::SendFrom {
+                    from: MOCK_CONTRACT_ADDR.to_string(),
+                    to: "funds_distributor".to_string(),
+                    token_id: "sword".to_string(),
+                    value: Uint128::new(300),
+                    msg: Some(to_json_binary(&Cw1155HookMsg::Stream { duration: 500 }).unwrap()),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "funds_distributor".to_string(),
+                msg: to_json_binary(&funds_distributor_api::msg::ExecuteMsg::StreamNative(
+                    StreamNativeMsg { duration: 500 },
+                ))
+                .unwrap(),
+                funds: coins(100, "uluna"),
+            }),
+        ]
+    );
+}
@@ -1,24 +1,45 @@
+use crate::cw1155_distributions::{Cw1155Distribution, CW1155_DISTRIBUTIONS};
 use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
+use crate::distributing::query_enterprise_components;
 use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
-use crate::rewards::calculate_user_reward;
-use crate::state::{CW20_GLOBAL_INDICES, ENTERPRISE_CONTRACT, NATIVE_GLOBAL_INDICES};
+use crate::reward_streams::accrue_reward_streams;
+use crate::rewards::{calculate_user_reward, to_asset_info};
+use crate::state::{
+    CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, DISTRIBUTED_ASSETS, ENTERPRISE_CONTRACT,
+    NATIVE_GLOBAL_INDICES,
+};
 use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
 use common::cw::Context;
-use cosmwasm_std::{Deps, Response, SubMsg, Uint128};
-use cw_asset::Asset;
-use enterprise_protocol::api::{IsRestrictedUserParams, IsRestrictedUserResponse};
-use enterprise_protocol::msg::QueryMsg::IsRestrictedUser;
-use funds_distributor_api::api::ClaimRewardsMsg;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{
+    coins, to_json_binary, wasm_execute, Addr, CosmosMsg, Deps, Response, StdResult, SubMsg,
+    Uint128,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_asset::{Asset, AssetInfo};
+use cw_storage_plus::Bound;
+use denom_staking_api::api::DenomConfigResponse;
+use denom_staking_api::msg::QueryMsg::DenomConfig;
+use enterprise_protocol::api::DaoType::{Denom, Multisig, Nft, Token};
+use enterprise_protocol::api::{DaoInfoResponse, IsRestrictedUserParams, IsRestrictedUserResponse};
+use enterprise_protocol::msg::QueryMsg::{DaoInfo, IsRestrictedUser};
+use funds_distributor_api::api::{ClaimAllRewardsMsg, ClaimRewardsMsg, Cw1155Asset};
 use funds_distributor_api::error::DistributorError::Unauthorized;
 use funds_distributor_api::error::{DistributorError, DistributorResult};
 use funds_distributor_api::response::execute_claim_rewards_response;
+use token_staking_api::api::TokenConfigResponse;
+use token_staking_api::msg::QueryMsg::TokenConfig;
 use DistributorError::RestrictedUser;
 
+const DEFAULT_CLAIM_ALL_LIMIT: u32 = 30;
+const MAX_CLAIM_ALL_LIMIT: u32 = 50;
+
 /// Attempt to claim rewards for the given parameters.
 ///
 /// Calculates rewards currently available to the user, and marks them as claimed.
 ///
-/// Returns a Response containing submessages that will send available rewards to the user.
+/// Returns a Response containing submessages that will send available rewards to the recipient,
+/// or stake them on the recipient's behalf if restaking was requested.
 pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResult<Response> {
     if is_restricted_user(ctx.deps.as_ref(), msg.user.clone())? {
         return Err(RestrictedUser);
@@ -30,6 +51,19 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
         return Err(Unauthorized);
     }
 
+    let recipient = match &msg.recipient {
+        Some(recipient) => ctx.deps.api.addr_validate(recipient)?,
+        None => user.clone(),
+    };
+
+    let restake_target = if msg.restake.unwrap_or(false) {
+        query_restake_target(ctx)?
+    } else {
+        None
+    };
+
+    accrue_reward_streams(ctx.deps.branch(), ctx.env.block.time)?;
+
     let user_weight = EFFECTIVE_USER_WEIGHTS
         .may_load(ctx.deps.storage, user.clone())?
         .unwrap_or_default();
@@ -43,11 +77,6 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             .may_load(ctx.deps.storage, denom.clone())?
             .unwrap_or_default();
 
-        // if no rewards for the given asset, just skip
-        if global_index.is_zero() {
-            continue;
-        }
-
         let reward = calculate_user_reward(global_index, distribution, user_weight)?;
 
         // if no user rewards due for the given asset, just skip - no need to send or store anything
@@ -55,7 +84,11 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             continue;
         }
 
-        let submsg = Asset::native(denom.clone(), reward).transfer_msg(user.clone())?;
+        let submsg = reward_msg(
+            Asset::native(denom.clone(), reward),
+            &recipient,
+            restake_target.as_ref(),
+        )?;
         submsgs.push(SubMsg::new(submsg));
 
         NATIVE_DISTRIBUTIONS().save(
@@ -79,11 +112,6 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             .may_load(ctx.deps.storage, asset.clone())?
             .unwrap_or_default();
 
-        // if no rewards for the given asset, just skip
-        if global_index.is_zero() {
-            continue;
-        }
-
         let reward = calculate_user_reward(global_index, distribution, user_weight)?;
 
         // if no user rewards due for the given asset, just skip - no need to send or store anything
@@ -91,7 +119,11 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             continue;
         }
 
-        let submsg = Asset::cw20(asset.clone(), reward).transfer_msg(user.clone())?;
+        let submsg = reward_msg(
+            Asset::cw20(asset.clone(), reward),
+            &recipient,
+            restake_target.as_ref(),
+        )?;
         submsgs.push(SubMsg::new(submsg));
 
         CW20_DISTRIBUTIONS().save(
@@ -106,7 +138,170 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
         )?;
     }
 
-    Ok(execute_claim_rewards_response(user.to_string()).add_submessages(submsgs))
+    for cw1155_asset in msg.cw1155_assets {
+        let asset = ctx.deps.api.addr_validate(&cw1155_asset.contract)?;
+        let token_id = cw1155_asset.token_id;
+
+        let distribution = CW1155_DISTRIBUTIONS().may_load(
+            ctx.deps.storage,
+            (user.clone(), asset.clone(), token_id.clone()),
+        )?;
+        let global_index = CW1155_GLOBAL_INDICES
+            .may_load(ctx.deps.storage, (asset.clone(), token_id.clone()))?
+            .unwrap_or_default();
+
+        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+
+        // if no user rewards due for the given asset, just skip - no need to send or store anything
+        if reward.is_zero() {
+            continue;
+        }
+
+        // CW1155 tokens have no plain transfer, so we send them from ourselves instead
+        let submsg = Asset::cw1155(asset.clone(), token_id.clone(), reward)
+            .transfer_from_msg(ctx.env.contract.address.clone(), recipient.clone())?;
+        submsgs.push(SubMsg::new(submsg));
+
+        CW1155_DISTRIBUTIONS().save(
+            ctx.deps.storage,
+            (user.clone(), asset.clone(), token_id.clone()),
+            &Cw1155Distribution {
+                user: user.clone(),
+                cw1155_asset: asset,
+                token_id,
+                user_index: global_index,
+                pending_rewards: Uint128::zero(),
+            },
+        )?;
+    }
+
+    Ok(
+        execute_claim_rewards_response(user.to_string(), recipient.to_string())
+            .add_submessages(submsgs),
+    )
+}
+
+/// Attempt to claim rewards in a page of the assets that were ever distributed.
+pub fn claim_all_rewards(
+    ctx: &mut Context,
+    msg: ClaimAllRewardsMsg,
+) -> DistributorResult<Response> {
+    let start_after = msg
+        .start_after
+        .map(|asset| to_asset_info(ctx.deps.api, asset))
+        .transpose()?
+        .map(|asset| asset.to_string());
+
+    let limit = msg
+        .limit
+        .unwrap_or(DEFAULT_CLAIM_ALL_LIMIT)
+        .min(MAX_CLAIM_ALL_LIMIT) as usize;
+
+    let assets = DISTRIBUTED_ASSETS
+        .range(
+            ctx.deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, asset)| asset))
+        .collect::<StdResult<Vec<AssetInfo>>>()?;
+
+    let mut claim_msg = ClaimRewardsMsg {
+        user: msg.user,
+        native_denoms: vec![],
+        cw20_assets: vec![],
+        cw1155_assets: vec![],
+        recipient: msg.recipient,
+        restake: msg.restake,
+    };
+
+    for asset in assets {
+        match asset {
+            AssetInfo::Native(denom) => claim_msg.native_denoms.push(denom),
+            AssetInfo::Cw20(addr) => claim_msg.cw20_assets.push(addr.to_string()),
+            AssetInfo::Cw1155(addr, token_id) => claim_msg.cw1155_assets.push(Cw1155Asset {
+                contract: addr.to_string(),
+                token_id,
+            }),
+            _ => {}
+        }
+    }
+
+    claim_rewards(ctx, claim_msg)
+}
+
+/// Membership contract and the asset it stakes, for DAOs whose membership can be staked
+/// with rewards. None for other DAO types.
+fn query_restake_target(ctx: &Context) -> DistributorResult<Option<(Addr, AssetInfo)>> {
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(ctx.deps.storage)?;
+
+    let dao_info: DaoInfoResponse = ctx
+        .deps
+        .querier
+        .query_wasm_smart(enterprise_contract.to_string(), &DaoInfo {})?;
+
+    let membership_contract = query_enterprise_components(ctx)?.membership_contract;
+
+    let staked_asset = match dao_info.dao_type {
+        Token => {
+            let token_config: TokenConfigResponse = ctx
+                .deps
+                .querier
+                .query_wasm_smart(membership_contract.to_string(), &TokenConfig {})?;
+            AssetInfo::cw20(token_config.token_contract)
+        }
+        Denom => {
+            let denom_config: DenomConfigResponse = ctx
+                .deps
+                .querier
+                .query_wasm_smart(membership_contract.to_string(), &DenomConfig {})?;
+            AssetInfo::native(denom_config.denom)
+        }
+        Nft | Multisig => return Ok(None),
+    };
+
+    Ok(Some((membership_contract, staked_asset)))
+}
+
+/// Message that either sends the reward to the recipient, or stakes it on their behalf
+/// if it is the asset staked in the restake target's membership contract.
+fn reward_msg(
+    reward: Asset,
+    recipient: &Addr,
+    restake_target: Option<&(Addr, AssetInfo)>,
+) -> DistributorResult<CosmosMsg> {
+    let msg = match restake_target {
+        Some((membership_contract, staked_asset)) if &reward.info == staked_asset => {
+            match &reward.info {
+                AssetInfo::Cw20(token) => wasm_execute(
+                    token.to_string(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: membership_contract.to_string(),
+                        amount: reward.amount,
+                        msg: to_json_binary(&token_staking_api::msg::Cw20HookMsg::Stake {
+                            user: recipient.to_string(),
+                        })?,
+                    },
+                    vec![],
+                )?
+                .into(),
+                AssetInfo::Native(denom) => wasm_execute(
+                    membership_contract.to_string(),
+                    &denom_staking_api::msg::ExecuteMsg::Stake {
+                        user: Some(recipient.to_string()),
+                    },
+                    coins(reward.amount.u128(), denom),
+                )?
+                .into(),
+                _ => reward.transfer_msg(recipient)?,
+            }
+        }
+        _ => reward.transfer_msg(recipient)?,
+    };
+
+    Ok(msg)
 }
 
 fn is_restricted_user(deps: Deps, user: String) -> DistributorResult<bool> {
@@ -1,20 +1,32 @@
-use crate::claim::claim_rewards;
-use crate::distributing::{distribute_cw20, distribute_native};
+use crate::claim::{claim_all_rewards, claim_rewards};
+use crate::distributing::{
+    distribute_cw1155, distribute_cw20, distribute_native, stream_cw1155, stream_cw20,
+    stream_native,
+};
+use crate::distribution_log::{query_distribution_history, query_trailing_rewards};
 use crate::eligibility::{
     execute_update_minimum_eligible_weight, query_minimum_eligible_weight, MINIMUM_ELIGIBLE_WEIGHT,
 };
-use crate::rewards::query_user_rewards;
+use crate::migration::migrate_distributed_assets;
+use crate::reward_streams::query_reward_streams;
+use crate::rewards::{query_all_user_rewards, query_user_rewards};
 use crate::state::{ADMIN, ENTERPRISE_CONTRACT};
+use crate::targeted_distributions::{
+    distribute_cw1155_targeted, distribute_cw20_targeted, distribute_native_targeted,
+};
 use crate::user_weights::{save_initial_weights, update_user_weights};
 use common::cw::{Context, QueryContext};
 use cosmwasm_std::{
     entry_point, from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
     Response, StdError,
 };
+use cw1155::Cw1155ReceiveMsg;
 use cw2::set_contract_version;
 use cw20::Cw20ReceiveMsg;
 use funds_distributor_api::error::DistributorResult;
-use funds_distributor_api::msg::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use funds_distributor_api::msg::{
+    Cw1155HookMsg, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg,
+};
 use funds_distributor_api::response::instantiate_response;
 
 // version info for migration info
@@ -60,18 +72,35 @@ pub fn execute(
             execute_update_minimum_eligible_weight(ctx, msg)
         }
         ExecuteMsg::DistributeNative {} => distribute_native(ctx),
+        ExecuteMsg::StreamNative(msg) => stream_native(ctx, msg.duration),
+        ExecuteMsg::DistributeNativeTargeted(msg) => distribute_native_targeted(ctx, msg),
         ExecuteMsg::ClaimRewards(msg) => claim_rewards(ctx, msg),
-        ExecuteMsg::Receive(msg) => receive_cw20(ctx, msg),
+        ExecuteMsg::ClaimAllRewards(msg) => claim_all_rewards(ctx, msg),
+        ExecuteMsg::Receive(ReceiveMsg::Cw20(msg)) => receive_cw20(ctx, msg),
+        ExecuteMsg::Receive(ReceiveMsg::Cw1155(msg)) => receive_cw1155(ctx, msg),
     }
 }
 
 fn receive_cw20(ctx: &mut Context, cw20_msg: Cw20ReceiveMsg) -> DistributorResult<Response> {
     match from_json(&cw20_msg.msg) {
         Ok(Cw20HookMsg::Distribute {}) => distribute_cw20(ctx, cw20_msg),
+        Ok(Cw20HookMsg::Stream { duration }) => stream_cw20(ctx, cw20_msg, duration),
+        Ok(Cw20HookMsg::DistributeTargeted(msg)) => distribute_cw20_targeted(ctx, cw20_msg, msg),
         _ => Err(StdError::generic_err("Received unknown CW20 hook message").into()),
     }
 }
 
+fn receive_cw1155(ctx: &mut Context, cw1155_msg: Cw1155ReceiveMsg) -> DistributorResult<Response> {
+    match from_json(&cw1155_msg.msg) {
+        Ok(Cw1155HookMsg::Distribute {}) => distribute_cw1155(ctx, cw1155_msg),
+        Ok(Cw1155HookMsg::Stream { duration }) => stream_cw1155(ctx, cw1155_msg, duration),
+        Ok(Cw1155HookMsg::DistributeTargeted(msg)) => {
+            distribute_cw1155_targeted(ctx, cw1155_msg, msg)
+        }
+        _ => Err(StdError::generic_err("Received unknown CW1155 hook message").into()),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, _msg: Reply) -> DistributorResult<Response> {
     Ok(Response::new())
@@ -83,16 +112,26 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> DistributorResult<Binary> {
 
     let response = match msg {
         QueryMsg::UserRewards(params) => to_json_binary(&query_user_rewards(qctx, params)?)?,
+        QueryMsg::AllUserRewards(params) => to_json_binary(&query_all_user_rewards(qctx, params)?)?,
         QueryMsg::MinimumEligibleWeight {} => {
             to_json_binary(&query_minimum_eligible_weight(qctx)?)?
         }
+        QueryMsg::RewardStreams(params) => to_json_binary(&query_reward_streams(qctx, params)?)?,
+        QueryMsg::DistributionHistory(params) => {
+            to_json_binary(&query_distribution_history(qctx, params)?)?
+        }
+        QueryMsg::TrailingRewards(params) => {
+            to_json_binary(&query_trailing_rewards(qctx, params)?)?
+        }
     };
     Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> DistributorResult<Response> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> DistributorResult<Response> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    migrate_distributed_assets(deps.branch())?;
+
     Ok(Response::new().add_attribute("action", "migrate"))
 }
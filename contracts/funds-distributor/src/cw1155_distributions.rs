@@ -0,0 +1,86 @@
+use crate::rewards::calculate_user_reward;
+use crate::state::CW1155_GLOBAL_INDICES;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Decimal, DepsMut, StdResult, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use funds_distributor_api::error::DistributorResult;
+
+#[cw_serde]
+/// State of a single user's specific CW1155 rewards.
+pub struct Cw1155Distribution {
+    pub user: Addr,
+    pub cw1155_asset: Addr,
+    pub token_id: String,
+    /// The last global index at which the user's pending rewards were calculated
+    pub user_index: Decimal,
+    /// User's unclaimed rewards
+    pub pending_rewards: Uint128,
+}
+
+pub struct Cw1155DistributionIndexes<'a> {
+    pub user: MultiIndex<'a, Addr, Cw1155Distribution, (Addr, Addr, String)>,
+}
+
+impl IndexList<Cw1155Distribution> for Cw1155DistributionIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Cw1155Distribution>> + '_> {
+        let v: Vec<&dyn Index<Cw1155Distribution>> = vec![&self.user];
+        Box::new(v.into_iter())
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CW1155_DISTRIBUTIONS<'a>(
+) -> IndexedMap<'a, (Addr, Addr, String), Cw1155Distribution, Cw1155DistributionIndexes<'a>> {
+    let indexes = Cw1155DistributionIndexes {
+        user: MultiIndex::new(
+            |_, cw1155_distribution| cw1155_distribution.user.clone(),
+            "cw1155_distributions",
+            "cw1155_distributions__user",
+        ),
+    };
+    IndexedMap::new("cw1155_distributions", indexes)
+}
+
+// convenience trait to unify duplicate code between this and other distributions
+impl From<Cw1155Distribution> for (Decimal, Uint128) {
+    fn from(item: Cw1155Distribution) -> Self {
+        (item.user_index, item.pending_rewards)
+    }
+}
+
+/// Updates user's reward indices for all CW1155 assets.
+///
+/// Will calculate newly pending rewards since the last update to the user's reward index until now,
+/// using their last weight to calculate the newly accrued rewards.
+pub fn update_user_cw1155_distributions(
+    deps: DepsMut,
+    user: Addr,
+    old_user_weight: Uint128,
+) -> DistributorResult<()> {
+    let cw1155_global_indices = CW1155_GLOBAL_INDICES
+        .range(deps.storage, None, None, Ascending)
+        .collect::<StdResult<Vec<((Addr, String), Decimal)>>>()?;
+
+    for ((cw1155_asset, token_id), global_index) in cw1155_global_indices {
+        let key = (user.clone(), cw1155_asset.clone(), token_id.clone());
+
+        let distribution = CW1155_DISTRIBUTIONS().may_load(deps.storage, key.clone())?;
+
+        let reward = calculate_user_reward(global_index, distribution, old_user_weight)?;
+
+        CW1155_DISTRIBUTIONS().save(
+            deps.storage,
+            key,
+            &Cw1155Distribution {
+                user: user.clone(),
+                cw1155_asset,
+                token_id,
+                user_index: global_index,
+                pending_rewards: reward,
+            },
+        )?;
+    }
+
+    Ok(())
+}
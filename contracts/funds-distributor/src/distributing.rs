@@ -1,7 +1,10 @@
-use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
-use crate::state::{EFFECTIVE_TOTAL_WEIGHT, ENTERPRISE_CONTRACT};
+use crate::distribution_log::log_distribution;
+use crate::reward_streams::{assert_can_create_reward_streams, create_reward_stream};
+use crate::state::{CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::state::{DISTRIBUTED_ASSETS, EFFECTIVE_TOTAL_WEIGHT, ENTERPRISE_CONTRACT};
 use common::cw::Context;
-use cosmwasm_std::{Decimal, Response, Uint128};
+use cosmwasm_std::{Decimal, Response, StdResult, Storage, Uint128};
+use cw1155::Cw1155ReceiveMsg;
 use cw20::Cw20ReceiveMsg;
 use cw_asset::AssetInfo;
 use enterprise_protocol::api::ComponentContractsResponse;
@@ -13,7 +16,9 @@ use funds_distributor_api::error::DistributorError::{
 };
 use funds_distributor_api::error::DistributorResult;
 use funds_distributor_api::response::{
-    cw20_hook_distribute_cw20_response, execute_distribute_native_response,
+    cw1155_hook_distribute_cw1155_response, cw1155_hook_stream_cw1155_response,
+    cw20_hook_distribute_cw20_response, cw20_hook_stream_cw20_response,
+    execute_distribute_native_response, execute_stream_native_response,
 };
 use std::ops::Not;
 
@@ -34,6 +39,8 @@ pub fn distribute_native(ctx: &mut Context) -> DistributorResult<Response> {
     }
 
     for fund in funds {
+        save_distributed_asset(ctx.deps.storage, &AssetInfo::native(fund.denom.clone()))?;
+
         let global_index = NATIVE_GLOBAL_INDICES
             .may_load(ctx.deps.storage, fund.denom.clone())?
             .unwrap_or(Decimal::zero());
@@ -44,9 +51,17 @@ pub fn distribute_native(ctx: &mut Context) -> DistributorResult<Response> {
 
         NATIVE_GLOBAL_INDICES.save(
             ctx.deps.storage,
-            fund.denom,
+            fund.denom.clone(),
             &global_index.checked_add(index_increment)?,
         )?;
+
+        log_distribution(
+            ctx.deps.storage,
+            AssetInfo::native(fund.denom),
+            fund.amount,
+            total_weight,
+            ctx.env.block.time,
+        )?;
     }
 
     Ok(execute_distribute_native_response(total_weight))
@@ -64,6 +79,8 @@ pub fn distribute_cw20(ctx: &mut Context, cw20_msg: Cw20ReceiveMsg) -> Distribut
         return Err(ZeroTotalWeight);
     }
 
+    save_distributed_asset(ctx.deps.storage, &AssetInfo::cw20(cw20_addr.clone()))?;
+
     let global_index = CW20_GLOBAL_INDICES
         .may_load(ctx.deps.storage, cw20_addr.clone())?
         .unwrap_or(Decimal::zero());
@@ -78,6 +95,14 @@ pub fn distribute_cw20(ctx: &mut Context, cw20_msg: Cw20ReceiveMsg) -> Distribut
         &global_index.checked_add(global_index_increment)?,
     )?;
 
+    log_distribution(
+        ctx.deps.storage,
+        AssetInfo::cw20(cw20_addr.clone()),
+        cw20_msg.amount,
+        total_weight,
+        ctx.env.block.time,
+    )?;
+
     Ok(cw20_hook_distribute_cw20_response(
         total_weight,
         cw20_addr.to_string(),
@@ -85,7 +110,183 @@ pub fn distribute_cw20(ctx: &mut Context, cw20_msg: Cw20ReceiveMsg) -> Distribut
     ))
 }
 
-fn assert_assets_whitelisted(ctx: &Context, mut assets: Vec<AssetInfo>) -> DistributorResult<()> {
+/// Distributes new rewards for a CW1155 asset.
+/// Will increase global index for the asset being distributed.
+pub fn distribute_cw1155(
+    ctx: &mut Context,
+    cw1155_msg: Cw1155ReceiveMsg,
+) -> DistributorResult<Response> {
+    let cw1155_addr = ctx.info.sender.clone();
+    let token_id = cw1155_msg.token_id;
+
+    assert_assets_whitelisted(
+        ctx,
+        vec![AssetInfo::cw1155(cw1155_addr.clone(), token_id.clone())],
+    )?;
+
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
+    if total_weight == Uint128::zero() {
+        return Err(ZeroTotalWeight);
+    }
+
+    save_distributed_asset(
+        ctx.deps.storage,
+        &AssetInfo::cw1155(cw1155_addr.clone(), token_id.clone()),
+    )?;
+
+    let key = (cw1155_addr.clone(), token_id.clone());
+
+    let global_index = CW1155_GLOBAL_INDICES
+        .may_load(ctx.deps.storage, key.clone())?
+        .unwrap_or(Decimal::zero());
+
+    // calculate how many units of the asset we're distributing per unit of total user weight
+    // and add that to the global index for the asset
+    let global_index_increment = Decimal::from_ratio(cw1155_msg.amount, total_weight);
+
+    CW1155_GLOBAL_INDICES.save(
+        ctx.deps.storage,
+        key,
+        &global_index.checked_add(global_index_increment)?,
+    )?;
+
+    log_distribution(
+        ctx.deps.storage,
+        AssetInfo::cw1155(cw1155_addr.clone(), token_id.clone()),
+        cw1155_msg.amount,
+        total_weight,
+        ctx.env.block.time,
+    )?;
+
+    Ok(cw1155_hook_distribute_cw1155_response(
+        total_weight,
+        cw1155_addr.to_string(),
+        token_id,
+        cw1155_msg.amount,
+    ))
+}
+
+/// Creates a reward stream for each of the native assets found in MessageInfo,
+/// releasing them to the users gradually over the given duration.
+pub fn stream_native(ctx: &mut Context, duration: u64) -> DistributorResult<Response> {
+    assert_can_create_reward_streams(ctx, ctx.info.sender.as_str())?;
+
+    let funds = ctx.info.funds.clone();
+
+    let distribution_assets = funds
+        .iter()
+        .map(|coin| AssetInfo::native(coin.denom.to_string()))
+        .collect();
+    assert_assets_whitelisted(ctx, distribution_assets)?;
+
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
+    if total_weight == Uint128::zero() {
+        return Err(ZeroTotalWeight);
+    }
+
+    for fund in funds {
+        let asset = AssetInfo::native(fund.denom);
+
+        save_distributed_asset(ctx.deps.storage, &asset)?;
+
+        create_reward_stream(
+            ctx.deps.branch(),
+            asset,
+            fund.amount,
+            ctx.env.block.time,
+            duration,
+        )?;
+    }
+
+    Ok(execute_stream_native_response(total_weight, duration))
+}
+
+/// Creates a reward stream for a CW20 asset, releasing it to the users gradually
+/// over the given duration.
+pub fn stream_cw20(
+    ctx: &mut Context,
+    cw20_msg: Cw20ReceiveMsg,
+    duration: u64,
+) -> DistributorResult<Response> {
+    let cw20_addr = ctx.info.sender.clone();
+
+    assert_can_create_reward_streams(ctx, &cw20_msg.sender)?;
+
+    assert_assets_whitelisted(ctx, vec![AssetInfo::cw20(cw20_addr.clone())])?;
+
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
+    if total_weight == Uint128::zero() {
+        return Err(ZeroTotalWeight);
+    }
+
+    let asset = AssetInfo::cw20(cw20_addr.clone());
+
+    save_distributed_asset(ctx.deps.storage, &asset)?;
+
+    let stream_id = create_reward_stream(
+        ctx.deps.branch(),
+        asset,
+        cw20_msg.amount,
+        ctx.env.block.time,
+        duration,
+    )?;
+
+    Ok(cw20_hook_stream_cw20_response(
+        stream_id,
+        cw20_addr.to_string(),
+        cw20_msg.amount,
+        duration,
+    ))
+}
+
+/// Creates a reward stream for a CW1155 asset, releasing it to the users gradually
+/// over the given duration.
+pub fn stream_cw1155(
+    ctx: &mut Context,
+    cw1155_msg: Cw1155ReceiveMsg,
+    duration: u64,
+) -> DistributorResult<Response> {
+    let cw1155_addr = ctx.info.sender.clone();
+    let token_id = cw1155_msg.token_id;
+
+    let asset = AssetInfo::cw1155(cw1155_addr.clone(), token_id.clone());
+
+    assert_can_create_reward_streams(ctx, &cw1155_msg.operator)?;
+
+    assert_assets_whitelisted(ctx, vec![asset.clone()])?;
+
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
+    if total_weight == Uint128::zero() {
+        return Err(ZeroTotalWeight);
+    }
+
+    save_distributed_asset(ctx.deps.storage, &asset)?;
+
+    let stream_id = create_reward_stream(
+        ctx.deps.branch(),
+        asset,
+        cw1155_msg.amount,
+        ctx.env.block.time,
+        duration,
+    )?;
+
+    Ok(cw1155_hook_stream_cw1155_response(
+        stream_id,
+        cw1155_addr.to_string(),
+        token_id,
+        cw1155_msg.amount,
+        duration,
+    ))
+}
+
+pub fn save_distributed_asset(storage: &mut dyn Storage, asset: &AssetInfo) -> StdResult<()> {
+    DISTRIBUTED_ASSETS.save(storage, asset.to_string(), asset)
+}
+
+pub fn assert_assets_whitelisted(
+    ctx: &Context,
+    mut assets: Vec<AssetInfo>,
+) -> DistributorResult<()> {
     let enterprise_components = query_enterprise_components(ctx)?;
 
     // query asset whitelist with no bounds
@@ -145,7 +346,7 @@ fn assert_assets_whitelisted(ctx: &Context, mut assets: Vec<AssetInfo>) -> Distr
     }
 }
 
-fn query_enterprise_components(ctx: &Context) -> DistributorResult<ComponentContractsResponse> {
+pub fn query_enterprise_components(ctx: &Context) -> DistributorResult<ComponentContractsResponse> {
     let enterprise_contract = ENTERPRISE_CONTRACT.load(ctx.deps.storage)?;
 
     let component_contracts: ComponentContractsResponse = ctx
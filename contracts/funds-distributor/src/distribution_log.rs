@@ -0,0 +1,230 @@
+use crate::rewards::{to_asset_info, to_reward_asset};
+use common::cw::QueryContext;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Order::{Ascending, Descending};
+use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_asset::AssetInfo;
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use funds_distributor_api::api::{
+    DistributionHistoryParams, DistributionHistoryResponse, DistributionRecord,
+    TrailingRewardsParams, TrailingRewardsResponse,
+};
+use funds_distributor_api::error::DistributorError::TrailingRewardsWindowTooLong;
+use funds_distributor_api::error::DistributorResult;
+
+const DEFAULT_QUERY_LIMIT: u32 = 50;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Length of the periods in which distributions are summed up for trailing rewards, in seconds.
+const TRAILING_REWARDS_PERIOD: u64 = 24 * 60 * 60;
+
+const MAX_TRAILING_REWARDS_WINDOW: u64 = 365 * TRAILING_REWARDS_PERIOD;
+
+#[cw_serde]
+/// A single increase of an asset's global index.
+pub struct LoggedDistribution {
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+    pub total_weight: Uint128,
+    pub distributed_at: Timestamp,
+}
+
+pub struct DistributionLogIndexes<'a> {
+    pub asset: MultiIndex<'a, String, LoggedDistribution, u64>,
+}
+
+impl IndexList<LoggedDistribution> for DistributionLogIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<LoggedDistribution>> + '_> {
+        let v: Vec<&dyn Index<LoggedDistribution>> = vec![&self.asset];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Log of all distributions, keyed by sequential IDs, and indexed by their asset.
+#[allow(non_snake_case)]
+pub fn DISTRIBUTION_LOG<'a>() -> IndexedMap<'a, u64, LoggedDistribution, DistributionLogIndexes<'a>>
+{
+    let indexes = DistributionLogIndexes {
+        asset: MultiIndex::new(
+            |_, distribution| distribution.asset.to_string(),
+            "distribution_log",
+            "distribution_log__asset",
+        ),
+    };
+    IndexedMap::new("distribution_log", indexes)
+}
+
+pub const NEXT_DISTRIBUTION_ID: Item<u64> = Item::new("next_distribution_id");
+
+#[cw_serde]
+#[derive(Default)]
+/// Sum of an asset's distributions within a single period.
+pub struct PeriodDistributions {
+    pub amount: Uint128,
+    pub reward_per_unit_weight: Decimal,
+}
+
+/// Sums of each asset's distributions, keyed by (asset, period number since the epoch).
+pub const PERIOD_DISTRIBUTIONS: Map<(String, u64), PeriodDistributions> =
+    Map::new("period_distributions");
+
+/// Records that the given amount of the asset was distributed to the given total weight.
+/// Distributions to specific users instead of to all the users by their weight
+/// are logged with a total weight of 0.
+pub fn log_distribution(
+    storage: &mut dyn Storage,
+    asset: AssetInfo,
+    amount: Uint128,
+    total_weight: Uint128,
+    now: Timestamp,
+) -> DistributorResult<()> {
+    add_to_period_distributions(storage, &asset, amount, total_weight, now)?;
+
+    save_distribution_record(storage, asset, amount, total_weight, now)?;
+
+    Ok(())
+}
+
+/// Adds a distribution to the history without counting it towards trailing rewards.
+/// Used for reward streams, which are counted towards trailing rewards as they are released.
+pub fn save_distribution_record(
+    storage: &mut dyn Storage,
+    asset: AssetInfo,
+    amount: Uint128,
+    total_weight: Uint128,
+    now: Timestamp,
+) -> StdResult<()> {
+    let id = NEXT_DISTRIBUTION_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_DISTRIBUTION_ID.save(storage, &(id + 1))?;
+
+    DISTRIBUTION_LOG().save(
+        storage,
+        id,
+        &LoggedDistribution {
+            asset,
+            amount,
+            total_weight,
+            distributed_at: now,
+        },
+    )
+}
+
+/// Counts a distribution towards the trailing rewards of the period it happened in.
+pub fn add_to_period_distributions(
+    storage: &mut dyn Storage,
+    asset: &AssetInfo,
+    amount: Uint128,
+    total_weight: Uint128,
+    now: Timestamp,
+) -> DistributorResult<()> {
+    let key = (asset.to_string(), now.seconds() / TRAILING_REWARDS_PERIOD);
+
+    let period_distributions = PERIOD_DISTRIBUTIONS
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+
+    let reward_per_unit_weight = if total_weight.is_zero() {
+        period_distributions.reward_per_unit_weight
+    } else {
+        period_distributions
+            .reward_per_unit_weight
+            .checked_add(Decimal::from_ratio(amount, total_weight))?
+    };
+
+    PERIOD_DISTRIBUTIONS.save(
+        storage,
+        key,
+        &PeriodDistributions {
+            amount: period_distributions.amount.checked_add(amount)?,
+            reward_per_unit_weight,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn query_distribution_history(
+    qctx: QueryContext,
+    params: DistributionHistoryParams,
+) -> DistributorResult<DistributionHistoryResponse> {
+    let asset = params
+        .asset
+        .map(|asset| to_asset_info(qctx.deps.api, asset))
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let end = params.start_after.map(Bound::exclusive);
+
+    let distributions = match asset {
+        Some(asset) => DISTRIBUTION_LOG()
+            .idx
+            .asset
+            .prefix(asset.to_string())
+            .range(qctx.deps.storage, None, end, Descending)
+            .take(limit)
+            .collect::<StdResult<Vec<(u64, LoggedDistribution)>>>()?,
+        None => DISTRIBUTION_LOG()
+            .range(qctx.deps.storage, None, end, Descending)
+            .take(limit)
+            .collect::<StdResult<Vec<(u64, LoggedDistribution)>>>()?,
+    };
+
+    let distributions = distributions
+        .into_iter()
+        .map(
+            |(id, distribution)| -> DistributorResult<DistributionRecord> {
+                Ok(DistributionRecord {
+                    id,
+                    asset: to_reward_asset(&distribution.asset)?,
+                    amount: distribution.amount,
+                    total_weight: distribution.total_weight,
+                    distributed_at: distribution.distributed_at,
+                })
+            },
+        )
+        .collect::<DistributorResult<Vec<DistributionRecord>>>()?;
+
+    Ok(DistributionHistoryResponse { distributions })
+}
+
+/// Sums up the asset's distributions in the periods overlapping with the trailing window.
+pub fn query_trailing_rewards(
+    qctx: QueryContext,
+    params: TrailingRewardsParams,
+) -> DistributorResult<TrailingRewardsResponse> {
+    if params.window > MAX_TRAILING_REWARDS_WINDOW {
+        return Err(TrailingRewardsWindowTooLong {
+            maximum: MAX_TRAILING_REWARDS_WINDOW,
+        });
+    }
+
+    let asset = to_asset_info(qctx.deps.api, params.asset)?;
+
+    let first_period =
+        qctx.env.block.time.seconds().saturating_sub(params.window) / TRAILING_REWARDS_PERIOD;
+
+    let mut reward_per_unit_weight = Decimal::zero();
+    let mut total_amount = Uint128::zero();
+
+    for period_distributions in PERIOD_DISTRIBUTIONS.prefix(asset.to_string()).range(
+        qctx.deps.storage,
+        Some(Bound::inclusive(first_period)),
+        None,
+        Ascending,
+    ) {
+        let (_, period_distributions) = period_distributions?;
+
+        reward_per_unit_weight =
+            reward_per_unit_weight.checked_add(period_distributions.reward_per_unit_weight)?;
+        total_amount = total_amount.checked_add(period_distributions.amount)?;
+    }
+
+    Ok(TrailingRewardsResponse {
+        reward_per_unit_weight,
+        total_amount,
+    })
+}
@@ -1,5 +1,7 @@
+use crate::cw1155_distributions::update_user_cw1155_distributions;
 use crate::cw20_distributions::update_user_cw20_distributions;
 use crate::native_distributions::update_user_native_distributions;
+use crate::reward_streams::accrue_reward_streams;
 use crate::state::{ADMIN, EFFECTIVE_TOTAL_WEIGHT};
 use crate::user_weights::{EFFECTIVE_USER_WEIGHTS, USER_WEIGHTS};
 use common::cw::{Context, QueryContext};
@@ -28,6 +30,9 @@ pub fn execute_update_minimum_eligible_weight(
     let old_minimum_weight = MINIMUM_ELIGIBLE_WEIGHT.load(ctx.deps.storage)?;
     let new_minimum_weight = msg.minimum_eligible_weight;
 
+    // streamed rewards released until now belong to the users with their old effective weights
+    accrue_reward_streams(ctx.deps.branch(), ctx.env.block.time)?;
+
     update_minimum_eligible_weight(ctx.deps.branch(), old_minimum_weight, new_minimum_weight)?;
 
     Ok(execute_update_minimum_eligible_weight_response(
@@ -93,6 +98,7 @@ pub fn update_minimum_eligible_weight(
         // newly accrued rewards since last updates into their pending rewards
         update_user_native_distributions(deps.branch(), user.clone(), old_effective_weight)?;
         update_user_cw20_distributions(deps.branch(), user.clone(), old_effective_weight)?;
+        update_user_cw1155_distributions(deps.branch(), user.clone(), old_effective_weight)?;
 
         let new_effective_weight = if use_actual_weights {
             user_weight
@@ -2,13 +2,17 @@ extern crate core;
 
 mod claim;
 pub mod contract;
+mod cw1155_distributions;
 mod cw20_distributions;
 mod distributing;
+mod distribution_log;
 mod eligibility;
 mod migration;
 mod native_distributions;
+mod reward_streams;
 mod rewards;
 mod state;
+mod targeted_distributions;
 mod user_weights;
 
 #[cfg(test)]
@@ -1 +1,35 @@
+use crate::distributing::save_distributed_asset;
+use crate::state::{CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{DepsMut, StdResult};
+use cw_asset::AssetInfo;
+use funds_distributor_api::error::DistributorResult;
 
+/// Records assets distributed before distributed assets were tracked,
+/// i.e. all the assets that already have a global index.
+pub fn migrate_distributed_assets(deps: DepsMut) -> DistributorResult<()> {
+    let mut assets = NATIVE_GLOBAL_INDICES
+        .keys(deps.storage, None, None, Ascending)
+        .map(|denom| denom.map(AssetInfo::native))
+        .collect::<StdResult<Vec<AssetInfo>>>()?;
+
+    assets.extend(
+        CW20_GLOBAL_INDICES
+            .keys(deps.storage, None, None, Ascending)
+            .map(|addr| addr.map(AssetInfo::cw20))
+            .collect::<StdResult<Vec<AssetInfo>>>()?,
+    );
+
+    assets.extend(
+        CW1155_GLOBAL_INDICES
+            .keys(deps.storage, None, None, Ascending)
+            .map(|key| key.map(|(addr, token_id)| AssetInfo::cw1155(addr, token_id)))
+            .collect::<StdResult<Vec<AssetInfo>>>()?,
+    );
+
+    for asset in assets {
+        save_distributed_asset(deps.storage, &asset)?;
+    }
+
+    Ok(())
+}
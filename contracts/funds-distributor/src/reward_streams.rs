@@ -0,0 +1,293 @@
+use crate::distributing::query_enterprise_components;
+use crate::distribution_log::{add_to_period_distributions, save_distribution_record};
+use crate::rewards::to_reward_asset;
+use crate::state::{
+    ADMIN, CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT,
+    NATIVE_GLOBAL_INDICES,
+};
+use common::cw::{Context, QueryContext};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Decimal, DepsMut, StdError, StdResult, Storage, Timestamp, Uint128};
+use cw_asset::AssetInfo;
+use cw_storage_plus::{Bound, Item, Map};
+use funds_distributor_api::api::{ActiveRewardStream, RewardStreamsParams, RewardStreamsResponse};
+use funds_distributor_api::error::DistributorError::{
+    MaximumRewardStreamsExceeded, RewardStreamDurationTooLong, Unauthorized,
+    ZeroRewardStreamDuration,
+};
+use funds_distributor_api::error::DistributorResult;
+use std::collections::BTreeMap;
+
+const DEFAULT_QUERY_LIMIT: u32 = 50;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Every stream is walked whenever streams are accrued, which happens on each change of weights
+/// and on each claim, so their number has to stay low.
+pub const MAX_ACTIVE_REWARD_STREAMS: u32 = 20;
+
+#[cw_serde]
+/// A distribution of an asset that is released into its global index gradually over time.
+pub struct RewardStream {
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+    /// Amount of the stream that was already added to the asset's global index
+    pub released_amount: Uint128,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+impl RewardStream {
+    /// Amount of the stream released by the given time that was not yet added to the global index.
+    pub fn releasable_amount(&self, now: Timestamp) -> DistributorResult<Uint128> {
+        let duration = self.ends_at.seconds() - self.starts_at.seconds();
+        let elapsed = now
+            .min(self.ends_at)
+            .seconds()
+            .saturating_sub(self.starts_at.seconds());
+
+        let released_by_now = self.amount.checked_multiply_ratio(elapsed, duration)?;
+
+        Ok(released_by_now.saturating_sub(self.released_amount))
+    }
+}
+
+/// Reward streams that are not yet fully released, keyed by their ID.
+pub const REWARD_STREAMS: Map<u64, RewardStream> = Map::new("reward_streams");
+
+pub const NEXT_REWARD_STREAM_ID: Item<u64> = Item::new("next_reward_stream_id");
+
+/// Only the admin and the DAO's treasury can create reward streams.
+pub fn assert_can_create_reward_streams(ctx: &Context, creator: &str) -> DistributorResult<()> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+    if creator == admin.as_str() {
+        return Ok(());
+    }
+
+    let treasury = query_enterprise_components(ctx)?.enterprise_treasury_contract;
+    if creator == treasury.as_str() {
+        return Ok(());
+    }
+
+    Err(Unauthorized)
+}
+
+/// Creates a stream that releases the given amount of the asset linearly, starting now
+/// and ending after the given duration. Returns the ID of the new stream.
+pub fn create_reward_stream(
+    mut deps: DepsMut,
+    asset: AssetInfo,
+    amount: Uint128,
+    now: Timestamp,
+    duration: u64,
+) -> DistributorResult<u64> {
+    if duration == 0 {
+        return Err(ZeroRewardStreamDuration);
+    }
+
+    let ends_at = duration
+        .checked_mul(1_000_000_000)
+        .and_then(|duration_nanos| now.nanos().checked_add(duration_nanos))
+        .map(Timestamp::from_nanos)
+        .ok_or(RewardStreamDurationTooLong)?;
+
+    // fully released streams are only removed once accrued, so they don't count towards the limit
+    accrue_reward_streams(deps.branch(), now)?;
+
+    let active_streams = REWARD_STREAMS
+        .keys(deps.storage, None, None, Ascending)
+        .count();
+    if active_streams >= MAX_ACTIVE_REWARD_STREAMS as usize {
+        return Err(MaximumRewardStreamsExceeded {
+            maximum: MAX_ACTIVE_REWARD_STREAMS,
+        });
+    }
+
+    let id = NEXT_REWARD_STREAM_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    NEXT_REWARD_STREAM_ID.save(deps.storage, &(id + 1))?;
+
+    // streams are logged in the history once, while trailing rewards count them as they're released
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(deps.storage)?;
+    save_distribution_record(deps.storage, asset.clone(), amount, total_weight, now)?;
+
+    REWARD_STREAMS.save(
+        deps.storage,
+        id,
+        &RewardStream {
+            asset,
+            amount,
+            released_amount: Uint128::zero(),
+            starts_at: now,
+            ends_at,
+        },
+    )?;
+
+    Ok(id)
+}
+
+/// Adds whatever the reward streams released since they were last accrued to their assets'
+/// global indices, distributing it according to the current total weight.
+///
+/// Has to be called before any change to the total weight or to users' weights, otherwise
+/// the released amounts would be distributed according to the new weights.
+///
+/// If the total weight is 0, nothing is accrued and the released amounts are distributed
+/// once there are eligible users again.
+pub fn accrue_reward_streams(deps: DepsMut, now: Timestamp) -> DistributorResult<()> {
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(deps.storage)?;
+    if total_weight.is_zero() {
+        return Ok(());
+    }
+
+    let streams = REWARD_STREAMS
+        .range(deps.storage, None, None, Ascending)
+        .collect::<StdResult<Vec<(u64, RewardStream)>>>()?;
+
+    // amounts released by streams of the same asset are added to its global index together
+    let mut released_amounts: BTreeMap<String, (AssetInfo, Uint128)> = BTreeMap::new();
+
+    for (id, stream) in streams {
+        let releasable_amount = stream.releasable_amount(now)?;
+
+        if releasable_amount.is_zero() {
+            continue;
+        }
+
+        let (_, asset_released_amount) = released_amounts
+            .entry(stream.asset.to_string())
+            .or_insert_with(|| (stream.asset.clone(), Uint128::zero()));
+        *asset_released_amount = asset_released_amount.checked_add(releasable_amount)?;
+
+        let released_amount = stream.released_amount + releasable_amount;
+
+        if released_amount == stream.amount {
+            REWARD_STREAMS.remove(deps.storage, id);
+        } else {
+            REWARD_STREAMS.save(
+                deps.storage,
+                id,
+                &RewardStream {
+                    released_amount,
+                    ..stream
+                },
+            )?;
+        }
+    }
+
+    for (asset, released_amount) in released_amounts.into_values() {
+        increase_global_index(
+            deps.storage,
+            &asset,
+            Decimal::from_ratio(released_amount, total_weight),
+        )?;
+
+        add_to_period_distributions(deps.storage, &asset, released_amount, total_weight, now)?;
+    }
+
+    Ok(())
+}
+
+/// Calculates how much the asset's global index would increase if reward streams
+/// were accrued at the given time.
+pub fn pending_global_index_increment(
+    storage: &dyn Storage,
+    asset: &AssetInfo,
+    now: Timestamp,
+) -> DistributorResult<Decimal> {
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(storage)?;
+    if total_weight.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    let mut increment = Decimal::zero();
+
+    for stream in REWARD_STREAMS.range(storage, None, None, Ascending) {
+        let (_, stream) = stream?;
+
+        if &stream.asset == asset {
+            let releasable_amount = stream.releasable_amount(now)?;
+            increment =
+                increment.checked_add(Decimal::from_ratio(releasable_amount, total_weight))?;
+        }
+    }
+
+    Ok(increment)
+}
+
+fn increase_global_index(
+    storage: &mut dyn Storage,
+    asset: &AssetInfo,
+    increment: Decimal,
+) -> DistributorResult<()> {
+    match asset {
+        AssetInfo::Native(denom) => {
+            let global_index = NATIVE_GLOBAL_INDICES
+                .may_load(storage, denom.clone())?
+                .unwrap_or_default();
+            NATIVE_GLOBAL_INDICES.save(
+                storage,
+                denom.clone(),
+                &global_index.checked_add(increment)?,
+            )?;
+        }
+        AssetInfo::Cw20(addr) => {
+            let global_index = CW20_GLOBAL_INDICES
+                .may_load(storage, addr.clone())?
+                .unwrap_or_default();
+            CW20_GLOBAL_INDICES.save(
+                storage,
+                addr.clone(),
+                &global_index.checked_add(increment)?,
+            )?;
+        }
+        AssetInfo::Cw1155(addr, token_id) => {
+            let key = (addr.clone(), token_id.clone());
+            let global_index = CW1155_GLOBAL_INDICES
+                .may_load(storage, key.clone())?
+                .unwrap_or_default();
+            CW1155_GLOBAL_INDICES.save(storage, key, &global_index.checked_add(increment)?)?;
+        }
+        _ => return Err(StdError::generic_err("Unsupported reward stream asset").into()),
+    }
+
+    Ok(())
+}
+
+pub fn query_reward_streams(
+    qctx: QueryContext,
+    params: RewardStreamsParams,
+) -> DistributorResult<RewardStreamsResponse> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let streams = REWARD_STREAMS
+        .range(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|stream| -> DistributorResult<ActiveRewardStream> {
+            let (id, stream) = stream?;
+
+            let released_amount =
+                stream.released_amount + stream.releasable_amount(qctx.env.block.time)?;
+
+            Ok(ActiveRewardStream {
+                id,
+                asset: to_reward_asset(&stream.asset)?,
+                amount: stream.amount,
+                released_amount,
+                starts_at: stream.starts_at,
+                ends_at: stream.ends_at,
+            })
+        })
+        .collect::<DistributorResult<Vec<ActiveRewardStream>>>()?;
+
+    Ok(RewardStreamsResponse { streams })
+}
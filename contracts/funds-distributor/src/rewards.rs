@@ -1,11 +1,19 @@
+use crate::cw1155_distributions::CW1155_DISTRIBUTIONS;
 use crate::cw20_distributions::CW20_DISTRIBUTIONS;
 use crate::native_distributions::NATIVE_DISTRIBUTIONS;
-use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::reward_streams::pending_global_index_increment;
+use crate::state::{
+    CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, DISTRIBUTED_ASSETS, NATIVE_GLOBAL_INDICES,
+};
 use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
 use common::cw::QueryContext;
-use cosmwasm_std::{Addr, Decimal, Fraction, Uint128};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Api, Decimal, Deps, Fraction, StdError, StdResult, Timestamp, Uint128};
+use cw_asset::AssetInfo;
+use cw_storage_plus::Bound;
 use funds_distributor_api::api::{
-    Cw20Reward, NativeReward, UserRewardsParams, UserRewardsResponse,
+    AllUserRewardsParams, AllUserRewardsResponse, Cw1155Reward, Cw20Reward, NativeReward,
+    RewardAsset, UserReward, UserRewardsParams, UserRewardsResponse,
 };
 use funds_distributor_api::error::DistributorResult;
 use std::collections::HashSet;
@@ -63,7 +71,12 @@ pub fn query_user_rewards(
 
         let global_index = NATIVE_GLOBAL_INDICES
             .may_load(qctx.deps.storage, denom.clone())?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .checked_add(pending_global_index_increment(
+                qctx.deps.storage,
+                &AssetInfo::native(denom.clone()),
+                qctx.env.block.time,
+            )?)?;
 
         let distribution =
             NATIVE_DISTRIBUTIONS().may_load(qctx.deps.storage, (user.clone(), denom.clone()))?;
@@ -91,7 +104,12 @@ pub fn query_user_rewards(
 
         let global_index = CW20_GLOBAL_INDICES
             .may_load(qctx.deps.storage, asset.clone())?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .checked_add(pending_global_index_increment(
+                qctx.deps.storage,
+                &AssetInfo::cw20(asset.clone()),
+                qctx.env.block.time,
+            )?)?;
 
         let distribution =
             CW20_DISTRIBUTIONS().may_load(qctx.deps.storage, (user.clone(), asset.clone()))?;
@@ -104,8 +122,179 @@ pub fn query_user_rewards(
         });
     }
 
+    let mut cw1155_rewards: Vec<Cw1155Reward> = vec![];
+
+    let mut cw1155_asset_set: HashSet<(Addr, String)> = HashSet::new();
+
+    for cw1155_asset in params.cw1155_assets {
+        let asset = qctx.deps.api.addr_validate(&cw1155_asset.contract)?;
+        let token_id = cw1155_asset.token_id;
+
+        if cw1155_asset_set.contains(&(asset.clone(), token_id.clone())) {
+            continue;
+        }
+
+        cw1155_asset_set.insert((asset.clone(), token_id.clone()));
+
+        let global_index = CW1155_GLOBAL_INDICES
+            .may_load(qctx.deps.storage, (asset.clone(), token_id.clone()))?
+            .unwrap_or_default()
+            .checked_add(pending_global_index_increment(
+                qctx.deps.storage,
+                &AssetInfo::cw1155(asset.clone(), token_id.clone()),
+                qctx.env.block.time,
+            )?)?;
+
+        let distribution = CW1155_DISTRIBUTIONS().may_load(
+            qctx.deps.storage,
+            (user.clone(), asset.clone(), token_id.clone()),
+        )?;
+
+        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+
+        cw1155_rewards.push(Cw1155Reward {
+            contract: asset.to_string(),
+            token_id,
+            amount: reward,
+        });
+    }
+
     Ok(UserRewardsResponse {
         native_rewards,
         cw20_rewards,
+        cw1155_rewards,
     })
 }
+
+const DEFAULT_QUERY_LIMIT: u32 = 50;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+pub fn query_all_user_rewards(
+    qctx: QueryContext,
+    params: AllUserRewardsParams,
+) -> DistributorResult<AllUserRewardsResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let user_weight = EFFECTIVE_USER_WEIGHTS
+        .may_load(qctx.deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    let start_after = params
+        .start_after
+        .map(|asset| to_asset_info(qctx.deps.api, asset))
+        .transpose()?
+        .map(|asset| asset.to_string());
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let assets = DISTRIBUTED_ASSETS
+        .range(
+            qctx.deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, asset)| asset))
+        .collect::<StdResult<Vec<AssetInfo>>>()?;
+
+    let mut rewards: Vec<UserReward> = vec![];
+
+    for asset in assets {
+        let reward = calculate_current_user_reward(
+            qctx.deps,
+            &user,
+            user_weight,
+            &asset,
+            qctx.env.block.time,
+        )?;
+
+        if reward.is_zero() {
+            continue;
+        }
+
+        rewards.push(UserReward {
+            asset: to_reward_asset(&asset)?,
+            amount: reward,
+        });
+    }
+
+    Ok(AllUserRewardsResponse { rewards })
+}
+
+/// Calculates user's rewards for an asset at the given time,
+/// including what reward streams released since they were last accrued.
+fn calculate_current_user_reward(
+    deps: Deps,
+    user: &Addr,
+    user_weight: Uint128,
+    asset: &AssetInfo,
+    now: Timestamp,
+) -> DistributorResult<Uint128> {
+    let pending_increment = pending_global_index_increment(deps.storage, asset, now)?;
+
+    match asset {
+        AssetInfo::Native(denom) => {
+            let global_index = NATIVE_GLOBAL_INDICES
+                .may_load(deps.storage, denom.clone())?
+                .unwrap_or_default()
+                .checked_add(pending_increment)?;
+            let distribution =
+                NATIVE_DISTRIBUTIONS().may_load(deps.storage, (user.clone(), denom.clone()))?;
+
+            calculate_user_reward(global_index, distribution, user_weight)
+        }
+        AssetInfo::Cw20(addr) => {
+            let global_index = CW20_GLOBAL_INDICES
+                .may_load(deps.storage, addr.clone())?
+                .unwrap_or_default()
+                .checked_add(pending_increment)?;
+            let distribution =
+                CW20_DISTRIBUTIONS().may_load(deps.storage, (user.clone(), addr.clone()))?;
+
+            calculate_user_reward(global_index, distribution, user_weight)
+        }
+        AssetInfo::Cw1155(addr, token_id) => {
+            let global_index = CW1155_GLOBAL_INDICES
+                .may_load(deps.storage, (addr.clone(), token_id.clone()))?
+                .unwrap_or_default()
+                .checked_add(pending_increment)?;
+            let distribution = CW1155_DISTRIBUTIONS()
+                .may_load(deps.storage, (user.clone(), addr.clone(), token_id.clone()))?;
+
+            calculate_user_reward(global_index, distribution, user_weight)
+        }
+        _ => Err(StdError::generic_err("Unsupported reward asset").into()),
+    }
+}
+
+pub fn to_reward_asset(asset: &AssetInfo) -> DistributorResult<RewardAsset> {
+    match asset {
+        AssetInfo::Native(denom) => Ok(RewardAsset::Native {
+            denom: denom.clone(),
+        }),
+        AssetInfo::Cw20(addr) => Ok(RewardAsset::Cw20 {
+            asset: addr.to_string(),
+        }),
+        AssetInfo::Cw1155(addr, token_id) => Ok(RewardAsset::Cw1155 {
+            contract: addr.to_string(),
+            token_id: token_id.clone(),
+        }),
+        _ => Err(StdError::generic_err("Unsupported reward asset").into()),
+    }
+}
+
+pub fn to_asset_info(api: &dyn Api, asset: RewardAsset) -> StdResult<AssetInfo> {
+    let asset = match asset {
+        RewardAsset::Native { denom } => AssetInfo::native(denom),
+        RewardAsset::Cw20 { asset } => AssetInfo::cw20(api.addr_validate(&asset)?),
+        RewardAsset::Cw1155 { contract, token_id } => {
+            AssetInfo::cw1155(api.addr_validate(&contract)?, token_id)
+        }
+    };
+
+    Ok(asset)
+}
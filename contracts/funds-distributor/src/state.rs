@@ -1,4 +1,5 @@
 use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_asset::AssetInfo;
 use cw_storage_plus::{Item, Map};
 
 pub const ADMIN: Item<Addr> = Item::new("admin");
@@ -16,3 +17,11 @@ pub const NATIVE_GLOBAL_INDICES: Map<String, Decimal> = Map::new("native_global_
 /// Global index is simply a decimal number representing the amount of currency rewards paid
 /// for a unit of user weight, since the beginning of time.
 pub const CW20_GLOBAL_INDICES: Map<Addr, Decimal> = Map::new("cw20_global_indices");
+
+/// Tracks global index for CW1155 token rewards, keyed by (contract, token ID).
+/// Global index is simply a decimal number representing the amount of currency rewards paid
+/// for a unit of user weight, since the beginning of time.
+pub const CW1155_GLOBAL_INDICES: Map<(Addr, String), Decimal> = Map::new("cw1155_global_indices");
+
+/// All the assets that were ever distributed, keyed by their string representation.
+pub const DISTRIBUTED_ASSETS: Map<String, AssetInfo> = Map::new("distributed_assets");
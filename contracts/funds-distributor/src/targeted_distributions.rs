@@ -0,0 +1,171 @@
+use crate::cw1155_distributions::{Cw1155Distribution, CW1155_DISTRIBUTIONS};
+use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
+use crate::distributing::{assert_assets_whitelisted, save_distributed_asset};
+use crate::distribution_log::log_distribution;
+use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
+use crate::rewards::calculate_user_reward;
+use crate::state::{CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
+use common::cw::Context;
+use cosmwasm_std::{Addr, DepsMut, Response, StdError, Uint128};
+use cw1155::Cw1155ReceiveMsg;
+use cw20::Cw20ReceiveMsg;
+use cw_asset::AssetInfo;
+use funds_distributor_api::api::DistributeTargetedMsg;
+use funds_distributor_api::error::DistributorError::InvalidTargetedDistributionFunds;
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::distribute_targeted_response;
+
+/// Distributes a native asset found in MessageInfo to the users given in the allocations.
+pub fn distribute_native_targeted(
+    ctx: &mut Context,
+    msg: DistributeTargetedMsg,
+) -> DistributorResult<Response> {
+    if ctx.info.funds.len() != 1 {
+        return Err(InvalidTargetedDistributionFunds);
+    }
+
+    let fund = ctx.info.funds[0].clone();
+
+    distribute_targeted(ctx, AssetInfo::native(fund.denom), fund.amount, msg)
+}
+
+/// Distributes a CW20 asset to the users given in the allocations.
+pub fn distribute_cw20_targeted(
+    ctx: &mut Context,
+    cw20_msg: Cw20ReceiveMsg,
+    msg: DistributeTargetedMsg,
+) -> DistributorResult<Response> {
+    let asset = AssetInfo::cw20(ctx.info.sender.clone());
+
+    distribute_targeted(ctx, asset, cw20_msg.amount, msg)
+}
+
+/// Distributes a CW1155 asset to the users given in the allocations.
+pub fn distribute_cw1155_targeted(
+    ctx: &mut Context,
+    cw1155_msg: Cw1155ReceiveMsg,
+    msg: DistributeTargetedMsg,
+) -> DistributorResult<Response> {
+    let asset = AssetInfo::cw1155(ctx.info.sender.clone(), cw1155_msg.token_id);
+
+    distribute_targeted(ctx, asset, cw1155_msg.amount, msg)
+}
+
+/// Adds each user's allocation directly to their pending rewards for the asset,
+/// without touching the asset's global index.
+fn distribute_targeted(
+    ctx: &mut Context,
+    asset: AssetInfo,
+    amount: Uint128,
+    msg: DistributeTargetedMsg,
+) -> DistributorResult<Response> {
+    assert_assets_whitelisted(ctx, vec![asset.clone()])?;
+
+    let allocations = msg.allocations.to_amounts(amount)?;
+
+    save_distributed_asset(ctx.deps.storage, &asset)?;
+
+    for allocation in &allocations {
+        let user = ctx.deps.api.addr_validate(&allocation.user)?;
+
+        add_user_reward(ctx.deps.branch(), user, &asset, allocation.amount)?;
+    }
+
+    log_distribution(
+        ctx.deps.storage,
+        asset.clone(),
+        amount,
+        Uint128::zero(),
+        ctx.env.block.time,
+    )?;
+
+    Ok(distribute_targeted_response(
+        asset.to_string(),
+        amount,
+        allocations.len(),
+    ))
+}
+
+/// Brings the user's distribution for the asset up to the current global index,
+/// then adds the given reward to their pending rewards.
+fn add_user_reward(
+    deps: DepsMut,
+    user: Addr,
+    asset: &AssetInfo,
+    reward: Uint128,
+) -> DistributorResult<()> {
+    let user_weight = EFFECTIVE_USER_WEIGHTS
+        .may_load(deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    match asset {
+        AssetInfo::Native(denom) => {
+            let global_index = NATIVE_GLOBAL_INDICES
+                .may_load(deps.storage, denom.clone())?
+                .unwrap_or_default();
+            let distribution =
+                NATIVE_DISTRIBUTIONS().may_load(deps.storage, (user.clone(), denom.clone()))?;
+
+            let pending_rewards = calculate_user_reward(global_index, distribution, user_weight)?
+                .checked_add(reward)?;
+
+            NATIVE_DISTRIBUTIONS().save(
+                deps.storage,
+                (user.clone(), denom.clone()),
+                &NativeDistribution {
+                    user,
+                    denom: denom.clone(),
+                    user_index: global_index,
+                    pending_rewards,
+                },
+            )?;
+        }
+        AssetInfo::Cw20(addr) => {
+            let global_index = CW20_GLOBAL_INDICES
+                .may_load(deps.storage, addr.clone())?
+                .unwrap_or_default();
+            let distribution =
+                CW20_DISTRIBUTIONS().may_load(deps.storage, (user.clone(), addr.clone()))?;
+
+            let pending_rewards = calculate_user_reward(global_index, distribution, user_weight)?
+                .checked_add(reward)?;
+
+            CW20_DISTRIBUTIONS().save(
+                deps.storage,
+                (user.clone(), addr.clone()),
+                &Cw20Distribution {
+                    user,
+                    cw20_asset: addr.clone(),
+                    user_index: global_index,
+                    pending_rewards,
+                },
+            )?;
+        }
+        AssetInfo::Cw1155(addr, token_id) => {
+            let global_index = CW1155_GLOBAL_INDICES
+                .may_load(deps.storage, (addr.clone(), token_id.clone()))?
+                .unwrap_or_default();
+            let distribution = CW1155_DISTRIBUTIONS()
+                .may_load(deps.storage, (user.clone(), addr.clone(), token_id.clone()))?;
+
+            let pending_rewards = calculate_user_reward(global_index, distribution, user_weight)?
+                .checked_add(reward)?;
+
+            CW1155_DISTRIBUTIONS().save(
+                deps.storage,
+                (user.clone(), addr.clone(), token_id.clone()),
+                &Cw1155Distribution {
+                    user,
+                    cw1155_asset: addr.clone(),
+                    token_id: token_id.clone(),
+                    user_index: global_index,
+                    pending_rewards,
+                },
+            )?;
+        }
+        _ => return Err(StdError::generic_err("Unsupported reward asset").into()),
+    }
+
+    Ok(())
+}
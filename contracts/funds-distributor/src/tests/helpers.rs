@@ -0,0 +1,171 @@
+use crate::contract::instantiate;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, OwnedDeps, StdError, StdResult, SystemError,
+    SystemResult, Uint128, WasmQuery,
+};
+use cw_asset::AssetInfo;
+use cw_utils::Duration;
+use denom_staking_api::api::DenomConfigResponse;
+use enterprise_protocol::api::{
+    ComponentContractsResponse, DaoInfoResponse, DaoMetadata, DaoSocialData, DaoType,
+    IsRestrictedUserResponse, Logo,
+};
+use enterprise_treasury_api::api::AssetWhitelistResponse;
+use enterprise_versioning_api::api::Version;
+use funds_distributor_api::api::UserWeight;
+use funds_distributor_api::msg::InstantiateMsg;
+use token_staking_api::api::TokenConfigResponse;
+
+pub const ADMIN: &str = "admin";
+pub const ENTERPRISE: &str = "enterprise";
+pub const TREASURY: &str = "treasury";
+pub const FACTORY: &str = "enterprise_factory";
+pub const MEMBERSHIP: &str = "membership";
+/// Token staked in the membership contract of token DAOs
+pub const MEMBERSHIP_TOKEN: &str = "membership_token";
+/// Denom staked in the membership contract of denom DAOs
+pub const MEMBERSHIP_DENOM: &str = "umembership";
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Instantiates the distributor with the given user weights, mocking the DAO's contracts
+/// so that only the given assets are whitelisted.
+pub fn instantiate_distributor(
+    weights: &[(&str, u128)],
+    asset_whitelist: Vec<AssetInfo>,
+) -> MockDeps {
+    instantiate_dao_distributor(DaoType::Multisig, weights, asset_whitelist)
+}
+
+/// Same as [instantiate_distributor], for a DAO of the given type.
+pub fn instantiate_dao_distributor(
+    dao_type: DaoType,
+    weights: &[(&str, u128)],
+    asset_whitelist: Vec<AssetInfo>,
+) -> MockDeps {
+    let mut deps = mock_dependencies();
+
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let response = match contract_addr.as_str() {
+                ENTERPRISE => query_enterprise(msg, &dao_type),
+                TREASURY => query_treasury(msg, &asset_whitelist),
+                MEMBERSHIP => query_membership(msg, &dao_type),
+                FACTORY => to_json_binary(&AssetWhitelistResponse { assets: vec![] }),
+                _ => Err(StdError::generic_err(format!(
+                    "unsupported contract {}",
+                    contract_addr
+                ))),
+            };
+
+            SystemResult::Ok(response.into())
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: format!("{:?}", query),
+        }),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            enterprise_contract: ENTERPRISE.to_string(),
+            initial_weights: weights
+                .iter()
+                .map(|(user, weight)| UserWeight {
+                    user: user.to_string(),
+                    weight: Uint128::new(*weight),
+                })
+                .collect(),
+            minimum_eligible_weight: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn query_enterprise(msg: &Binary, dao_type: &DaoType) -> StdResult<Binary> {
+    match from_json(msg)? {
+        enterprise_protocol::msg::QueryMsg::ComponentContracts {} => {
+            to_json_binary(&ComponentContractsResponse {
+                enterprise_factory_contract: Addr::unchecked(FACTORY),
+                enterprise_versioning_contract: Addr::unchecked("enterprise_versioning"),
+                enterprise_governance_contract: Addr::unchecked("governance"),
+                enterprise_governance_controller_contract: Addr::unchecked("governance_controller"),
+                enterprise_outposts_contract: Addr::unchecked("enterprise_outposts"),
+                enterprise_treasury_contract: Addr::unchecked(TREASURY),
+                funds_distributor_contract: mock_env().contract.address,
+                membership_contract: Addr::unchecked(MEMBERSHIP),
+                council_membership_contract: Addr::unchecked("council_membership"),
+                attestation_contract: None,
+            })
+        }
+        enterprise_protocol::msg::QueryMsg::DaoInfo {} => to_json_binary(&DaoInfoResponse {
+            creation_date: mock_env().block.time,
+            metadata: DaoMetadata {
+                name: "dao".to_string(),
+                description: None,
+                logo: Logo::None,
+                socials: DaoSocialData {
+                    github_username: None,
+                    discord_username: None,
+                    twitter_username: None,
+                    telegram_username: None,
+                },
+            },
+            dao_type: dao_type.clone(),
+            dao_version: Version::new(1, 0, 0),
+        }),
+        enterprise_protocol::msg::QueryMsg::IsRestrictedUser(_) => {
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+        }
+    }
+}
+
+fn query_membership(msg: &Binary, dao_type: &DaoType) -> StdResult<Binary> {
+    match dao_type {
+        DaoType::Token => match from_json(msg)? {
+            token_staking_api::msg::QueryMsg::TokenConfig {} => {
+                to_json_binary(&TokenConfigResponse {
+                    enterprise_contract: Addr::unchecked(ENTERPRISE),
+                    token_contract: Addr::unchecked(MEMBERSHIP_TOKEN),
+                    unlocking_period: Duration::Time(0),
+                })
+            }
+            _ => Err(StdError::generic_err("unsupported membership query")),
+        },
+        DaoType::Denom => match from_json(msg)? {
+            denom_staking_api::msg::QueryMsg::DenomConfig {} => {
+                to_json_binary(&DenomConfigResponse {
+                    enterprise_contract: Addr::unchecked(ENTERPRISE),
+                    denom: MEMBERSHIP_DENOM.to_string(),
+                    unlocking_period: Duration::Time(0),
+                })
+            }
+            _ => Err(StdError::generic_err("unsupported membership query")),
+        },
+        _ => Err(StdError::generic_err("unsupported membership query")),
+    }
+}
+
+fn query_treasury(msg: &Binary, asset_whitelist: &[AssetInfo]) -> StdResult<Binary> {
+    match from_json(msg)? {
+        enterprise_treasury_api::msg::QueryMsg::AssetWhitelist(params) => {
+            // the whole whitelist fits in the first page
+            let assets = match params.start_after {
+                None => asset_whitelist.to_vec(),
+                Some(_) => vec![],
+            };
+            to_json_binary(&AssetWhitelistResponse { assets })
+        }
+        _ => Err(StdError::generic_err("unsupported treasury query")),
+    }
+}
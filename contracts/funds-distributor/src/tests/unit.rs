@@ -1 +1,649 @@
+use crate::contract::{execute, query};
+use crate::reward_streams::{RewardStream, MAX_ACTIVE_REWARD_STREAMS};
+use crate::tests::helpers::{
+    instantiate_dao_distributor, instantiate_distributor, MockDeps, ADMIN, MEMBERSHIP,
+    MEMBERSHIP_DENOM, MEMBERSHIP_TOKEN, TREASURY,
+};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    coin, coins, from_json, to_json_binary, wasm_execute, Addr, BankMsg, Coin, CosmosMsg, Decimal,
+    Env, Response, Timestamp, Uint128,
+};
+use cw1155::Cw1155ReceiveMsg;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_asset::{Asset, AssetInfo};
+use enterprise_protocol::api::DaoType;
+use funds_distributor_api::api::{
+    ClaimAllRewardsMsg, ClaimRewardsMsg, Cw1155Asset, DistributeTargetedMsg,
+    DistributionHistoryParams, DistributionHistoryResponse, RewardAllocations, RewardAsset,
+    StreamNativeMsg, TrailingRewardsParams, TrailingRewardsResponse, UpdateUserWeightsMsg,
+    UserAmount, UserRewardsParams, UserRewardsResponse, UserWeight,
+};
+use funds_distributor_api::error::DistributorError::{
+    MaximumRewardStreamsExceeded, RewardStreamDurationTooLong, TrailingRewardsWindowTooLong,
+    Unauthorized, ZeroRewardStreamDuration,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::msg::{Cw1155HookMsg, Cw20HookMsg, ExecuteMsg, QueryMsg, ReceiveMsg};
 
+const CW1155_CONTRACT: &str = "game_items";
+
+fn cw1155_receive_msg(token_id: &str, amount: u128) -> Cw1155ReceiveMsg {
+    Cw1155ReceiveMsg {
+        operator: TREASURY.to_string(),
+        from: Some(TREASURY.to_string()),
+        token_id: token_id.to_string(),
+        amount: Uint128::new(amount),
+        msg: to_json_binary(&Cw1155HookMsg::Distribute {}).unwrap(),
+    }
+}
+
+#[test]
+fn receive_msg_tells_cw20_and_cw1155_hooks_apart() {
+    let cw1155_msg = cw1155_receive_msg("sword", 100);
+    assert_eq!(
+        from_json::<ExecuteMsg>(cw1155_msg.clone().into_binary().unwrap()).unwrap(),
+        ExecuteMsg::Receive(ReceiveMsg::Cw1155(cw1155_msg))
+    );
+
+    let cw20_msg = Cw20ReceiveMsg {
+        sender: TREASURY.to_string(),
+        amount: Uint128::new(100),
+        msg: to_json_binary(&Cw1155HookMsg::Distribute {}).unwrap(),
+    };
+    assert_eq!(
+        from_json::<ExecuteMsg>(cw20_msg.clone().into_binary().unwrap()).unwrap(),
+        ExecuteMsg::Receive(ReceiveMsg::Cw20(cw20_msg))
+    );
+}
+
+#[test]
+fn cw1155_distribution_is_claimable_by_members() {
+    let mut deps = instantiate_distributor(
+        &[("user1", 1), ("user2", 3)],
+        vec![AssetInfo::cw1155(Addr::unchecked(CW1155_CONTRACT), "sword")],
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CW1155_CONTRACT, &[]),
+        ExecuteMsg::Receive(ReceiveMsg::Cw1155(cw1155_receive_msg("sword", 100))),
+    )
+    .unwrap();
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user2", &[]),
+        ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+            user: "user2".to_string(),
+            native_denoms: vec![],
+            cw20_assets: vec![],
+            cw1155_assets: vec![Cw1155Asset {
+                contract: CW1155_CONTRACT.to_string(),
+                token_id: "sword".to_string(),
+            }],
+            recipient: None,
+            restake: None,
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.messages[0].msg,
+        Asset::cw1155(Addr::unchecked(CW1155_CONTRACT), "sword", 75u128)
+            .transfer_from_msg(MOCK_CONTRACT_ADDR, "user2")
+            .unwrap()
+    );
+}
+
+#[test]
+fn cw1155_not_whitelisted_cannot_be_distributed() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![]);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CW1155_CONTRACT, &[]),
+        ExecuteMsg::Receive(ReceiveMsg::Cw1155(cw1155_receive_msg("sword", 100))),
+    );
+
+    assert!(result.is_err());
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+fn stream_native(
+    deps: &mut MockDeps,
+    env: Env,
+    sender: &str,
+    amount: u128,
+    duration: u64,
+) -> DistributorResult<Response> {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(sender, &coins(amount, "uluna")),
+        ExecuteMsg::StreamNative(StreamNativeMsg { duration }),
+    )
+}
+
+fn update_user_weight(deps: &mut MockDeps, env: Env, user: &str, weight: u128) {
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateUserWeights(UpdateUserWeightsMsg {
+            new_user_weights: vec![UserWeight {
+                user: user.to_string(),
+                weight: Uint128::new(weight),
+            }],
+        }),
+    )
+    .unwrap();
+}
+
+fn native_reward(deps: &MockDeps, env: Env, user: &str) -> Uint128 {
+    let response: UserRewardsResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::UserRewards(UserRewardsParams {
+                user: user.to_string(),
+                native_denoms: vec!["uluna".to_string()],
+                cw20_assets: vec![],
+                cw1155_assets: vec![],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response.native_rewards[0].amount
+}
+
+#[test]
+fn reward_stream_releases_its_amount_linearly() {
+    let starts_at = Timestamp::from_seconds(1000);
+    let stream = RewardStream {
+        asset: AssetInfo::native("uluna"),
+        amount: Uint128::new(1000),
+        released_amount: Uint128::zero(),
+        starts_at,
+        ends_at: starts_at.plus_seconds(300),
+    };
+
+    let releasable_at = |seconds: u64, released_amount: u128| {
+        RewardStream {
+            released_amount: Uint128::new(released_amount),
+            ..stream.clone()
+        }
+        .releasable_amount(starts_at.plus_seconds(seconds))
+        .unwrap()
+    };
+
+    assert_eq!(releasable_at(0, 0), Uint128::zero());
+    assert_eq!(releasable_at(1, 0), Uint128::new(3));
+    assert_eq!(releasable_at(150, 0), Uint128::new(500));
+    assert_eq!(releasable_at(150, 300), Uint128::new(200));
+    assert_eq!(releasable_at(300, 0), Uint128::new(1000));
+    assert_eq!(releasable_at(300, 999), Uint128::new(1));
+    assert_eq!(releasable_at(10_000, 500), Uint128::new(500));
+    assert_eq!(
+        stream
+            .releasable_amount(Timestamp::from_seconds(0))
+            .unwrap(),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn streamed_rewards_are_released_to_weights_at_the_time() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![AssetInfo::native("uluna")]);
+
+    stream_native(&mut deps, env_after(0), TREASURY, 1000, 100).unwrap();
+
+    assert_eq!(
+        native_reward(&deps, env_after(25), "user1"),
+        Uint128::new(250)
+    );
+
+    // user2 joins halfway through, and only gets a share of what is released afterwards
+    update_user_weight(&mut deps, env_after(50), "user2", 1);
+
+    assert_eq!(
+        native_reward(&deps, env_after(200), "user1"),
+        Uint128::new(750)
+    );
+    assert_eq!(
+        native_reward(&deps, env_after(200), "user2"),
+        Uint128::new(250)
+    );
+}
+
+#[test]
+fn streamed_rewards_released_while_total_weight_is_zero_go_to_next_eligible_users() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![AssetInfo::native("uluna")]);
+
+    stream_native(&mut deps, env_after(0), TREASURY, 1000, 100).unwrap();
+
+    update_user_weight(&mut deps, env_after(40), "user1", 0);
+
+    // nobody is eligible, so nothing is accrued
+    assert_eq!(
+        native_reward(&deps, env_after(70), "user1"),
+        Uint128::new(400)
+    );
+    update_user_weight(&mut deps, env_after(70), "user2", 1);
+
+    assert_eq!(
+        native_reward(&deps, env_after(100), "user1"),
+        Uint128::new(400)
+    );
+    assert_eq!(
+        native_reward(&deps, env_after(100), "user2"),
+        Uint128::new(600)
+    );
+}
+
+#[test]
+fn only_admin_and_treasury_can_create_reward_streams() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![AssetInfo::native("uluna")]);
+
+    let result = stream_native(&mut deps, env_after(0), "user1", 1000, 100);
+    assert_eq!(result.unwrap_err(), Unauthorized);
+
+    stream_native(&mut deps, env_after(0), ADMIN, 1000, 100).unwrap();
+    stream_native(&mut deps, env_after(0), TREASURY, 1000, 100).unwrap();
+}
+
+#[test]
+fn number_of_active_reward_streams_is_limited() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![AssetInfo::native("uluna")]);
+
+    for _ in 0..MAX_ACTIVE_REWARD_STREAMS {
+        stream_native(&mut deps, env_after(0), TREASURY, 1000, 100).unwrap();
+    }
+
+    let result = stream_native(&mut deps, env_after(0), TREASURY, 1000, 100);
+    assert_eq!(
+        result.unwrap_err(),
+        MaximumRewardStreamsExceeded {
+            maximum: MAX_ACTIVE_REWARD_STREAMS
+        }
+    );
+
+    // fully released streams make room for new ones
+    stream_native(&mut deps, env_after(100), TREASURY, 1000, 100).unwrap();
+}
+
+#[test]
+fn reward_stream_ending_beyond_representable_time_is_rejected() {
+    let mut deps = instantiate_distributor(&[("user1", 1)], vec![AssetInfo::native("uluna")]);
+
+    let result = stream_native(&mut deps, env_after(0), TREASURY, 1000, u64::MAX);
+    assert_eq!(result.unwrap_err(), RewardStreamDurationTooLong);
+
+    let result = stream_native(&mut deps, env_after(0), TREASURY, 1000, 0);
+    assert_eq!(result.unwrap_err(), ZeroRewardStreamDuration);
+}
+
+#[test]
+fn claim_all_rewards_claims_a_page_of_assets() {
+    let mut deps = instantiate_distributor(
+        &[("user1", 1)],
+        vec![
+            AssetInfo::native("uatom"),
+            AssetInfo::native("uluna"),
+            AssetInfo::native("uusd"),
+        ],
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(
+            TREASURY,
+            &[coin(10, "uatom"), coin(20, "uluna"), coin(30, "uusd")],
+        ),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+
+    let claim_all = |deps: &mut MockDeps, start_after: Option<RewardAsset>| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user1", &[]),
+            ExecuteMsg::ClaimAllRewards(ClaimAllRewardsMsg {
+                user: "user1".to_string(),
+                recipient: None,
+                restake: None,
+                start_after,
+                limit: Some(2),
+            }),
+        )
+        .unwrap()
+        .messages
+        .into_iter()
+        .map(|submsg| submsg.msg)
+        .collect::<Vec<CosmosMsg>>()
+    };
+
+    assert_eq!(
+        claim_all(&mut deps, None),
+        vec![
+            BankMsg::Send {
+                to_address: "user1".to_string(),
+                amount: coins(10, "uatom"),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "user1".to_string(),
+                amount: coins(20, "uluna"),
+            }
+            .into(),
+        ]
+    );
+
+    assert_eq!(
+        claim_all(
+            &mut deps,
+            Some(RewardAsset::Native {
+                denom: "uluna".to_string()
+            })
+        ),
+        vec![BankMsg::Send {
+            to_address: "user1".to_string(),
+            amount: coins(30, "uusd"),
+        }
+        .into()]
+    );
+}
+
+/// Instantiates the distributor for a DAO of the given type, with rewards for its only user
+/// distributed in both membership assets and in uluna.
+fn distributor_with_membership_rewards(dao_type: DaoType) -> MockDeps {
+    let mut deps = instantiate_dao_distributor(
+        dao_type,
+        &[("user1", 1)],
+        vec![
+            AssetInfo::native(MEMBERSHIP_DENOM),
+            AssetInfo::native("uluna"),
+            AssetInfo::cw20(Addr::unchecked(MEMBERSHIP_TOKEN)),
+        ],
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TREASURY, &[coin(10, MEMBERSHIP_DENOM), coin(20, "uluna")]),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MEMBERSHIP_TOKEN, &[]),
+        ExecuteMsg::Receive(ReceiveMsg::Cw20(Cw20ReceiveMsg {
+            sender: TREASURY.to_string(),
+            amount: Uint128::new(30),
+            msg: to_json_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        })),
+    )
+    .unwrap();
+
+    deps
+}
+
+fn claim(deps: &mut MockDeps, recipient: Option<&str>, restake: Option<bool>) -> Vec<CosmosMsg> {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+            user: "user1".to_string(),
+            native_denoms: vec![MEMBERSHIP_DENOM.to_string(), "uluna".to_string()],
+            cw20_assets: vec![MEMBERSHIP_TOKEN.to_string()],
+            cw1155_assets: vec![],
+            recipient: recipient.map(|recipient| recipient.to_string()),
+            restake,
+        }),
+    )
+    .unwrap()
+    .messages
+    .into_iter()
+    .map(|submsg| submsg.msg)
+    .collect()
+}
+
+fn transfer_msgs(recipient: &str) -> Vec<CosmosMsg> {
+    vec![
+        BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(10, MEMBERSHIP_DENOM),
+        }
+        .into(),
+        BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(20, "uluna"),
+        }
+        .into(),
+        Asset::cw20(Addr::unchecked(MEMBERSHIP_TOKEN), 30u128)
+            .transfer_msg(recipient)
+            .unwrap(),
+    ]
+}
+
+#[test]
+fn claimed_rewards_are_sent_to_recipient() {
+    let mut deps = distributor_with_membership_rewards(DaoType::Multisig);
+
+    assert_eq!(
+        claim(&mut deps, Some("recipient"), None),
+        transfer_msgs("recipient")
+    );
+}
+
+#[test]
+fn restaked_rewards_in_membership_token_are_staked_for_recipient() {
+    let mut deps = distributor_with_membership_rewards(DaoType::Token);
+
+    let mut expected_msgs = transfer_msgs("recipient");
+    expected_msgs[2] = wasm_execute(
+        MEMBERSHIP_TOKEN,
+        &Cw20ExecuteMsg::Send {
+            contract: MEMBERSHIP.to_string(),
+            amount: Uint128::new(30),
+            msg: to_json_binary(&token_staking_api::msg::Cw20HookMsg::Stake {
+                user: "recipient".to_string(),
+            })
+            .unwrap(),
+        },
+        vec![],
+    )
+    .unwrap()
+    .into();
+
+    assert_eq!(
+        claim(&mut deps, Some("recipient"), Some(true)),
+        expected_msgs
+    );
+}
+
+#[test]
+fn restaked_rewards_in_membership_denom_are_staked_for_user() {
+    let mut deps = distributor_with_membership_rewards(DaoType::Denom);
+
+    let mut expected_msgs = transfer_msgs("user1");
+    expected_msgs[0] = wasm_execute(
+        MEMBERSHIP,
+        &denom_staking_api::msg::ExecuteMsg::Stake {
+            user: Some("user1".to_string()),
+        },
+        coins(10, MEMBERSHIP_DENOM),
+    )
+    .unwrap()
+    .into();
+
+    assert_eq!(claim(&mut deps, None, Some(true)), expected_msgs);
+}
+
+#[test]
+fn restaked_rewards_are_transferred_when_dao_membership_cannot_be_staked() {
+    for dao_type in [DaoType::Nft, DaoType::Multisig] {
+        let mut deps = distributor_with_membership_rewards(dao_type);
+
+        assert_eq!(claim(&mut deps, None, Some(true)), transfer_msgs("user1"));
+    }
+}
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn distribution_history(
+    deps: &MockDeps,
+    asset: Option<RewardAsset>,
+    start_after: Option<u64>,
+) -> Vec<(u64, Uint128, Uint128)> {
+    let response: DistributionHistoryResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::DistributionHistory(DistributionHistoryParams {
+                asset,
+                start_after,
+                limit: Some(3),
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response
+        .distributions
+        .into_iter()
+        .map(|record| (record.id, record.amount, record.total_weight))
+        .collect()
+}
+
+fn trailing_rewards(deps: &MockDeps, env: Env, window: u64) -> DistributorResult<(Decimal, u128)> {
+    let response: TrailingRewardsResponse = from_json(query(
+        deps.as_ref(),
+        env,
+        QueryMsg::TrailingRewards(TrailingRewardsParams {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+            window,
+        }),
+    )?)?;
+
+    Ok((
+        response.reward_per_unit_weight,
+        response.total_amount.u128(),
+    ))
+}
+
+/// Distributes 100uluna on the first day, and two days later 40uluna to all users,
+/// 30uluna to user1 only, 10uatom to all users and streams 100uluna over 100 seconds.
+fn distribute_over_two_days() -> MockDeps {
+    let mut deps = instantiate_distributor(
+        &[("user1", 1), ("user2", 1)],
+        vec![AssetInfo::native("uluna"), AssetInfo::native("uatom")],
+    );
+
+    let distribute = |deps: &mut MockDeps, env: Env, funds: Vec<Coin>| {
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(TREASURY, &funds),
+            ExecuteMsg::DistributeNative {},
+        )
+        .unwrap();
+    };
+
+    distribute(&mut deps, env_after(0), coins(100, "uluna"));
+    distribute(&mut deps, env_after(2 * DAY), coins(40, "uluna"));
+    execute(
+        deps.as_mut(),
+        env_after(2 * DAY),
+        mock_info(TREASURY, &coins(30, "uluna")),
+        ExecuteMsg::DistributeNativeTargeted(DistributeTargetedMsg {
+            allocations: RewardAllocations::Amounts(vec![UserAmount {
+                user: "user1".to_string(),
+                amount: Uint128::new(30),
+            }]),
+        }),
+    )
+    .unwrap();
+    distribute(&mut deps, env_after(2 * DAY), coins(10, "uatom"));
+    stream_native(&mut deps, env_after(2 * DAY), TREASURY, 100, 100).unwrap();
+
+    deps
+}
+
+#[test]
+fn distribution_history_is_filtered_by_asset_before_pagination() {
+    let deps = distribute_over_two_days();
+
+    let uluna = || {
+        Some(RewardAsset::Native {
+            denom: "uluna".to_string(),
+        })
+    };
+
+    // the stream is logged once, when created, and the targeted distribution with no weight
+    assert_eq!(
+        distribution_history(&deps, uluna(), None),
+        vec![
+            (4, Uint128::new(100), Uint128::new(2)),
+            (2, Uint128::new(30), Uint128::zero()),
+            (1, Uint128::new(40), Uint128::new(2)),
+        ]
+    );
+    assert_eq!(
+        distribution_history(&deps, uluna(), Some(1)),
+        vec![(0, Uint128::new(100), Uint128::new(2))]
+    );
+    assert_eq!(
+        distribution_history(&deps, None, None),
+        vec![
+            (4, Uint128::new(100), Uint128::new(2)),
+            (3, Uint128::new(10), Uint128::new(2)),
+            (2, Uint128::new(30), Uint128::zero()),
+        ]
+    );
+}
+
+#[test]
+fn trailing_rewards_sum_up_days_within_window() {
+    let mut deps = distribute_over_two_days();
+
+    // the stream did not release anything yet
+    assert_eq!(
+        trailing_rewards(&deps, env_after(2 * DAY), DAY).unwrap(),
+        (Decimal::from_ratio(40u128, 2u128), 70)
+    );
+    assert_eq!(
+        trailing_rewards(&deps, env_after(2 * DAY), 3 * DAY).unwrap(),
+        (Decimal::from_ratio(140u128, 2u128), 170)
+    );
+
+    // streamed rewards count once they're released
+    update_user_weight(&mut deps, env_after(2 * DAY + 100), "user3", 2);
+    assert_eq!(
+        trailing_rewards(&deps, env_after(2 * DAY + 100), DAY).unwrap(),
+        (Decimal::from_ratio(140u128, 2u128), 170)
+    );
+
+    assert_eq!(
+        trailing_rewards(&deps, env_after(2 * DAY), 366 * DAY).unwrap_err(),
+        TrailingRewardsWindowTooLong { maximum: 365 * DAY }
+    );
+}
@@ -1,11 +1,17 @@
+use crate::cw1155_distributions::{Cw1155Distribution, CW1155_DISTRIBUTIONS};
 use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
 use crate::eligibility::MINIMUM_ELIGIBLE_WEIGHT;
 use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
-use crate::state::{ADMIN, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT, NATIVE_GLOBAL_INDICES};
-use crate::{cw20_distributions, native_distributions};
+use crate::reward_streams::accrue_reward_streams;
+use crate::state::{
+    ADMIN, CW1155_GLOBAL_INDICES, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT,
+    NATIVE_GLOBAL_INDICES,
+};
+use crate::{cw1155_distributions, cw20_distributions, native_distributions};
 use common::cw::Context;
 use cosmwasm_std::Order::Ascending;
 use cosmwasm_std::{Addr, Decimal, DepsMut, Response, StdResult, Uint128};
+use cw1155_distributions::update_user_cw1155_distributions;
 use cw20_distributions::update_user_cw20_distributions;
 use cw_storage_plus::Map;
 use funds_distributor_api::api::{UpdateUserWeightsMsg, UserWeight};
@@ -71,6 +77,9 @@ pub fn update_user_weights(
         return Err(Unauthorized);
     }
 
+    // streamed rewards released until now belong to the users with their old weights
+    accrue_reward_streams(ctx.deps.branch(), ctx.env.block.time)?;
+
     update_user_weights_checked(ctx.deps.branch(), msg)
 }
 
@@ -108,6 +117,11 @@ pub fn update_user_weights_checked(
                     user.clone(),
                     old_user_effective_weight,
                 )?;
+                update_user_cw1155_distributions(
+                    deps.branch(),
+                    user.clone(),
+                    old_user_effective_weight,
+                )?;
             }
         };
 
@@ -187,5 +201,28 @@ fn initialize_user_indices(deps: DepsMut, user: Addr) -> DistributorResult<()> {
         )?;
     }
 
+    let cw1155_global_indices = CW1155_GLOBAL_INDICES
+        .range(deps.storage, None, None, Ascending)
+        .collect::<StdResult<Vec<((Addr, String), Decimal)>>>()?;
+
+    for ((asset, token_id), global_index) in cw1155_global_indices {
+        CW1155_DISTRIBUTIONS().update(
+            deps.storage,
+            (user.clone(), asset.clone(), token_id.clone()),
+            |distribution| -> StdResult<Cw1155Distribution> {
+                match distribution {
+                    None => Ok(Cw1155Distribution {
+                        user: user.clone(),
+                        cw1155_asset: asset,
+                        token_id,
+                        user_index: global_index,
+                        pending_rewards: Uint128::zero(),
+                    }),
+                    Some(distribution) => Ok(distribution),
+                }
+            },
+        )?;
+    }
+
     Ok(())
 }
@@ -1,2 +1,3 @@
 pub mod commons;
 pub mod cw;
+pub mod nft;
@@ -0,0 +1,45 @@
+use cosmwasm_std::{Addr, Deps, StdResult};
+use cw721::{Cw721QueryMsg, TokensResponse};
+
+const DEFAULT_TOKEN_IDS_LIMIT: u32 = 30;
+const MAX_TOKEN_IDS_LIMIT: u32 = 100;
+
+pub struct OwnedTokenIds {
+    pub token_ids: Vec<String>,
+    /// Set if the owner may hold more tokens than were returned, to be used as start_after
+    /// to continue listing them.
+    pub next_start_after: Option<String>,
+}
+
+/// Queries a page of the token IDs of the NFT contract that the owner holds.
+pub fn query_owned_token_ids(
+    deps: Deps,
+    nft_contract: &Addr,
+    owner: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<OwnedTokenIds> {
+    let limit = limit
+        .unwrap_or(DEFAULT_TOKEN_IDS_LIMIT)
+        .min(MAX_TOKEN_IDS_LIMIT);
+
+    let response: TokensResponse = deps.querier.query_wasm_smart(
+        nft_contract.to_string(),
+        &Cw721QueryMsg::Tokens {
+            owner: owner.to_string(),
+            start_after,
+            limit: Some(limit),
+        },
+    )?;
+
+    let next_start_after = if response.tokens.len() < limit as usize {
+        None
+    } else {
+        response.tokens.last().cloned()
+    };
+
+    Ok(OwnedTokenIds {
+        token_ids: response.tokens,
+        next_start_after,
+    })
+}
@@ -39,7 +39,8 @@ pub fn stake_denom(ctx: &mut Context, user: Option<String>) -> DenomStakingResul
     validate_user_not_restricted(ctx.deps.as_ref(), user.to_string())?;
 
     let old_weight = get_member_weight(ctx.deps.storage, user.clone())?;
-    let new_weight = increment_member_weight(ctx.deps.storage, user.clone(), coin.amount)?;
+    let new_weight =
+        increment_member_weight(ctx.deps.storage, user.clone(), coin.amount, &ctx.env.block)?;
     let new_total_staked = increment_total_weight(ctx, coin.amount)?;
 
     let report_weight_change_submsgs = report_weight_change_submsgs(
@@ -70,7 +71,12 @@ pub fn unstake(ctx: &mut Context, msg: UnstakeMsg) -> DenomStakingResult<Respons
 
     let unstaked_amount = msg.amount;
 
-    let new_weight = decrement_member_weight(ctx.deps.storage, user.clone(), unstaked_amount)?;
+    let new_weight = decrement_member_weight(
+        ctx.deps.storage,
+        user.clone(),
+        unstaked_amount,
+        &ctx.env.block,
+    )?;
     let new_total_staked = decrement_total_weight(ctx, unstaked_amount)?;
 
     let release_at = calculate_release_at(ctx)?;
@@ -8,7 +8,9 @@ use cw_utils::Expiration;
 use denom_staking_api::api::{ClaimsParams, ClaimsResponse, DenomConfigResponse};
 use denom_staking_api::error::DenomStakingResult;
 use membership_common::enterprise_contract::ENTERPRISE_CONTRACT;
-use membership_common::member_weights::{get_member_weight, MEMBER_WEIGHTS};
+use membership_common::member_weights::{
+    get_member_weight, load_member_weight_at_height, load_member_weight_at_time, MEMBER_WEIGHTS,
+};
 use membership_common::total_weight::{
     load_total_weight, load_total_weight_at_height, load_total_weight_at_time,
 };
@@ -37,7 +39,15 @@ pub fn query_user_weight(
 ) -> DenomStakingResult<UserWeightResponse> {
     let user = qctx.deps.api.addr_validate(&params.user)?;
 
-    let user_stake = get_member_weight(qctx.deps.storage, user.clone())?;
+    let user_stake = match params.expiration.unwrap_or(Expiration::Never {}) {
+        Expiration::AtHeight(height) => {
+            load_member_weight_at_height(qctx.deps.storage, user.clone(), height)?
+        }
+        Expiration::AtTime(time) => {
+            load_member_weight_at_time(qctx.deps.storage, user.clone(), time)?
+        }
+        Expiration::Never {} => get_member_weight(qctx.deps.storage, user.clone())?,
+    };
 
     Ok(UserWeightResponse {
         user,
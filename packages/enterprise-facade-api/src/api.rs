@@ -1,9 +1,11 @@
 use common::cw::ReleaseAt;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128, Uint64};
-use cw_asset::{AssetInfo, AssetInfoUnchecked};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked};
 use cw_utils::{Duration, Expiration};
-use enterprise_governance_controller_api::api::{ProposalAction, ProposalActionType};
+use enterprise_governance_controller_api::api::{
+    ProposalAction, ProposalActionType, ProposalMetadata,
+};
 use enterprise_versioning_api::api::Version;
 use poll_engine_api::api::{Vote, VoteOutcome};
 use serde_with::serde_as;
@@ -298,6 +300,9 @@ pub struct GovConfigFacade {
     /// If set to true, this will allow DAOs to execute proposals that have reached quorum and
     /// threshold, even before their voting period ends.
     pub allow_early_proposal_execution: bool,
+    /// If set to true, voting power in general proposals is fixed to members' weights at the
+    /// moment the proposal was created, instead of following their current weights.
+    pub snapshot_voting: bool,
 }
 
 #[cw_serde]
@@ -322,6 +327,37 @@ pub struct NftWhitelistResponse {
     pub nfts: Vec<Addr>,
 }
 
+#[cw_serde]
+pub struct BalancesParams {
+    /// Whitelisted asset after which to continue listing asset balances
+    pub asset_start_after: Option<AssetInfoUnchecked>,
+    /// Maximum number of assets to return
+    pub asset_limit: Option<u32>,
+    /// Whitelisted NFT contract after which to continue listing NFT holdings
+    pub nft_start_after: Option<String>,
+    /// Maximum number of NFT contracts to return
+    pub nft_limit: Option<u32>,
+    /// Maximum number of token IDs to return for each NFT contract
+    pub token_ids_limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct BalancesResponse {
+    /// Balances of whitelisted assets
+    pub assets: Vec<Asset>,
+    /// Token IDs held of each whitelisted NFT contract
+    pub nfts: Vec<NftBalance>,
+}
+
+#[cw_serde]
+pub struct NftBalance {
+    pub nft_contract: Addr,
+    pub token_ids: Vec<String>,
+    /// Set if more token IDs may be held than were returned.
+    /// The rest can be listed by querying the NFT contract's tokens, starting after this one.
+    pub token_ids_start_after: Option<String>,
+}
+
 #[cw_serde]
 pub struct MemberInfoResponse {
     pub voting_power: Decimal,
@@ -356,6 +392,10 @@ pub struct ProposalsResponse {
 pub struct ProposalsParams {
     /// Optional proposal status to filter for.
     pub filter: Option<ProposalStatusFilter>,
+    /// Optional metadata tag to filter for.
+    /// Filtering is applied to a page of proposals, so a page can contain less than 'limit' results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
     pub start_after: Option<ProposalId>,
     pub limit: Option<u32>,
     // TODO: allow ordering
@@ -380,6 +420,9 @@ pub enum ProposalStatus {
     Passed,
     Rejected,
     Executed,
+    Cancelled,
+    Expired,
+    Vetoed,
 }
 
 #[cw_serde]
@@ -387,6 +430,7 @@ pub enum ProposalStatusFilter {
     InProgress,
     Passed,
     Rejected,
+    Cancelled,
 }
 
 impl ProposalStatusFilter {
@@ -395,6 +439,7 @@ impl ProposalStatusFilter {
             ProposalStatusFilter::InProgress => status == &ProposalStatus::InProgress,
             ProposalStatusFilter::Passed => status == &ProposalStatus::Passed,
             ProposalStatusFilter::Rejected => status == &ProposalStatus::Rejected,
+            ProposalStatusFilter::Cancelled => status == &ProposalStatus::Cancelled,
         }
     }
 }
@@ -451,6 +496,9 @@ pub struct Proposal {
     pub started_at: Timestamp,
     pub expires: Expiration,
     pub proposal_actions: Vec<ProposalAction>,
+    /// Off-chain content and discussion references, None if the proposal was created without them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProposalMetadata>,
     // TODO: include quorum? difficult because cw3 doesn't support it
     // pub quorum: Decimal,
 }
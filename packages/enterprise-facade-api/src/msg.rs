@@ -1,14 +1,14 @@
 use crate::api::{
-    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, CastVoteMsg, ClaimsParams,
-    ClaimsResponse, ComponentContractsResponse, CreateProposalMsg,
-    CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg, DaoInfoResponse,
-    ExecuteProposalMsg, ListMultisigMembersMsg, MemberInfoResponse, MemberVoteParams,
-    MemberVoteResponse, MultisigMembersResponse, NftWhitelistParams, NftWhitelistResponse,
-    ProposalParams, ProposalResponse, ProposalStatusParams, ProposalStatusResponse,
-    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
-    QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse, TotalStakedAmountResponse,
-    TreasuryAddressResponse, UnstakeMsg, UserStakeParams, UserStakeResponse,
-    V2MigrationStageResponse,
+    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, BalancesParams,
+    BalancesResponse, CastVoteMsg, ClaimsParams, ClaimsResponse, ComponentContractsResponse,
+    CreateProposalMsg, CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg,
+    DaoInfoResponse, ExecuteProposalMsg, ListMultisigMembersMsg, MemberInfoResponse,
+    MemberVoteParams, MemberVoteResponse, MultisigMembersResponse, NftWhitelistParams,
+    NftWhitelistResponse, ProposalParams, ProposalResponse, ProposalStatusParams,
+    ProposalStatusResponse, ProposalVotesParams, ProposalVotesResponse, ProposalsParams,
+    ProposalsResponse, QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse,
+    TotalStakedAmountResponse, TreasuryAddressResponse, UnstakeMsg, UserStakeParams,
+    UserStakeResponse, V2MigrationStageResponse,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
@@ -56,6 +56,12 @@ pub enum QueryMsg {
         contract: Addr,
         params: NftWhitelistParams,
     },
+    /// DAO treasury's holdings of whitelisted assets and NFTs.
+    #[returns(BalancesResponse)]
+    Balances {
+        contract: Addr,
+        params: BalancesParams,
+    },
     #[returns(ProposalResponse)]
     Proposal {
         contract: Addr,
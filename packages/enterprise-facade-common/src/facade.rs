@@ -1,15 +1,15 @@
 use common::cw::QueryContext;
 use enterprise_facade_api::api::{
-    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, CastVoteMsg, ClaimsParams,
-    ClaimsResponse, ComponentContractsResponse, CreateProposalMsg,
-    CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg, DaoInfoResponse,
-    ExecuteProposalMsg, ListMultisigMembersMsg, MemberInfoResponse, MemberVoteParams,
-    MemberVoteResponse, MultisigMembersResponse, NftWhitelistParams, NftWhitelistResponse,
-    ProposalParams, ProposalResponse, ProposalStatusParams, ProposalStatusResponse,
-    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
-    QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse, TotalStakedAmountResponse,
-    TreasuryAddressResponse, UnstakeMsg, UserStakeParams, UserStakeResponse,
-    V2MigrationStageResponse,
+    AdapterResponse, AssetWhitelistParams, AssetWhitelistResponse, BalancesParams,
+    BalancesResponse, CastVoteMsg, ClaimsParams, ClaimsResponse, ComponentContractsResponse,
+    CreateProposalMsg, CreateProposalWithDenomDepositMsg, CreateProposalWithTokenDepositMsg,
+    DaoInfoResponse, ExecuteProposalMsg, ListMultisigMembersMsg, MemberInfoResponse,
+    MemberVoteParams, MemberVoteResponse, MultisigMembersResponse, NftWhitelistParams,
+    NftWhitelistResponse, ProposalParams, ProposalResponse, ProposalStatusParams,
+    ProposalStatusResponse, ProposalVotesParams, ProposalVotesResponse, ProposalsParams,
+    ProposalsResponse, QueryMemberInfoMsg, StakeMsg, StakedNftsParams, StakedNftsResponse,
+    TotalStakedAmountResponse, TreasuryAddressResponse, UnstakeMsg, UserStakeParams,
+    UserStakeResponse, V2MigrationStageResponse,
 };
 use enterprise_facade_api::error::EnterpriseFacadeResult;
 use enterprise_governance_controller_api::api::CreateProposalWithNftDepositMsg;
@@ -55,6 +55,12 @@ pub trait EnterpriseFacade {
         params: NftWhitelistParams,
     ) -> EnterpriseFacadeResult<NftWhitelistResponse>;
 
+    fn query_balances(
+        &self,
+        qctx: QueryContext,
+        params: BalancesParams,
+    ) -> EnterpriseFacadeResult<BalancesResponse>;
+
     fn query_proposal(
         &self,
         qctx: QueryContext,
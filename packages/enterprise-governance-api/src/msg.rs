@@ -1,9 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, PollId, PollParams, PollResponse,
-    PollStatusResponse, PollVoterParams, PollVoterResponse, PollVotersParams, PollVotersResponse,
-    PollsParams, PollsResponse, UpdateVotesParams, VoterParams, VoterResponse,
+    CancelPollParams, CastVoteParams, CastWeightedVoteParams, CreatePollParams, EndPollParams,
+    PollId, PollParams, PollResponse, PollStatusResponse, PollVoterParams, PollVoterResponse,
+    PollVotersParams, PollVotersResponse, PollsParams, PollsResponse, UpdatePollParams,
+    UpdateVotesParams, VoterParams, VoterResponse,
 };
 
 #[cw_serde]
@@ -15,8 +16,11 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     CreatePoll(CreatePollParams),
     CastVote(CastVoteParams),
+    CastWeightedVote(CastWeightedVoteParams),
     UpdateVotes(UpdateVotesParams),
     EndPoll(EndPollParams),
+    CancelPoll(CancelPollParams),
+    UpdatePoll(UpdatePollParams),
 }
 
 #[cw_serde]
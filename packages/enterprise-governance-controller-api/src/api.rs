@@ -1,14 +1,20 @@
+use crate::error::GovernanceControllerError::InvalidCosmosMessage;
+use crate::error::GovernanceControllerResult;
 use common::commons::ModifyValue;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, Decimal, Timestamp, Uint128, Uint64};
-use cw_asset::{AssetInfoUnchecked, AssetUnchecked};
+use cosmwasm_std::{
+    Addr, Binary, BlockInfo, CosmosMsg, Decimal, StdError, StdResult, Timestamp, Uint128, Uint64,
+};
+use cw_asset::{AssetInfo, AssetInfoUnchecked, AssetUnchecked};
 use cw_utils::{Duration, Expiration};
 use enterprise_outposts_api::api::{DeployCrossChainTreasuryMsg, RemoteTreasuryTarget};
 use enterprise_protocol::api::{UpdateMetadataMsg, UpgradeDaoMsg};
+use funds_distributor_api::api::RewardAllocations;
 use multisig_membership_api::api::UserWeight;
 use nft_staking_api::api::NftTokenId;
-use poll_engine_api::api::{Vote, VoteOutcome};
+use poll_engine_api::api::{Vote, VoteChoice, WeightedVoteChoice};
 use serde_with::serde_as;
+use std::cmp::max;
 use std::collections::BTreeMap;
 use strum_macros::Display;
 
@@ -23,14 +29,84 @@ pub struct ProposalInfo {
     pub earliest_execution: Option<Timestamp>,
     pub proposal_deposit: Option<ProposalDeposit>,
     pub proposal_actions: Vec<ProposalAction>,
+    /// If present, voting power in this proposal is fixed to voters' weights at this moment,
+    /// instead of following their current weights.
+    pub voting_power_snapshot: Option<Timestamp>,
+    /// Options of a multiple-choice proposal, None for yes/no proposals.
+    /// Once a multiple-choice proposal passes, the winning option's actions are
+    /// copied to proposal_actions to be executed.
+    pub proposal_options: Option<Vec<ProposalOption>>,
+    /// The latest time at which a passed proposal's actions can be executed.
+    /// If None, there is no deadline for execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_execution: Option<Timestamp>,
+    /// Set if the proposal was vetoed while waiting for its execution delay to pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vetoed_at: Option<BlockInfo>,
+    #[serde(default)]
+    pub execution_mode: ProposalExecutionMode,
+    /// Results of each of the proposal's actions, in the same order as proposal_actions.
+    /// Only recorded for proposals executed in the partial-failure tolerant mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub action_results: Vec<ProposalActionResult>,
+    /// Until this time, the proposal can be amended by its proposer, and cannot be voted on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_ends_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProposalMetadata>,
 }
 
 impl ProposalInfo {
+    pub fn is_in_review(&self, now: Timestamp) -> bool {
+        self.review_ends_at
+            .map(|review_ends_at| now < review_ends_at)
+            .unwrap_or(false)
+    }
+
     pub fn is_past_earliest_execution(&self, now: Timestamp) -> bool {
         self.earliest_execution
             .map(|earliest_execution| now >= earliest_execution)
             .unwrap_or(true)
     }
+
+    pub fn is_past_latest_execution(&self, now: Timestamp) -> bool {
+        self.latest_execution
+            .map(|latest_execution| now > latest_execution)
+            .unwrap_or(false)
+    }
+
+    /// Indices of the proposal's actions that failed during execution, and can be retried.
+    pub fn failed_action_indices(&self) -> Vec<u32> {
+        self.action_results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| matches!(result, ProposalActionResult::Failed { .. }))
+            .map(|(index, _)| index as u32)
+            .collect()
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum ProposalExecutionMode {
+    /// All the proposal's actions are executed together, and are all reverted if any of them fails
+    #[default]
+    Atomic,
+    /// Each of the proposal's actions is executed on its own, so a failing action does not
+    /// revert the others. Failed actions can be retried by anyone later.
+    PartialFailureTolerant,
+}
+
+#[cw_serde]
+pub enum ProposalActionResult {
+    /// The action's execution has been started, but has not completed yet
+    Pending,
+    Succeeded,
+    Failed {
+        /// The error the action's submessage failed with. The chain redacts submessage errors
+        /// down to their codespace and code, so this does not contain the full error message.
+        error: String,
+    },
 }
 
 #[cw_serde]
@@ -55,6 +131,122 @@ pub struct GovConfig {
     /// If set to true, this will allow DAOs to execute proposals that have reached quorum and
     /// threshold, even before their voting period ends.
     pub allow_early_proposal_execution: bool,
+    /// If set to true, voting power in general proposals is fixed to members' weights at the
+    /// moment the proposal was created, instead of following their current weights.
+    #[serde(default)]
+    pub snapshot_voting: bool,
+    /// Time (in seconds) after a proposal's creation during which its proposer can cancel it,
+    /// even if it has already received votes.
+    /// Regardless of this, proposers can cancel their in-progress proposals that have no votes.
+    pub proposal_cancellation_cutoff: Option<u64>,
+    /// What happens to proposal deposits depending on how the proposal ended.
+    #[serde(default)]
+    pub proposal_deposit_outcomes: ProposalDepositOutcomes,
+    /// Delay and window for executing passed general proposals.
+    #[serde(default)]
+    pub general_execution_timelock: ExecutionTimelock,
+    /// Delay and window for executing passed council proposals.
+    #[serde(default)]
+    pub council_execution_timelock: ExecutionTimelock,
+    /// Governance parameters overriding the ones above for general proposals containing
+    /// the given action types.
+    /// A proposal with multiple action types uses the strictest of their parameters.
+    #[serde(default)]
+    pub action_type_gov_params: Vec<ActionTypeGovParams>,
+    /// Time (in seconds) after a proposal's creation during which no votes are accepted,
+    /// and the proposer can amend the proposal. Voting starts once the review period ends.
+    pub review_period: Option<u64>,
+}
+
+impl GovConfig {
+    /// The longest duration a general proposal can have, given the action type overrides
+    /// and the review period preceding voting.
+    pub fn longest_vote_duration(&self) -> u64 {
+        self.action_type_gov_params
+            .iter()
+            .filter_map(|params| params.vote_duration)
+            .fold(self.vote_duration, max)
+            + self.review_period.unwrap_or_default()
+    }
+}
+
+/// Overrides of governance parameters for proposals containing a given action type.
+/// Parameters left as None are the same as in the DAO's gov config.
+#[cw_serde]
+pub struct ActionTypeGovParams {
+    pub action_type: ProposalActionType,
+    pub quorum: Option<Decimal>,
+    pub threshold: Option<Decimal>,
+    pub veto_threshold: Option<Decimal>,
+    pub vote_duration: Option<u64>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutionTimelock {
+    /// Time (in seconds) after a passed proposal is queued before its actions can be executed.
+    /// During this time, the proposal can still be vetoed.
+    /// Proposals get queued by the first attempt to execute them after their voting ends.
+    pub execution_delay: u64,
+    /// Time (in seconds) after the execution delay during which the proposal can be executed,
+    /// counting the delay from the end of voting.
+    /// Proposals not executed within this window expire, as do passed proposals that are queued
+    /// too late for their execution delay to end within it. If None, proposals never expire.
+    pub execution_window: Option<u64>,
+}
+
+#[cw_serde]
+pub struct ProposalDepositOutcomes {
+    pub passed: ProposalDepositOutcome,
+    /// Rejected for any reason other than being vetoed or not reaching quorum
+    pub rejected: ProposalDepositOutcome,
+    pub vetoed: ProposalDepositOutcome,
+    pub quorum_not_reached: ProposalDepositOutcome,
+    /// Cancelled by the proposer, or by another proposal
+    #[serde(default)]
+    pub cancelled: ProposalDepositOutcome,
+}
+
+impl Default for ProposalDepositOutcomes {
+    fn default() -> Self {
+        ProposalDepositOutcomes {
+            passed: ProposalDepositOutcome::Refund,
+            rejected: ProposalDepositOutcome::Refund,
+            vetoed: ProposalDepositOutcome::SendToTreasury,
+            quorum_not_reached: ProposalDepositOutcome::SendToTreasury,
+            cancelled: ProposalDepositOutcome::Refund,
+        }
+    }
+}
+
+impl ProposalDepositOutcomes {
+    pub fn all(&self) -> Vec<&ProposalDepositOutcome> {
+        vec![
+            &self.passed,
+            &self.rejected,
+            &self.vetoed,
+            &self.quorum_not_reached,
+            &self.cancelled,
+        ]
+    }
+}
+
+#[cw_serde]
+#[derive(Display, Default)]
+pub enum ProposalDepositOutcome {
+    /// Deposit is returned to the depositor
+    #[default]
+    Refund,
+    /// Deposit is burned. Only for native and CW20 deposits.
+    /// If burning fails, e.g. because the CW20 token does not support burning,
+    /// the deposit is sent to the treasury instead
+    Burn,
+    SendToTreasury,
+    /// Deposit is distributed to DAO members through the funds distributor.
+    /// Only for native and CW20 deposits.
+    /// If distributing fails, e.g. because there are no members eligible for rewards,
+    /// the deposit is sent to the treasury instead
+    DistributeToMembers,
 }
 
 #[cw_serde]
@@ -62,6 +254,26 @@ pub struct CouncilGovConfig {
     pub allowed_proposal_action_types: Vec<ProposalActionType>,
     pub quorum: Decimal,
     pub threshold: Decimal,
+    /// If None, council proposals can spend any amount of any asset.
+    pub spend_budgets: Option<Vec<CouncilSpendBudget>>,
+}
+
+#[cw_serde]
+pub struct CouncilSpendBudgetSpec {
+    pub asset: AssetInfoUnchecked,
+    /// Maximum amount of the asset that council proposals can spend within a period
+    pub amount: Uint128,
+    /// Length of the rolling budget period, in seconds
+    pub period: u64,
+}
+
+#[cw_serde]
+pub struct CouncilSpendBudget {
+    pub asset: AssetInfo,
+    /// Maximum amount of the asset that council proposals can spend within a period
+    pub amount: Uint128,
+    /// Length of the rolling budget period, in seconds
+    pub period: u64,
 }
 
 #[cw_serde]
@@ -80,6 +292,12 @@ pub struct DaoCouncilSpec {
     /// Effectively defines what types of actions council can propose and vote on.
     /// If None, will default to a predefined set of actions.
     pub allowed_proposal_action_types: Option<Vec<ProposalActionType>>,
+    /// Per-asset budgets limiting how much council proposals can spend from the treasury
+    /// through RequestFundingFromDao and DistributeFunds actions.
+    /// Assets without a budget cannot be spent by council proposals.
+    /// If None, council proposals can spend any amount of any asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spend_budgets: Option<Vec<CouncilSpendBudgetSpec>>,
 }
 
 #[cw_serde]
@@ -102,6 +320,48 @@ pub struct CreateProposalMsg {
     #[serde(skip_serializing_if = "Option::is_none")]
     // this flag is here to allow the facade v2 to work with gov controller <v1.1.0
     pub deposit_owner: Option<String>,
+    /// If defined, creates a multiple-choice proposal where voters pick one of the options,
+    /// instead of voting yes/no. Only the winning option's actions are executed.
+    /// Proposal actions must be empty in this case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ProposalOption>>,
+    /// How the proposal's actions are executed. If None, defaults to atomic execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_mode: Option<ProposalExecutionMode>,
+    /// Optional references to the proposal's off-chain content and discussions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProposalMetadata>,
+}
+
+impl CreateProposalMsg {
+    /// All actions of this proposal, including actions of all of its options.
+    pub fn all_proposal_actions(&self) -> impl Iterator<Item = &ProposalAction> {
+        self.proposal_actions.iter().chain(
+            self.options
+                .iter()
+                .flatten()
+                .flat_map(|option| option.proposal_actions.iter()),
+        )
+    }
+}
+
+#[cw_serde]
+pub struct ProposalOption {
+    pub label: String,
+    /// Actions to be executed, in order, if this option wins
+    pub proposal_actions: Vec<ProposalAction>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalMetadata {
+    /// Link to where the proposal is being discussed, e.g. a forum thread
+    pub discussion_url: Option<String>,
+    /// Hash of the proposal's full content stored off-chain, e.g. an IPFS CID
+    pub content_hash: Option<String>,
+    /// Category tags of the proposal, proposals can be filtered by them
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[cw_serde]
@@ -155,14 +415,22 @@ pub enum ProposalActionType {
     UpdateAssetWhitelist,
     UpdateNftWhitelist,
     RequestFundingFromDao,
+    TransferNfts,
     UpgradeDao,
     ExecuteMsgs,
     ExecuteTreasuryMsgs,
     ExecuteEnterpriseMsgs,
     ModifyMultisigMembership,
     DistributeFunds,
+    DistributeFundsTargeted,
     UpdateMinimumWeightForRewards,
     DeployCrossChainTreasury,
+    CancelProposal,
+    VetoProposal,
+    CreateStream,
+    CancelStream,
+    CreatePaymentSchedule,
+    CancelPaymentSchedule,
 }
 
 #[cw_serde]
@@ -173,14 +441,54 @@ pub enum ProposalAction {
     UpdateAssetWhitelist(UpdateAssetWhitelistProposalActionMsg),
     UpdateNftWhitelist(UpdateNftWhitelistProposalActionMsg),
     RequestFundingFromDao(RequestFundingFromDaoMsg),
+    TransferNfts(TransferNftsProposalActionMsg),
     UpgradeDao(UpgradeDaoMsg),
     ExecuteMsgs(ExecuteMsgsMsg),
     ExecuteTreasuryMsgs(ExecuteTreasuryMsgsMsg),
     ExecuteEnterpriseMsgs(ExecuteEnterpriseMsgsMsg),
     ModifyMultisigMembership(ModifyMultisigMembershipMsg),
     DistributeFunds(DistributeFundsMsg),
+    DistributeFundsTargeted(DistributeFundsTargetedMsg),
     UpdateMinimumWeightForRewards(UpdateMinimumWeightForRewardsMsg),
     DeployCrossChainTreasury(DeployCrossChainTreasuryMsg),
+    CancelProposal(CancelProposalMsg),
+    VetoProposal(VetoProposalMsg),
+    CreateStream(CreateStreamProposalActionMsg),
+    CancelStream(CancelStreamProposalActionMsg),
+    CreatePaymentSchedule(CreatePaymentScheduleProposalActionMsg),
+    CancelPaymentSchedule(CancelPaymentScheduleProposalActionMsg),
+}
+
+impl ProposalAction {
+    /// Returns the action with any Cosmos messages it executes in their structured form,
+    /// so that they are readable when querying the proposal.
+    pub fn with_decoded_msgs(self) -> Self {
+        let decode_all = |msgs: Vec<ProposalCosmosMsg>| {
+            msgs.into_iter()
+                .map(ProposalCosmosMsg::decoded)
+                .collect::<Vec<ProposalCosmosMsg>>()
+        };
+
+        match self {
+            ProposalAction::ExecuteMsgs(msg) => ProposalAction::ExecuteMsgs(ExecuteMsgsMsg {
+                msgs: decode_all(msg.msgs),
+                ..msg
+            }),
+            ProposalAction::ExecuteTreasuryMsgs(msg) => {
+                ProposalAction::ExecuteTreasuryMsgs(ExecuteTreasuryMsgsMsg {
+                    msgs: decode_all(msg.msgs),
+                    ..msg
+                })
+            }
+            ProposalAction::ExecuteEnterpriseMsgs(msg) => {
+                ProposalAction::ExecuteEnterpriseMsgs(ExecuteEnterpriseMsgsMsg {
+                    msgs: decode_all(msg.msgs),
+                    ..msg
+                })
+            }
+            action => action,
+        }
+    }
 }
 
 #[cw_serde]
@@ -192,6 +500,20 @@ pub struct UpdateGovConfigMsg {
     pub unlocking_period: ModifyValue<Duration>,
     pub minimum_deposit: ModifyValue<Option<Uint128>>,
     pub allow_early_proposal_execution: ModifyValue<bool>,
+    #[serde(default)]
+    pub snapshot_voting: ModifyValue<bool>,
+    #[serde(default)]
+    pub proposal_cancellation_cutoff: ModifyValue<Option<Uint64>>,
+    #[serde(default)]
+    pub proposal_deposit_outcomes: ModifyValue<ProposalDepositOutcomes>,
+    #[serde(default)]
+    pub general_execution_timelock: ModifyValue<ExecutionTimelock>,
+    #[serde(default)]
+    pub council_execution_timelock: ModifyValue<ExecutionTimelock>,
+    #[serde(default)]
+    pub action_type_gov_params: ModifyValue<Vec<ActionTypeGovParams>>,
+    #[serde(default)]
+    pub review_period: ModifyValue<Option<Uint64>>,
 }
 
 #[cw_serde]
@@ -199,6 +521,45 @@ pub struct UpdateCouncilMsg {
     pub dao_council: Option<DaoCouncilSpec>,
 }
 
+#[cw_serde]
+pub struct CreateStreamProposalActionMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    pub recipient: String,
+    /// Total amount of the asset to be streamed from the treasury
+    pub asset: AssetUnchecked,
+    /// Moment from which the asset starts vesting. If None, defaults to the proposal's execution.
+    pub start_time: Option<Timestamp>,
+    /// Duration, in seconds, over which the asset vests linearly
+    pub duration: u64,
+    /// Duration, in seconds, after the start during which nothing can be withdrawn
+    pub cliff: Option<u64>,
+}
+
+#[cw_serde]
+pub struct CancelStreamProposalActionMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    pub stream_id: u64,
+}
+
+#[cw_serde]
+pub struct CreatePaymentScheduleProposalActionMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    pub recipient: String,
+    /// Assets paid out in each payment
+    pub assets: Vec<AssetUnchecked>,
+    /// Moment from which the intervals are counted. If None, defaults to the proposal's execution.
+    pub start_time: Option<Timestamp>,
+    /// Time, in seconds, between payments. The first payment is due one interval after the start.
+    pub interval: u64,
+    pub number_of_payments: u32,
+}
+
+#[cw_serde]
+pub struct CancelPaymentScheduleProposalActionMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    pub schedule_id: u64,
+}
+
 #[cw_serde]
 pub struct RequestFundingFromDaoMsg {
     pub remote_treasury_target: Option<RemoteTreasuryTarget>,
@@ -206,6 +567,18 @@ pub struct RequestFundingFromDaoMsg {
     pub assets: Vec<AssetUnchecked>,
 }
 
+#[cw_serde]
+pub struct TransferNftsProposalActionMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    /// Whitelisted NFT contract whose tokens are transferred out of the treasury
+    pub nft_contract: String,
+    /// IDs of the tokens to transfer, all of which have to be held by the treasury
+    pub token_ids: Vec<String>,
+    pub recipient: String,
+    /// If set, the tokens are sent to the recipient contract with this message, using SendNft
+    pub msg: Option<Binary>,
+}
+
 #[cw_serde]
 pub struct UpdateAssetWhitelistProposalActionMsg {
     pub remote_treasury_target: Option<RemoteTreasuryTarget>,
@@ -227,20 +600,66 @@ pub struct UpdateNftWhitelistProposalActionMsg {
 #[cw_serde]
 pub struct ExecuteMsgsMsg {
     pub action_type: String,
-    pub msgs: Vec<String>,
+    pub msgs: Vec<ProposalCosmosMsg>,
 }
 
 #[cw_serde]
 pub struct ExecuteTreasuryMsgsMsg {
     pub action_type: String,
-    pub msgs: Vec<String>,
+    pub msgs: Vec<ProposalCosmosMsg>,
     pub remote_treasury_target: Option<RemoteTreasuryTarget>,
 }
 
 #[cw_serde]
 pub struct ExecuteEnterpriseMsgsMsg {
     pub action_type: String,
-    pub msgs: Vec<String>,
+    pub msgs: Vec<ProposalCosmosMsg>,
+}
+
+/// A Cosmos message executed by a proposal.
+/// Can be given either as a structured message, or as its JSON string.
+#[cw_serde]
+#[serde(untagged)]
+pub enum ProposalCosmosMsg {
+    Structured(CosmosMsg),
+    Json(String),
+}
+
+impl ProposalCosmosMsg {
+    /// Decodes the message, parsing it if it was given as a JSON string.
+    pub fn decode(&self) -> GovernanceControllerResult<CosmosMsg> {
+        match self {
+            ProposalCosmosMsg::Structured(msg) => Ok(msg.clone()),
+            ProposalCosmosMsg::Json(msg) => {
+                serde_json_wasm::from_str::<CosmosMsg>(msg).map_err(|_| InvalidCosmosMessage)
+            }
+        }
+    }
+
+    /// Encodes the message as a JSON string, which is what the treasury and the enterprise
+    /// contract expect when executing messages on behalf of the DAO.
+    pub fn to_json_string(&self) -> StdResult<String> {
+        match self {
+            ProposalCosmosMsg::Structured(msg) => {
+                serde_json_wasm::to_string(msg).map_err(|e| StdError::generic_err(e.to_string()))
+            }
+            ProposalCosmosMsg::Json(msg) => Ok(msg.clone()),
+        }
+    }
+
+    /// Returns the structured form of the message, if it can be decoded.
+    pub fn decoded(self) -> Self {
+        match self.decode() {
+            Ok(msg) => ProposalCosmosMsg::Structured(msg),
+            Err(_) => self,
+        }
+    }
+}
+
+impl From<String> for ProposalCosmosMsg {
+    fn from(msg: String) -> Self {
+        ProposalCosmosMsg::Json(msg)
+    }
 }
 
 #[cw_serde]
@@ -254,6 +673,17 @@ pub struct ModifyMultisigMembershipMsg {
 #[cw_serde]
 pub struct DistributeFundsMsg {
     pub funds: Vec<AssetUnchecked>,
+    /// If set, the funds are streamed to the users gradually over this many seconds,
+    /// instead of being distributed all at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_duration: Option<u64>,
+}
+
+#[cw_serde]
+pub struct DistributeFundsTargetedMsg {
+    pub asset: AssetUnchecked,
+    /// How the asset is split between the users, either as exact amounts or as shares
+    pub allocations: RewardAllocations,
 }
 
 #[cw_serde]
@@ -264,7 +694,54 @@ pub struct UpdateMinimumWeightForRewardsMsg {
 #[cw_serde]
 pub struct CastVoteMsg {
     pub proposal_id: ProposalId,
-    pub outcome: VoteOutcome,
+    /// Either a yes/no/abstain/veto outcome, or an index of the chosen option
+    /// for multiple-choice proposals.
+    pub outcome: VoteChoice,
+}
+
+#[cw_serde]
+pub struct CastWeightedVoteMsg {
+    pub proposal_id: ProposalId,
+    /// Outcomes to split the voting power across. Weights have to add up to 1.
+    pub outcomes: Vec<WeightedVoteChoice>,
+}
+
+#[cw_serde]
+pub struct DelegateMsg {
+    /// Address that will vote on the sender's behalf in general proposals,
+    /// unless the sender votes on a proposal directly.
+    pub delegate: String,
+}
+
+#[cw_serde]
+pub struct CancelProposalMsg {
+    pub proposal_id: ProposalId,
+}
+
+/// Amends a proposal during its review period. Fields left as None are not changed.
+#[cw_serde]
+pub struct AmendProposalMsg {
+    pub proposal_id: ProposalId,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub proposal_actions: Option<Vec<ProposalAction>>,
+}
+
+/// A proposal's content as it was before being amended.
+#[cw_serde]
+pub struct ProposalAmendment {
+    /// Sequence number of the amendment within the proposal, starting from 0
+    pub amendment_id: u32,
+    pub amended_at: Timestamp,
+    pub title: String,
+    pub description: String,
+    pub proposal_actions: Vec<ProposalAction>,
+}
+
+#[cw_serde]
+pub struct VetoProposalMsg {
+    /// ID of a passed proposal that is still waiting for its execution delay to pass
+    pub proposal_id: ProposalId,
 }
 
 #[cw_serde]
@@ -272,6 +749,18 @@ pub struct ExecuteProposalMsg {
     pub proposal_id: ProposalId,
 }
 
+#[cw_serde]
+pub struct RetryProposalActionsMsg {
+    pub proposal_id: ProposalId,
+}
+
+#[cw_serde]
+pub struct ExecuteProposalActionMsg {
+    pub proposal_id: ProposalId,
+    /// Index of the action within the proposal's actions
+    pub action_index: u32,
+}
+
 #[cw_serde]
 pub struct ConfigResponse {
     pub enterprise_contract: Addr,
@@ -314,6 +803,10 @@ pub struct ProposalsResponse {
 pub struct ProposalsParams {
     /// Optional proposal status to filter for.
     pub filter: Option<ProposalStatusFilter>,
+    /// Optional metadata tag to filter for.
+    /// Proposals are listed until 'limit' of them with the tag are found, or there are no more.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
     pub start_after: Option<ProposalId>,
     pub limit: Option<u32>,
     // TODO: allow ordering
@@ -338,6 +831,11 @@ pub enum ProposalStatus {
     Passed,
     Rejected,
     Executed,
+    Cancelled,
+    /// Passed, but was not executed within its execution window
+    Expired,
+    /// Passed, but was vetoed by the council before it could be executed
+    Vetoed,
 }
 
 #[cw_serde]
@@ -345,6 +843,7 @@ pub enum ProposalStatusFilter {
     InProgress,
     Passed,
     Rejected,
+    Cancelled,
 }
 
 impl ProposalStatusFilter {
@@ -353,6 +852,7 @@ impl ProposalStatusFilter {
             ProposalStatusFilter::InProgress => status == &ProposalStatus::InProgress,
             ProposalStatusFilter::Passed => status == &ProposalStatus::Passed,
             ProposalStatusFilter::Rejected => status == &ProposalStatus::Rejected,
+            ProposalStatusFilter::Cancelled => status == &ProposalStatus::Cancelled,
         }
     }
 }
@@ -362,6 +862,34 @@ pub struct ProposalStatusParams {
     pub proposal_id: ProposalId,
 }
 
+#[cw_serde]
+pub struct SimulateProposalExecutionParams {
+    pub proposal_id: ProposalId,
+    /// For multiple-choice proposals, the index of the option whose actions to simulate.
+    /// If None, simulates the actions that the proposal would currently execute - for
+    /// multiple-choice proposals those are the winning option's actions, and the query
+    /// fails if the proposal has not passed yet.
+    pub option: Option<u8>,
+}
+
+#[cw_serde]
+pub struct SimulateProposalExecutionResponse {
+    pub proposal_id: ProposalId,
+    /// Whether all the proposal's actions are expected to execute successfully
+    pub success: bool,
+    /// Failure of checks applying to the proposal as a whole, such as council spend budgets
+    pub proposal_error: Option<String>,
+    /// Diagnostics for each of the simulated actions, in order of execution
+    pub actions: Vec<ProposalActionDiagnostic>,
+}
+
+#[cw_serde]
+pub struct ProposalActionDiagnostic {
+    pub action_type: ProposalActionType,
+    /// Reason this action is expected to fail, if any
+    pub error: Option<String>,
+}
+
 #[cw_serde]
 pub struct MemberVoteParams {
     pub member: String,
@@ -386,6 +914,66 @@ pub struct ProposalVotesResponse {
     pub votes: Vec<Vote>,
 }
 
+#[cw_serde]
+pub struct DelegationParams {
+    pub delegator: String,
+}
+
+#[cw_serde]
+pub struct DelegationResponse {
+    pub delegate: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegatorsParams {
+    pub delegate: String,
+    /// Optional pagination data, will return delegators after the given address
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct ProposalAmendmentsParams {
+    pub proposal_id: ProposalId,
+    /// Optional pagination data, will return amendments after the given amendment ID
+    pub start_after: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct ProposalAmendmentsResponse {
+    pub amendments: Vec<ProposalAmendment>,
+}
+
+#[cw_serde]
+pub struct DelegatorsResponse {
+    pub delegators: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegatedVotingPowerParams {
+    pub delegate: String,
+}
+
+#[cw_serde]
+pub struct DelegatedVotingPowerResponse {
+    /// Sum of current weights of all the delegate's delegators.
+    pub delegated_voting_power: Uint128,
+}
+
+#[cw_serde]
+pub struct CouncilSpendBudgetsResponse {
+    pub budgets: Vec<CouncilRemainingBudget>,
+}
+
+#[cw_serde]
+pub struct CouncilRemainingBudget {
+    pub budget: CouncilSpendBudget,
+    /// Amount spent by council proposals within the current rolling period
+    pub spent: Uint128,
+    pub remaining: Uint128,
+}
+
 #[cw_serde]
 pub struct ProposalVotersParams {
     pub proposal_id: ProposalId,
@@ -409,6 +997,18 @@ pub struct Proposal {
     pub started_at: Timestamp,
     pub expires: Expiration,
     pub proposal_actions: Vec<ProposalAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposal_options: Option<Vec<ProposalOption>>,
+    #[serde(default)]
+    pub execution_mode: ProposalExecutionMode,
+    /// Results of each of the proposal's actions, if executed in the partial-failure tolerant mode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub action_results: Vec<ProposalActionResult>,
+    /// Until this time, the proposal can be amended by its proposer, and cannot be voted on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_ends_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProposalMetadata>,
     // TODO: include quorum? difficult because cw3 doesn't support it
     // pub quorum: Decimal,
 }
@@ -1,4 +1,4 @@
-use crate::api::ProposalActionType;
+use crate::api::{ProposalActionType, ProposalDepositOutcome};
 use cosmwasm_std::{OverflowError, StdError, Uint128};
 use cw_utils::ParseReplyError;
 use enterprise_outposts_api::error::EnterpriseOutpostsError;
@@ -29,6 +29,18 @@ pub enum GovernanceControllerError {
     #[error("The user has not signed the DAO's attestation and is not allowed to use most of DAO's functions")]
     RestrictedUser,
 
+    #[error("Cannot delegate voting power to oneself")]
+    SelfDelegation,
+
+    #[error("The user has not delegated their voting power")]
+    NoDelegation,
+
+    #[error("Cannot delegate without having any voting power")]
+    NoVotingPowerToDelegate,
+
+    #[error("The delegate already has the maximum number of delegators, which is {maximum}")]
+    MaximumDelegatorsExceeded { maximum: u32 },
+
     #[error("The DAO does not have a council specified")]
     NoDaoCouncil,
 
@@ -38,9 +50,20 @@ pub enum GovernanceControllerError {
     #[error("Proposal action {action} is not supported in council proposals")]
     UnsupportedCouncilProposalAction { action: ProposalActionType },
 
+    #[error(
+        "Council proposal exceeds the spend budget for {asset}, remaining budget is {remaining}"
+    )]
+    CouncilSpendBudgetExceeded { asset: String, remaining: Uint128 },
+
     #[error("Proposal exceeds maximum amount of proposal actions, which is {maximum}")]
     MaximumProposalActionsExceeded { maximum: u8 },
 
+    #[error("Multiple-choice proposals require between 2 and {maximum} options")]
+    InvalidNumberOfProposalOptions { maximum: u8 },
+
+    #[error("Multiple-choice proposals can only have actions defined within their options")]
+    ProposalActionsOutsideOfOptions,
+
     #[error("Council members must be unique, however {member} was duplicated")]
     DuplicateCouncilMember { member: String },
 
@@ -59,6 +82,12 @@ pub enum GovernanceControllerError {
     #[error("Invalid deposit type")]
     InvalidDepositType,
 
+    #[error("Proposal deposit outcome {outcome} is not supported for {dao_type} DAOs")]
+    UnsupportedProposalDepositOutcome {
+        outcome: ProposalDepositOutcome,
+        dao_type: String,
+    },
+
     #[error("Requiring a minimum deposit for proposals is not allowed for this DAO type")]
     MinimumDepositNotAllowed,
 
@@ -71,6 +100,33 @@ pub enum GovernanceControllerError {
     #[error("The given proposal has already been executed")]
     ProposalAlreadyExecuted,
 
+    #[error("The given proposal has been cancelled")]
+    ProposalCancelled,
+
+    #[error("The proposal was not executed within its execution window")]
+    ProposalExpired,
+
+    #[error("The given proposal is in its review period, and cannot be voted on yet")]
+    ProposalInReview,
+
+    #[error("The given proposal can only be amended during its review period")]
+    ProposalNotInReview,
+
+    #[error("The given proposal has no failed actions to retry")]
+    NoFailedProposalActions,
+
+    #[error("The window for retrying the proposal's failed actions has passed")]
+    ProposalActionsRetryWindowPassed,
+
+    #[error("The given proposal has been vetoed")]
+    ProposalVetoed,
+
+    #[error("Only passed proposals waiting for their execution delay can be vetoed")]
+    ProposalCannotBeVetoed,
+
+    #[error("The proposal can no longer be cancelled")]
+    ProposalCannotBeCancelled,
+
     #[error("Not enough time has passed since the proposal reached its current outcome")]
     ProposalCannotBeExecutedYet,
 
@@ -98,6 +154,21 @@ pub enum GovernanceControllerError {
     #[error("An NFT is present in both add and remove lists")]
     NftPresentInBothAddAndRemove,
 
+    #[error("Token {token_id} of NFT contract {nft_contract} is not held by the treasury")]
+    NftNotHeldByTreasury {
+        nft_contract: String,
+        token_id: String,
+    },
+
+    #[error("NFT contract {nft_contract} is not whitelisted in the treasury")]
+    NftNotWhitelistedInTreasury { nft_contract: String },
+
+    #[error("Insufficient treasury balance of {asset}, available balance is {available}")]
+    InsufficientTreasuryBalance { asset: String, available: Uint128 },
+
+    #[error("Version {version} is not available in the versioning contract")]
+    NoSuchDaoVersion { version: String },
+
     #[error("Error parsing message into Cosmos message")]
     InvalidCosmosMessage,
 
@@ -1,9 +1,13 @@
 use crate::api::{
-    CastVoteMsg, ConfigResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
-    DaoCouncilSpec, ExecuteProposalMsg, GovConfig, GovConfigResponse, MemberVoteParams,
-    MemberVoteResponse, ProposalId, ProposalInfo, ProposalParams, ProposalResponse,
-    ProposalStatusParams, ProposalStatusResponse, ProposalVotesParams, ProposalVotesResponse,
-    ProposalsParams, ProposalsResponse,
+    AmendProposalMsg, CancelProposalMsg, CastVoteMsg, CastWeightedVoteMsg, ConfigResponse,
+    CouncilSpendBudgetsResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
+    DaoCouncilSpec, DelegateMsg, DelegatedVotingPowerParams, DelegatedVotingPowerResponse,
+    DelegationParams, DelegationResponse, DelegatorsParams, DelegatorsResponse,
+    ExecuteProposalActionMsg, ExecuteProposalMsg, GovConfig, GovConfigResponse, MemberVoteParams,
+    MemberVoteResponse, ProposalAmendmentsParams, ProposalAmendmentsResponse, ProposalId,
+    ProposalInfo, ProposalParams, ProposalResponse, ProposalStatusParams, ProposalStatusResponse,
+    ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
+    RetryProposalActionsMsg, SimulateProposalExecutionParams, SimulateProposalExecutionResponse,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw20::Cw20ReceiveMsg;
@@ -27,14 +31,39 @@ pub enum ExecuteMsg {
     CreateProposalWithNftDeposit(CreateProposalWithNftDepositMsg),
     CreateCouncilProposal(CreateProposalMsg),
     CastVote(CastVoteMsg),
+    /// Splits the sender's voting power across multiple outcomes.
+    CastWeightedVote(CastWeightedVoteMsg),
     CastCouncilVote(CastVoteMsg),
+    /// Delegates the sender's voting power in general proposals to another address.
+    /// Replaces any existing delegation of the sender.
+    /// Only members with voting power can delegate, and each delegate can have a limited
+    /// number of delegators.
+    Delegate(DelegateMsg),
+    /// Removes the sender's delegation. Votes already cast on the sender's behalf remain.
+    Undelegate {},
+    /// Ends the proposal's voting and executes it if it passed.
+    /// Passed proposals with an execution delay are queued instead, and have to be executed
+    /// again once the delay passes, unless the council vetoes them in the meantime.
     ExecuteProposal(ExecuteProposalMsg),
+    /// Withdraws an in-progress proposal. Only the proposer can do this, and only until
+    /// the proposal receives votes or until the configured cancellation cutoff passes.
+    CancelProposal(CancelProposalMsg),
+    /// Changes the title, description or actions of a proposal during its review period.
+    /// Only the proposer can do this.
+    AmendProposal(AmendProposalMsg),
+    /// Retries the failed actions of a proposal executed in the partial-failure tolerant mode.
+    /// Can be called by anyone, within the proposal type's execution window counted from
+    /// the proposal's execution, or within a week if there is no execution window.
+    RetryProposalActions(RetryProposalActionsMsg),
     Receive(Cw20ReceiveMsg),
     WeightsChanged(WeightsChangedMsg),
 
     /// Only executable by the contract itself. Not part of the public API.
     ExecuteProposalActions(ExecuteProposalMsg),
 
+    /// Only executable by the contract itself. Not part of the public API.
+    ExecuteProposalAction(ExecuteProposalActionMsg),
+
     /// Only executable by the instantiator of this contract, in the same block as the creation.
     DeployInitialCrossChainTreasuries {},
 }
@@ -64,4 +93,16 @@ pub enum QueryMsg {
     MemberVote(MemberVoteParams),
     #[returns(ProposalVotesResponse)]
     ProposalVotes(ProposalVotesParams),
+    #[returns(DelegationResponse)]
+    Delegation(DelegationParams),
+    #[returns(DelegatorsResponse)]
+    Delegators(DelegatorsParams),
+    #[returns(DelegatedVotingPowerResponse)]
+    DelegatedVotingPower(DelegatedVotingPowerParams),
+    #[returns(CouncilSpendBudgetsResponse)]
+    CouncilSpendBudgets {},
+    #[returns(SimulateProposalExecutionResponse)]
+    SimulateProposalExecution(SimulateProposalExecutionParams),
+    #[returns(ProposalAmendmentsResponse)]
+    ProposalAmendments(ProposalAmendmentsParams),
 }
@@ -1,6 +1,6 @@
 use crate::api::{ProposalId, ProposalType};
 use cosmwasm_std::{Response, Uint128};
-use poll_engine_api::api::{PollId, VoteOutcome};
+use poll_engine_api::api::{PollId, VoteChoice};
 
 pub fn instantiate_response() -> Response {
     Response::new().add_attribute("action", "instantiate")
@@ -26,7 +26,7 @@ pub fn execute_cast_vote_response(
     dao_address: String,
     proposal_id: ProposalId,
     voter: String,
-    outcome: VoteOutcome,
+    outcome: VoteChoice,
     amount: Uint128,
 ) -> Response {
     Response::new()
@@ -38,11 +38,27 @@ pub fn execute_cast_vote_response(
         .add_attribute("amount", amount.to_string())
 }
 
+pub fn execute_cast_weighted_vote_response(
+    dao_address: String,
+    proposal_id: ProposalId,
+    voter: String,
+    outcomes: String,
+    amount: Uint128,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "cast_weighted_vote")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", voter)
+        .add_attribute("outcomes", outcomes)
+        .add_attribute("amount", amount.to_string())
+}
+
 pub fn execute_cast_council_vote_response(
     dao_address: String,
     proposal_id: ProposalId,
     voter: String,
-    outcome: VoteOutcome,
+    outcome: VoteChoice,
     amount: Uint128,
 ) -> Response {
     Response::new()
@@ -54,6 +70,54 @@ pub fn execute_cast_council_vote_response(
         .add_attribute("amount", amount.to_string())
 }
 
+pub fn execute_cancel_proposal_response(
+    dao_address: String,
+    proposal_id: ProposalId,
+    proposal_type: ProposalType,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "cancel_proposal")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposal_type", proposal_type.to_string())
+}
+
+pub fn execute_amend_proposal_response(
+    dao_address: String,
+    proposal_id: ProposalId,
+    amendment_id: u32,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "amend_proposal")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("amendment_id", amendment_id.to_string())
+}
+
+pub fn execute_delegate_response(
+    dao_address: String,
+    delegator: String,
+    delegate: String,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("delegator", delegator)
+        .add_attribute("delegate", delegate)
+}
+
+pub fn execute_undelegate_response(
+    dao_address: String,
+    delegator: String,
+    delegate: String,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("delegator", delegator)
+        .add_attribute("delegate", delegate)
+}
+
 pub fn execute_execute_proposal_response(
     dao_address: String,
     proposal_id: ProposalId,
@@ -66,6 +130,18 @@ pub fn execute_execute_proposal_response(
         .add_attribute("proposal_type", proposal_type.to_string())
 }
 
+pub fn execute_retry_proposal_actions_response(
+    dao_address: String,
+    proposal_id: ProposalId,
+    retried_actions: usize,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "retry_proposal_actions")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("retried_actions", retried_actions.to_string())
+}
+
 pub fn execute_weights_changed_response() -> Response {
     Response::new().add_attribute("action", "weights_changed")
 }
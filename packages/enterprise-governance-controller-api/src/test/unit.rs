@@ -1,5 +1,13 @@
-use crate::api::CreateProposalMsg;
-use cosmwasm_std::{from_json, to_json_string, StdResult};
+use crate::api::{
+    CastVoteMsg, CreateProposalMsg, DaoCouncilSpec, DistributeFundsTargetedMsg, ExecuteMsgsMsg,
+    ExecutionTimelock, GovConfig, ProposalAction, ProposalActionResult, ProposalActionType,
+    ProposalCosmosMsg, ProposalDepositOutcome, ProposalDepositOutcomes, ProposalExecutionMode,
+    ProposalInfo, ProposalMetadata, ProposalsParams, SimulateProposalExecutionParams,
+};
+use crate::msg::QueryMsg;
+use cosmwasm_std::{coins, from_json, to_json_string, BankMsg, CosmosMsg, StdResult, Uint128};
+use funds_distributor_api::api::{RewardAllocations, UserAmount};
+use poll_engine_api::api::{VoteChoice, VoteOutcome};
 
 #[test]
 fn stuff() -> StdResult<()> {
@@ -8,6 +16,9 @@ fn stuff() -> StdResult<()> {
         description: Some("stuff".to_string()),
         proposal_actions: vec![],
         deposit_owner: Some("stranger".to_string()),
+        options: None,
+        execution_mode: None,
+        metadata: None,
     })?;
 
     println!("{}", json);
@@ -21,8 +32,237 @@ fn stuff() -> StdResult<()> {
             description: Some("stuff".to_string()),
             proposal_actions: vec![],
             deposit_owner: Some("stranger".to_string()),
+            options: None,
+            execution_mode: None,
+            metadata: None,
         }
     );
 
     Ok(())
 }
+
+#[test]
+fn proposal_metadata_tags_default_to_empty() -> StdResult<()> {
+    let structure: CreateProposalMsg = from_json(
+        r#"{"title":"123","description":null,"proposal_actions":[],"metadata":{"discussion_url":"https://forum.example/t/1","content_hash":null}}"#,
+    )?;
+
+    assert_eq!(
+        structure.metadata,
+        Some(ProposalMetadata {
+            discussion_url: Some("https://forum.example/t/1".to_string()),
+            content_hash: None,
+            tags: vec![],
+        })
+    );
+
+    let params = ProposalsParams {
+        filter: None,
+        tag: None,
+        start_after: None,
+        limit: None,
+    };
+    assert_eq!(
+        to_json_string(&params)?,
+        r#"{"filter":null,"start_after":null,"limit":null}"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cast_vote_msg_accepts_outcomes_and_multiple_choice_options() -> StdResult<()> {
+    let structure: CastVoteMsg = from_json(r#"{"proposal_id":1,"outcome":"yes"}"#)?;
+    assert_eq!(structure.outcome, VoteChoice::Outcome(VoteOutcome::Yes));
+
+    let structure: CastVoteMsg = from_json(r#"{"proposal_id":1,"outcome":3}"#)?;
+    assert_eq!(structure.outcome, VoteChoice::MultipleChoiceOption(3));
+
+    Ok(())
+}
+
+#[test]
+fn gov_config_without_deposit_outcomes_keeps_previous_deposit_behavior() -> StdResult<()> {
+    let gov_config: GovConfig = from_json(
+        r#"{"quorum":"0.3","threshold":"0.5","veto_threshold":null,"vote_duration":100,"minimum_deposit":null,"allow_early_proposal_execution":false}"#,
+    )?;
+
+    assert_eq!(
+        gov_config.proposal_deposit_outcomes,
+        ProposalDepositOutcomes {
+            passed: ProposalDepositOutcome::Refund,
+            rejected: ProposalDepositOutcome::Refund,
+            vetoed: ProposalDepositOutcome::SendToTreasury,
+            quorum_not_reached: ProposalDepositOutcome::SendToTreasury,
+            cancelled: ProposalDepositOutcome::Refund,
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gov_config_without_execution_timelocks_executes_immediately() -> StdResult<()> {
+    let gov_config: GovConfig = from_json(
+        r#"{"quorum":"0.3","threshold":"0.5","veto_threshold":null,"vote_duration":100,"minimum_deposit":null,"allow_early_proposal_execution":false}"#,
+    )?;
+
+    assert_eq!(
+        gov_config.general_execution_timelock,
+        ExecutionTimelock {
+            execution_delay: 0,
+            execution_window: None,
+        }
+    );
+    assert_eq!(
+        gov_config.council_execution_timelock,
+        ExecutionTimelock::default()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn longest_vote_duration_includes_action_type_overrides() -> StdResult<()> {
+    let gov_config: GovConfig = from_json(
+        r#"{"quorum":"0.3","threshold":"0.5","veto_threshold":null,"vote_duration":100,"minimum_deposit":null,"allow_early_proposal_execution":false,"proposal_cancellation_cutoff":null,"action_type_gov_params":[{"action_type":"upgrade_dao","quorum":"0.4","threshold":"0.67","veto_threshold":null,"vote_duration":300},{"action_type":"update_metadata","quorum":"0.1","threshold":null,"veto_threshold":null,"vote_duration":50}]}"#,
+    )?;
+
+    assert_eq!(
+        gov_config.action_type_gov_params[0].action_type,
+        ProposalActionType::UpgradeDao
+    );
+    assert_eq!(gov_config.longest_vote_duration(), 300);
+
+    Ok(())
+}
+
+#[test]
+fn longest_vote_duration_includes_review_period() -> StdResult<()> {
+    let gov_config: GovConfig = from_json(
+        r#"{"quorum":"0.3","threshold":"0.5","veto_threshold":null,"vote_duration":100,"minimum_deposit":null,"allow_early_proposal_execution":false,"proposal_cancellation_cutoff":null,"review_period":40}"#,
+    )?;
+
+    assert_eq!(gov_config.review_period, Some(40));
+    assert_eq!(gov_config.longest_vote_duration(), 140);
+
+    Ok(())
+}
+
+#[test]
+fn dao_council_spec_without_spend_budgets_omits_them() -> StdResult<()> {
+    let json = r#"{"members":["member"],"quorum":"0.3","threshold":"0.5","allowed_proposal_action_types":null}"#;
+
+    let spec: DaoCouncilSpec = from_json(json)?;
+    assert_eq!(spec.spend_budgets, None);
+
+    assert_eq!(to_json_string(&spec)?, json);
+
+    Ok(())
+}
+
+#[test]
+fn execute_msgs_accept_json_strings_and_structured_msgs() -> StdResult<()> {
+    let msg: ExecuteMsgsMsg = from_json(
+        r#"{"action_type":"send","msgs":["{\"bank\":{\"send\":{\"to_address\":\"recipient\",\"amount\":[{\"denom\":\"uluna\",\"amount\":\"5\"}]}}}",{"bank":{"send":{"to_address":"recipient","amount":[{"denom":"uluna","amount":"5"}]}}}]}"#,
+    )?;
+
+    let expected = CosmosMsg::Bank(BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: coins(5, "uluna"),
+    });
+
+    assert!(matches!(msg.msgs[0], ProposalCosmosMsg::Json(_)));
+    assert_eq!(msg.msgs[1], ProposalCosmosMsg::Structured(expected.clone()));
+
+    for proposal_msg in msg.msgs {
+        assert_eq!(proposal_msg.decode().unwrap(), expected);
+        assert_eq!(
+            proposal_msg.decoded(),
+            ProposalCosmosMsg::Structured(expected.clone())
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn simulate_proposal_execution_query_without_option_simulates_current_actions() -> StdResult<()> {
+    let msg: QueryMsg = from_json(r#"{"simulate_proposal_execution":{"proposal_id":3}}"#)?;
+
+    assert_eq!(
+        msg,
+        QueryMsg::SimulateProposalExecution(SimulateProposalExecutionParams {
+            proposal_id: 3,
+            option: None,
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proposal_info_without_execution_mode_executes_atomically() -> StdResult<()> {
+    let proposal_info: ProposalInfo = from_json(
+        r#"{"proposal_type":"general","executed_at":null,"earliest_execution":null,"proposal_deposit":null,"proposal_actions":[],"voting_power_snapshot":null,"proposal_options":null}"#,
+    )?;
+
+    assert_eq!(proposal_info.execution_mode, ProposalExecutionMode::Atomic);
+    assert!(proposal_info.action_results.is_empty());
+    assert!(proposal_info.failed_action_indices().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn failed_action_indices_only_include_failed_actions() -> StdResult<()> {
+    let proposal_info: ProposalInfo = from_json(
+        r#"{"proposal_type":"general","executed_at":null,"earliest_execution":null,"proposal_deposit":null,"proposal_actions":[],"voting_power_snapshot":null,"proposal_options":null,"execution_mode":"partial_failure_tolerant","action_results":["succeeded",{"failed":{"error":"out of funds"}},"pending",{"failed":{"error":"unauthorized"}}]}"#,
+    )?;
+
+    assert_eq!(
+        proposal_info.action_results[1],
+        ProposalActionResult::Failed {
+            error: "out of funds".to_string()
+        }
+    );
+    assert_eq!(proposal_info.failed_action_indices(), vec![1, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn distribute_funds_targeted_shares_give_rounding_remainder_to_first_user() -> StdResult<()> {
+    let action: ProposalAction = from_json(
+        r#"{"distribute_funds_targeted":{"asset":{"info":{"native":"uluna"},"amount":"11"},"allocations":{"shares":[{"user":"user1","share":"0.3"},{"user":"user2","share":"0.3"},{"user":"user3","share":"0.4"}]}}}"#,
+    )?;
+
+    let ProposalAction::DistributeFundsTargeted(DistributeFundsTargetedMsg { asset, allocations }) =
+        action
+    else {
+        panic!("expected a targeted distribution");
+    };
+    assert!(matches!(allocations, RewardAllocations::Shares(_)));
+
+    let amounts = allocations.to_amounts(asset.amount).unwrap();
+
+    assert_eq!(
+        amounts,
+        vec![
+            UserAmount {
+                user: "user1".to_string(),
+                amount: Uint128::new(4),
+            },
+            UserAmount {
+                user: "user2".to_string(),
+                amount: Uint128::new(3),
+            },
+            UserAmount {
+                user: "user3".to_string(),
+                amount: Uint128::new(4),
+            },
+        ]
+    );
+
+    Ok(())
+}
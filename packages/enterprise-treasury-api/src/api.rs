@@ -1,6 +1,10 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_asset::{AssetInfo, AssetInfoUnchecked, AssetUnchecked};
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked, AssetUnchecked};
+use funds_distributor_api::api::RewardAllocations;
+
+pub type StreamId = u64;
+pub type PaymentScheduleId = u64;
 
 #[cw_serde]
 pub struct SetAdminMsg {
@@ -29,10 +33,80 @@ pub struct SpendMsg {
     pub assets: Vec<AssetUnchecked>,
 }
 
+#[cw_serde]
+pub struct TransferNftsMsg {
+    /// Whitelisted NFT contract whose tokens are transferred
+    pub nft_contract: String,
+    /// IDs of the tokens to transfer, all of which have to be held by the treasury
+    pub token_ids: Vec<String>,
+    pub recipient: String,
+    /// If set, the tokens are sent to the recipient contract with this message, using SendNft
+    pub msg: Option<Binary>,
+}
+
 #[cw_serde]
 pub struct DistributeFundsMsg {
     pub funds: Vec<AssetUnchecked>,
     pub funds_distributor_contract: String,
+    /// If set, the funds are streamed to the users gradually over this many seconds,
+    /// instead of being distributed all at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_duration: Option<u64>,
+}
+
+#[cw_serde]
+pub struct DistributeFundsTargetedMsg {
+    pub asset: AssetUnchecked,
+    /// How the asset is split between the users
+    pub allocations: RewardAllocations,
+    pub funds_distributor_contract: String,
+}
+
+#[cw_serde]
+pub struct CreateStreamMsg {
+    pub recipient: String,
+    /// Total amount of the asset to be streamed. Locked in the treasury until withdrawn or cancelled.
+    pub asset: AssetUnchecked,
+    /// Moment from which the asset starts vesting. If None, defaults to the stream's creation.
+    pub start_time: Option<Timestamp>,
+    /// Duration, in seconds, over which the asset vests linearly
+    pub duration: u64,
+    /// Duration, in seconds, after the start during which nothing can be withdrawn.
+    /// Once the cliff passes, everything vested so far becomes withdrawable.
+    pub cliff: Option<u64>,
+}
+
+#[cw_serde]
+pub struct WithdrawFromStreamMsg {
+    pub stream_id: StreamId,
+}
+
+#[cw_serde]
+pub struct CancelStreamMsg {
+    pub stream_id: StreamId,
+}
+
+#[cw_serde]
+pub struct CreatePaymentScheduleMsg {
+    pub recipient: String,
+    /// Assets paid out in each payment. Everything the schedule will pay out is locked
+    /// in the treasury until paid out or cancelled.
+    pub assets: Vec<AssetUnchecked>,
+    /// Moment from which the intervals are counted. If None, defaults to the schedule's creation.
+    pub start_time: Option<Timestamp>,
+    /// Time, in seconds, between payments. The first payment is due one interval after the start.
+    pub interval: u64,
+    pub number_of_payments: u32,
+}
+
+#[cw_serde]
+pub struct CancelPaymentScheduleMsg {
+    pub schedule_id: PaymentScheduleId,
+}
+
+#[cw_serde]
+pub struct ClaimScheduledPaymentsMsg {
+    pub schedule_id: PaymentScheduleId,
 }
 
 #[cw_serde]
@@ -53,6 +127,40 @@ pub struct NftWhitelistParams {
     pub limit: Option<u32>,
 }
 
+#[cw_serde]
+pub struct BalancesParams {
+    /// Whitelisted asset after which to continue listing asset balances
+    pub asset_start_after: Option<AssetInfoUnchecked>,
+    /// Maximum number of assets to return
+    pub asset_limit: Option<u32>,
+    /// Whitelisted NFT contract after which to continue listing NFT holdings
+    pub nft_start_after: Option<String>,
+    /// Maximum number of NFT contracts to return
+    pub nft_limit: Option<u32>,
+    /// Maximum number of token IDs to return for each NFT contract
+    pub token_ids_limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct LockedAmountParams {
+    pub asset: AssetInfoUnchecked,
+}
+
+#[cw_serde]
+pub struct PaymentSchedulesParams {
+    /// If set, only returns schedules paying out to the given recipient
+    pub recipient: Option<String>,
+    pub start_after: Option<PaymentScheduleId>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct StreamsParams {
+    pub recipient: String,
+    pub start_after: Option<StreamId>,
+    pub limit: Option<u32>,
+}
+
 ////// Responses
 
 #[cw_serde]
@@ -70,6 +178,81 @@ pub struct NftWhitelistResponse {
     pub nfts: Vec<Addr>,
 }
 
+#[cw_serde]
+pub struct BalancesResponse {
+    /// Balances of whitelisted assets
+    pub assets: Vec<Asset>,
+    /// Token IDs held of each whitelisted NFT contract
+    pub nfts: Vec<NftBalance>,
+}
+
+#[cw_serde]
+pub struct NftBalance {
+    pub nft_contract: Addr,
+    pub token_ids: Vec<String>,
+    /// Set if more token IDs may be held than were returned.
+    /// The rest can be listed by querying the NFT contract's tokens, starting after this one.
+    pub token_ids_start_after: Option<String>,
+}
+
+#[cw_serde]
+pub struct Stream {
+    pub id: StreamId,
+    pub recipient: Addr,
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+    pub withdrawn: Uint128,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub cliff_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct StreamResponse {
+    pub stream: Stream,
+    /// Amount vested so far, including what was already withdrawn
+    pub vested: Uint128,
+    /// Amount the recipient can withdraw right now
+    pub withdrawable: Uint128,
+}
+
+#[cw_serde]
+pub struct StreamsResponse {
+    pub streams: Vec<StreamResponse>,
+}
+
+#[cw_serde]
+pub struct PaymentSchedule {
+    pub id: PaymentScheduleId,
+    pub recipient: Addr,
+    pub assets: Vec<Asset>,
+    pub start_time: Timestamp,
+    pub interval: u64,
+    pub number_of_payments: u32,
+    pub payments_made: u32,
+}
+
+#[cw_serde]
+pub struct PaymentScheduleResponse {
+    pub schedule: PaymentSchedule,
+    /// Number of payments that are due and can be claimed right now
+    pub claimable_payments: u32,
+    /// When the next payment that is not yet due becomes claimable, if there are any left
+    pub next_payment_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct LockedAmountResponse {
+    pub asset: AssetInfo,
+    /// Amount of the asset locked in streams and payment schedules
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct PaymentSchedulesResponse {
+    pub schedules: Vec<PaymentScheduleResponse>,
+}
+
 #[cw_serde]
 pub struct HasIncompleteV2MigrationResponse {
     pub has_incomplete_migration: bool,
@@ -1,5 +1,5 @@
 use crate::error::EnterpriseTreasuryError::Std;
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 pub type EnterpriseTreasuryResult<T> = Result<T, EnterpriseTreasuryError>;
@@ -17,6 +17,39 @@ pub enum EnterpriseTreasuryError {
 
     #[error("Cannot perform that operation in the current migration stage")]
     InvalidMigrationStage,
+
+    #[error("No stream with the given ID exists")]
+    NoSuchStream,
+
+    #[error("Stream duration must be greater than zero, and its cliff cannot be longer than it")]
+    InvalidStreamDuration,
+
+    #[error("Insufficient unlocked balance of {asset}, {available} available")]
+    InsufficientUnlockedBalance { asset: String, available: Uint128 },
+
+    #[error("Cannot spend {asset} locked in streams or payment schedules")]
+    LockedBalanceSpent { asset: String },
+
+    #[error("Nothing to withdraw from the stream")]
+    NothingToWithdraw,
+
+    #[error("No payment schedule with the given ID exists")]
+    NoSuchPaymentSchedule,
+
+    #[error("Payment schedules must have a non-zero interval, number of payments, and assets")]
+    InvalidPaymentSchedule,
+
+    #[error("No scheduled payments are due yet")]
+    NoPaymentsDue,
+
+    #[error("NFT contract {nft_contract} is not whitelisted")]
+    NftNotWhitelisted { nft_contract: String },
+
+    #[error("Token {token_id} of NFT contract {nft_contract} is not held by the treasury")]
+    NftNotOwned {
+        nft_contract: String,
+        token_id: String,
+    },
 }
 
 impl From<OverflowError> for EnterpriseTreasuryError {
@@ -1,8 +1,12 @@
 use crate::api::{
-    AssetWhitelistParams, AssetWhitelistResponse, ConfigResponse, DistributeFundsMsg,
+    AssetWhitelistParams, AssetWhitelistResponse, BalancesParams, BalancesResponse,
+    CancelPaymentScheduleMsg, CancelStreamMsg, ClaimScheduledPaymentsMsg, ConfigResponse,
+    CreatePaymentScheduleMsg, CreateStreamMsg, DistributeFundsMsg, DistributeFundsTargetedMsg,
     ExecuteCosmosMsgsMsg, HasIncompleteV2MigrationResponse, HasUnmovedStakesOrClaimsResponse,
-    NftWhitelistParams, NftWhitelistResponse, SetAdminMsg, SpendMsg, UpdateAssetWhitelistMsg,
-    UpdateNftWhitelistMsg,
+    LockedAmountParams, LockedAmountResponse, NftWhitelistParams, NftWhitelistResponse,
+    PaymentSchedulesParams, PaymentSchedulesResponse, SetAdminMsg, SpendMsg, StreamsParams,
+    StreamsResponse, TransferNftsMsg, UpdateAssetWhitelistMsg, UpdateNftWhitelistMsg,
+    WithdrawFromStreamMsg,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_asset::AssetInfoUnchecked;
@@ -23,8 +27,22 @@ pub enum ExecuteMsg {
     UpdateAssetWhitelist(UpdateAssetWhitelistMsg),
     UpdateNftWhitelist(UpdateNftWhitelistMsg),
     Spend(SpendMsg),
+    TransferNfts(TransferNftsMsg),
     DistributeFunds(DistributeFundsMsg),
+    /// Distributes funds to the given users through the funds distributor.
+    DistributeFundsTargeted(DistributeFundsTargetedMsg),
+    /// Executes the given Cosmos messages. Fails if they spend any funds locked in streams.
     ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg),
+    CreateStream(CreateStreamMsg),
+    CancelStream(CancelStreamMsg),
+    /// Sends the vested, not yet withdrawn part of a stream to its recipient.
+    /// Can be called by anyone.
+    WithdrawFromStream(WithdrawFromStreamMsg),
+    CreatePaymentSchedule(CreatePaymentScheduleMsg),
+    CancelPaymentSchedule(CancelPaymentScheduleMsg),
+    /// Pays out all the schedule's payments that are due.
+    /// Can be called by anyone.
+    ClaimScheduledPayments(ClaimScheduledPaymentsMsg),
 
     /// To be called only when there is an unfinished migration from pre-1.0.0 Enterprise
     PerformNextMigrationStep {
@@ -41,6 +59,19 @@ pub enum QueryMsg {
     AssetWhitelist(AssetWhitelistParams),
     #[returns(NftWhitelistResponse)]
     NftWhitelist(NftWhitelistParams),
+    /// Treasury's holdings of whitelisted assets and NFTs.
+    #[returns(BalancesResponse)]
+    Balances(BalancesParams),
+    /// Active streams of the given recipient.
+    #[returns(StreamsResponse)]
+    Streams(StreamsParams),
+    /// Active payment schedules, along with their upcoming payments.
+    #[returns(PaymentSchedulesResponse)]
+    PaymentSchedules(PaymentSchedulesParams),
+    /// Amount of an asset locked in streams and payment schedules, which cannot be spent
+    /// until paid out or cancelled.
+    #[returns(LockedAmountResponse)]
+    LockedAmount(LockedAmountParams),
 
     /// Not part of this contract's API, but kept as a failsafe when performing migration
     /// from the previous version.
@@ -1,4 +1,4 @@
-use cosmwasm_std::Response;
+use cosmwasm_std::{Response, Uint128};
 
 pub fn instantiate_response(admin: String) -> Response {
     Response::new()
@@ -24,10 +24,63 @@ pub fn execute_spend_response() -> Response {
     Response::new().add_attribute("action", "spend")
 }
 
+pub fn execute_transfer_nfts_response(nft_contract: String, recipient: String) -> Response {
+    Response::new()
+        .add_attribute("action", "transfer_nfts")
+        .add_attribute("nft_contract", nft_contract)
+        .add_attribute("recipient", recipient)
+}
+
 pub fn execute_distribute_funds_response() -> Response {
     Response::new().add_attribute("action", "distribute_funds")
 }
 
+pub fn execute_distribute_funds_targeted_response(asset: String) -> Response {
+    Response::new()
+        .add_attribute("action", "distribute_funds_targeted")
+        .add_attribute("asset", asset)
+}
+
+pub fn execute_create_stream_response(stream_id: u64, recipient: String) -> Response {
+    Response::new()
+        .add_attribute("action", "create_stream")
+        .add_attribute("stream_id", stream_id.to_string())
+        .add_attribute("recipient", recipient)
+}
+
+pub fn execute_cancel_stream_response(stream_id: u64) -> Response {
+    Response::new()
+        .add_attribute("action", "cancel_stream")
+        .add_attribute("stream_id", stream_id.to_string())
+}
+
+pub fn execute_withdraw_from_stream_response(stream_id: u64, amount: Uint128) -> Response {
+    Response::new()
+        .add_attribute("action", "withdraw_from_stream")
+        .add_attribute("stream_id", stream_id.to_string())
+        .add_attribute("amount", amount.to_string())
+}
+
+pub fn execute_create_payment_schedule_response(schedule_id: u64, recipient: String) -> Response {
+    Response::new()
+        .add_attribute("action", "create_payment_schedule")
+        .add_attribute("schedule_id", schedule_id.to_string())
+        .add_attribute("recipient", recipient)
+}
+
+pub fn execute_cancel_payment_schedule_response(schedule_id: u64) -> Response {
+    Response::new()
+        .add_attribute("action", "cancel_payment_schedule")
+        .add_attribute("schedule_id", schedule_id.to_string())
+}
+
+pub fn execute_claim_scheduled_payments_response(schedule_id: u64, payments: u32) -> Response {
+    Response::new()
+        .add_attribute("action", "claim_scheduled_payments")
+        .add_attribute("schedule_id", schedule_id.to_string())
+        .add_attribute("payments", payments.to_string())
+}
+
 pub fn execute_execute_cosmos_msgs_response() -> Response {
     Response::new().add_attribute("action", "execute_cosmos_msgs")
 }
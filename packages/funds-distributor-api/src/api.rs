@@ -1,5 +1,9 @@
+use crate::error::DistributorError::{
+    AllocatedAmountMismatch, EmptyAllocations, InvalidAllocationShares,
+};
+use crate::error::DistributorResult;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Fraction, Timestamp, Uint128};
 
 #[cw_serde]
 pub struct UpdateUserWeightsMsg {
@@ -26,6 +30,124 @@ pub struct ClaimRewardsMsg {
     pub native_denoms: Vec<String>,
     /// CW20 asset rewards to be claimed, should be addresses of CW20 tokens
     pub cw20_assets: Vec<String>,
+    /// CW1155 asset rewards to be claimed
+    #[serde(default)]
+    pub cw1155_assets: Vec<Cw1155Asset>,
+    /// Address to send the rewards to. If None, rewards are sent to the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+    /// If true, rewards in the DAO's membership asset (token or denom DAOs only)
+    /// are staked on behalf of the recipient instead of being sent to them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restake: Option<bool>,
+}
+
+#[cw_serde]
+pub struct ClaimAllRewardsMsg {
+    pub user: String,
+    /// Address to send the rewards to. If None, rewards are sent to the user.
+    pub recipient: Option<String>,
+    /// If true, rewards in the DAO's membership asset (token or denom DAOs only)
+    /// are staked on behalf of the recipient instead of being sent to them.
+    pub restake: Option<bool>,
+    /// Assets are claimed in pages, in the same order as they are listed in AllUserRewards.
+    /// Claims rewards in the assets after this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_after: Option<RewardAsset>,
+    /// Maximum number of assets to claim rewards in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct StreamNativeMsg {
+    /// Duration in seconds over which the funds are gradually distributed
+    pub duration: u64,
+}
+
+#[cw_serde]
+pub struct DistributeTargetedMsg {
+    pub allocations: RewardAllocations,
+}
+
+/// Specifies how a targeted distribution is split between the users.
+/// Users receive their allocations regardless of their weights.
+#[cw_serde]
+pub enum RewardAllocations {
+    /// Exact amounts each user receives, have to add up to the distributed amount
+    Amounts(Vec<UserAmount>),
+    /// Share of the distributed amount each user receives, shares have to add up to 1.
+    /// Any remainder left from rounding goes to the first user.
+    Shares(Vec<UserShare>),
+}
+
+impl RewardAllocations {
+    /// Resolves the allocations into amounts each user receives out of the distributed amount,
+    /// failing if the allocations do not add up to it.
+    pub fn to_amounts(&self, distributed: Uint128) -> DistributorResult<Vec<UserAmount>> {
+        let amounts = match self {
+            RewardAllocations::Amounts(amounts) => amounts.clone(),
+            RewardAllocations::Shares(shares) => {
+                let total_shares = shares.iter().try_fold(Decimal::zero(), |total, share| {
+                    total.checked_add(share.share)
+                })?;
+
+                if !shares.is_empty() && total_shares != Decimal::one() {
+                    return Err(InvalidAllocationShares);
+                }
+
+                let mut amounts = shares
+                    .iter()
+                    .map(|share| -> DistributorResult<UserAmount> {
+                        Ok(UserAmount {
+                            user: share.user.clone(),
+                            amount: distributed.checked_multiply_ratio(
+                                share.share.numerator(),
+                                share.share.denominator(),
+                            )?,
+                        })
+                    })
+                    .collect::<DistributorResult<Vec<UserAmount>>>()?;
+
+                let allocated = amounts.iter().map(|amount| amount.amount).sum::<Uint128>();
+
+                if let Some(first) = amounts.first_mut() {
+                    first.amount += distributed.checked_sub(allocated)?;
+                }
+
+                amounts
+            }
+        };
+
+        if amounts.is_empty() {
+            return Err(EmptyAllocations);
+        }
+
+        let allocated = amounts.iter().try_fold(Uint128::zero(), |total, amount| {
+            total.checked_add(amount.amount)
+        })?;
+
+        if allocated != distributed {
+            return Err(AllocatedAmountMismatch {
+                allocated,
+                distributed,
+            });
+        }
+
+        Ok(amounts)
+    }
+}
+
+#[cw_serde]
+pub struct UserAmount {
+    pub user: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct UserShare {
+    pub user: String,
+    pub share: Decimal,
 }
 
 #[cw_serde]
@@ -35,12 +157,119 @@ pub struct UserRewardsParams {
     pub native_denoms: Vec<String>,
     /// Addresses of CW20 tokens to be queried for rewards
     pub cw20_assets: Vec<String>,
+    /// CW1155 tokens to be queried for rewards
+    #[serde(default)]
+    pub cw1155_assets: Vec<Cw1155Asset>,
 }
 
 #[cw_serde]
 pub struct UserRewardsResponse {
     pub native_rewards: Vec<NativeReward>,
     pub cw20_rewards: Vec<Cw20Reward>,
+    pub cw1155_rewards: Vec<Cw1155Reward>,
+}
+
+#[cw_serde]
+pub struct AllUserRewardsParams {
+    pub user: String,
+    pub start_after: Option<RewardAsset>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct AllUserRewardsResponse {
+    /// User's rewards in each asset ever distributed, omitting assets with no rewards for the user.
+    /// A page can thus contain less than 'limit' rewards.
+    pub rewards: Vec<UserReward>,
+}
+
+#[cw_serde]
+pub struct UserReward {
+    pub asset: RewardAsset,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct DistributionHistoryParams {
+    /// Optional asset to filter for.
+    pub asset: Option<RewardAsset>,
+    /// Distributions are returned from the newest to the oldest,
+    /// so this will return distributions older than the one with the given ID.
+    pub start_after: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct DistributionHistoryResponse {
+    pub distributions: Vec<DistributionRecord>,
+}
+
+#[cw_serde]
+pub struct DistributionRecord {
+    pub id: u64,
+    pub asset: RewardAsset,
+    pub amount: Uint128,
+    /// Total effective weight of the users at the time of the distribution.
+    /// 0 for targeted distributions, which go to the given users regardless of their weight.
+    pub total_weight: Uint128,
+    pub distributed_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct TrailingRewardsParams {
+    pub asset: RewardAsset,
+    /// Length of the trailing window in seconds, ending at the current block time.
+    /// Distributions are summed up by day, so the window is extended to the start of the day
+    /// it begins in. Can be at most 365 days.
+    pub window: u64,
+}
+
+#[cw_serde]
+pub struct TrailingRewardsResponse {
+    /// Amount of the asset distributed within the window, per unit of user weight.
+    /// Targeted distributions are not included.
+    pub reward_per_unit_weight: Decimal,
+    /// Total amount of the asset distributed within the window
+    pub total_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct RewardStreamsParams {
+    pub start_after: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct RewardStreamsResponse {
+    pub streams: Vec<ActiveRewardStream>,
+}
+
+#[cw_serde]
+pub struct ActiveRewardStream {
+    pub id: u64,
+    pub asset: RewardAsset,
+    /// Total amount being distributed by the stream
+    pub amount: Uint128,
+    /// Amount distributed to the users so far
+    pub released_amount: Uint128,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+#[cw_serde]
+pub enum RewardAsset {
+    Native {
+        denom: String,
+    },
+    Cw20 {
+        /// Address of the CW20 token
+        asset: String,
+    },
+    Cw1155 {
+        /// Address of the CW1155 contract
+        contract: String,
+        token_id: String,
+    },
 }
 
 #[cw_serde]
@@ -60,3 +289,18 @@ pub struct Cw20Reward {
     pub asset: String,
     pub amount: Uint128,
 }
+
+#[cw_serde]
+pub struct Cw1155Asset {
+    /// Address of the CW1155 contract
+    pub contract: String,
+    pub token_id: String,
+}
+
+#[cw_serde]
+pub struct Cw1155Reward {
+    /// Address of the CW1155 contract
+    pub contract: String,
+    pub token_id: String,
+    pub amount: Uint128,
+}
@@ -1,5 +1,5 @@
 use crate::error::DistributorError::Std;
-use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
+use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 pub type DistributorResult<T> = Result<T, DistributorError>;
@@ -25,6 +25,33 @@ pub enum DistributorError {
 
     #[error("Attempting to distribute an asset that is not whitelisted")]
     DistributingNonWhitelistedAsset,
+
+    #[error("Reward stream duration must be greater than zero")]
+    ZeroRewardStreamDuration,
+
+    #[error("Reward stream duration is too long")]
+    RewardStreamDurationTooLong,
+
+    #[error("Trailing rewards window cannot be longer than {maximum} seconds")]
+    TrailingRewardsWindowTooLong { maximum: u64 },
+
+    #[error("Cannot have more than {maximum} active reward streams")]
+    MaximumRewardStreamsExceeded { maximum: u32 },
+
+    #[error("No reward allocations given")]
+    EmptyAllocations,
+
+    #[error("Allocated amounts add up to {allocated}, but {distributed} is being distributed")]
+    AllocatedAmountMismatch {
+        allocated: Uint128,
+        distributed: Uint128,
+    },
+
+    #[error("Reward allocation shares must add up to 1")]
+    InvalidAllocationShares,
+
+    #[error("Targeted distributions must distribute exactly one asset")]
+    InvalidTargetedDistributionFunds,
 }
 
 impl From<OverflowError> for DistributorError {
@@ -1,9 +1,13 @@
 use crate::api::{
-    ClaimRewardsMsg, MinimumEligibleWeightResponse, UpdateMinimumEligibleWeightMsg,
+    AllUserRewardsParams, AllUserRewardsResponse, ClaimAllRewardsMsg, ClaimRewardsMsg,
+    DistributeTargetedMsg, DistributionHistoryParams, DistributionHistoryResponse,
+    MinimumEligibleWeightResponse, RewardStreamsParams, RewardStreamsResponse, StreamNativeMsg,
+    TrailingRewardsParams, TrailingRewardsResponse, UpdateMinimumEligibleWeightMsg,
     UpdateUserWeightsMsg, UserRewardsParams, UserRewardsResponse, UserWeight,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
+use cw1155::Cw1155ReceiveMsg;
 use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
@@ -20,13 +24,48 @@ pub enum ExecuteMsg {
     UpdateUserWeights(UpdateUserWeightsMsg),
     UpdateMinimumEligibleWeight(UpdateMinimumEligibleWeightMsg),
     DistributeNative {},
+    /// Distributes the funds sent gradually over the given duration, instead of all at once.
+    /// Can only be called by the admin or the DAO's treasury.
+    StreamNative(StreamNativeMsg),
+    /// Distributes the funds sent to the given users, instead of to all users by their weights.
+    DistributeNativeTargeted(DistributeTargetedMsg),
     ClaimRewards(ClaimRewardsMsg),
-    Receive(Cw20ReceiveMsg),
+    /// Claims the user's rewards in a page of all the assets ever distributed.
+    ClaimAllRewards(ClaimAllRewardsMsg),
+    Receive(ReceiveMsg),
+}
+
+/// Both CW20 and CW1155 contracts call their receive hooks under `receive`,
+/// so the two are told apart by their fields.
+#[cw_serde]
+#[serde(untagged)]
+pub enum ReceiveMsg {
+    Cw20(Cw20ReceiveMsg),
+    Cw1155(Cw1155ReceiveMsg),
 }
 
 #[cw_serde]
 pub enum Cw20HookMsg {
     Distribute {},
+    /// Distributes the tokens gradually over the given duration in seconds.
+    /// Can only be sent by the admin or the DAO's treasury.
+    Stream {
+        duration: u64,
+    },
+    /// Distributes the tokens to the given users, instead of to all users by their weights.
+    DistributeTargeted(DistributeTargetedMsg),
+}
+
+#[cw_serde]
+pub enum Cw1155HookMsg {
+    Distribute {},
+    /// Distributes the tokens gradually over the given duration in seconds.
+    /// Can only be sent by the admin or the DAO's treasury.
+    Stream {
+        duration: u64,
+    },
+    /// Distributes the tokens to the given users, instead of to all users by their weights.
+    DistributeTargeted(DistributeTargetedMsg),
 }
 
 #[cw_serde]
@@ -34,8 +73,21 @@ pub enum Cw20HookMsg {
 pub enum QueryMsg {
     #[returns(UserRewardsResponse)]
     UserRewards(UserRewardsParams),
+    /// User's rewards in all the assets ever distributed.
+    #[returns(AllUserRewardsResponse)]
+    AllUserRewards(AllUserRewardsParams),
     #[returns(MinimumEligibleWeightResponse)]
     MinimumEligibleWeight {},
+    /// Reward streams that are still being distributed.
+    #[returns(RewardStreamsResponse)]
+    RewardStreams(RewardStreamsParams),
+    /// Log of past distributions, from the newest to the oldest.
+    /// Streamed distributions are logged once, with the total weight at the time they are created.
+    #[returns(DistributionHistoryResponse)]
+    DistributionHistory(DistributionHistoryParams),
+    /// Rewards in an asset distributed within a trailing time window.
+    #[returns(TrailingRewardsResponse)]
+    TrailingRewards(TrailingRewardsParams),
 }
 
 #[cw_serde]
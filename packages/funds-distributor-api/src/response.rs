@@ -26,10 +26,18 @@ pub fn execute_distribute_native_response(total_weight: Uint128) -> Response {
         .add_attribute("total_weight", total_weight.to_string())
 }
 
-pub fn execute_claim_rewards_response(user: String) -> Response {
+pub fn execute_stream_native_response(total_weight: Uint128, duration: u64) -> Response {
+    Response::new()
+        .add_attribute("action", "stream_native")
+        .add_attribute("total_weight", total_weight.to_string())
+        .add_attribute("duration", duration.to_string())
+}
+
+pub fn execute_claim_rewards_response(user: String, recipient: String) -> Response {
     Response::new()
         .add_attribute("action", "claim_rewards")
         .add_attribute("user", user)
+        .add_attribute("recipient", recipient)
 }
 
 pub fn cw20_hook_distribute_cw20_response(
@@ -43,3 +51,55 @@ pub fn cw20_hook_distribute_cw20_response(
         .add_attribute("cw20_asset", cw20_asset)
         .add_attribute("amount_distributed", amount.to_string())
 }
+
+pub fn cw1155_hook_distribute_cw1155_response(
+    total_weight: Uint128,
+    cw1155_asset: String,
+    token_id: String,
+    amount: Uint128,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "distribute_cw1155")
+        .add_attribute("total_weight", total_weight.to_string())
+        .add_attribute("cw1155_asset", cw1155_asset)
+        .add_attribute("token_id", token_id)
+        .add_attribute("amount_distributed", amount.to_string())
+}
+
+pub fn cw20_hook_stream_cw20_response(
+    stream_id: u64,
+    cw20_asset: String,
+    amount: Uint128,
+    duration: u64,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "stream_cw20")
+        .add_attribute("stream_id", stream_id.to_string())
+        .add_attribute("cw20_asset", cw20_asset)
+        .add_attribute("amount_streamed", amount.to_string())
+        .add_attribute("duration", duration.to_string())
+}
+
+pub fn cw1155_hook_stream_cw1155_response(
+    stream_id: u64,
+    cw1155_asset: String,
+    token_id: String,
+    amount: Uint128,
+    duration: u64,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "stream_cw1155")
+        .add_attribute("stream_id", stream_id.to_string())
+        .add_attribute("cw1155_asset", cw1155_asset)
+        .add_attribute("token_id", token_id)
+        .add_attribute("amount_streamed", amount.to_string())
+        .add_attribute("duration", duration.to_string())
+}
+
+pub fn distribute_targeted_response(asset: String, amount: Uint128, users: usize) -> Response {
+    Response::new()
+        .add_attribute("action", "distribute_targeted")
+        .add_attribute("asset", asset)
+        .add_attribute("amount_distributed", amount.to_string())
+        .add_attribute("users", users.to_string())
+}
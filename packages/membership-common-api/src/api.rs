@@ -11,6 +11,11 @@ pub struct TotalWeightCheckpoint {
 #[cw_serde]
 pub struct UserWeightParams {
     pub user: String,
+    /// Denotes the moment at which we're interested in the user's weight.
+    /// None or Expiration::Never are used for user's current weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    // this flag is here to allow querying membership contracts that don't support this field yet
+    pub expiration: Option<Expiration>,
 }
 
 #[cw_serde]
@@ -1,32 +1,143 @@
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
-use cw_storage_plus::Map;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Map, SnapshotMap, Strategy};
 
 pub const MEMBER_WEIGHTS: Map<Addr, Uint128> = Map::new("membership_common__member_weights");
 
+const MEMBER_WEIGHTS_HEIGHT_SNAPSHOT: SnapshotMap<Addr, Uint128> = SnapshotMap::new(
+    "membership_common__member_weights_block_height_snapshot",
+    "membership_common__member_weights_block_height_checkpoints",
+    "membership_common__member_weights_block_height_changelog",
+    Strategy::EveryBlock,
+);
+const MEMBER_WEIGHTS_SECONDS_SNAPSHOT: SnapshotMap<Addr, Uint128> = SnapshotMap::new(
+    "membership_common__member_weights_time_seconds_snapshot",
+    "membership_common__member_weights_time_seconds_checkpoints",
+    "membership_common__member_weights_time_seconds_changelog",
+    Strategy::EveryBlock,
+);
+
 pub fn get_member_weight(storage: &dyn Storage, member: Addr) -> StdResult<Uint128> {
     Ok(MEMBER_WEIGHTS
         .may_load(storage, member)?
         .unwrap_or_default())
 }
 
+pub fn load_member_weight_at_height(
+    storage: &dyn Storage,
+    member: Addr,
+    height: u64,
+) -> StdResult<Uint128> {
+    match MEMBER_WEIGHTS_HEIGHT_SNAPSHOT.may_load_at_height(storage, member.clone(), height)? {
+        Some(weight) => Ok(weight),
+        None => load_weight_missing_from_snapshot(
+            storage,
+            MEMBER_WEIGHTS_HEIGHT_SNAPSHOT
+                .may_load(storage, member.clone())?
+                .is_some(),
+            member,
+        ),
+    }
+}
+
+pub fn load_member_weight_at_time(
+    storage: &dyn Storage,
+    member: Addr,
+    time: Timestamp,
+) -> StdResult<Uint128> {
+    match MEMBER_WEIGHTS_SECONDS_SNAPSHOT.may_load_at_height(
+        storage,
+        member.clone(),
+        time.seconds(),
+    )? {
+        Some(weight) => Ok(weight),
+        None => load_weight_missing_from_snapshot(
+            storage,
+            MEMBER_WEIGHTS_SECONDS_SNAPSHOT
+                .may_load(storage, member.clone())?
+                .is_some(),
+            member,
+        ),
+    }
+}
+
+/// Snapshots only contain members whose weight changed after snapshots were introduced.
+/// Members that were never written to the snapshot have had the same weight ever since,
+/// so their current weight is also their historical weight.
+fn load_weight_missing_from_snapshot(
+    storage: &dyn Storage,
+    is_in_snapshot: bool,
+    member: Addr,
+) -> StdResult<Uint128> {
+    if is_in_snapshot {
+        // member was tracked by the snapshot, but had no weight at the given moment
+        Ok(Uint128::zero())
+    } else {
+        get_member_weight(storage, member)
+    }
+}
+
 pub fn set_member_weight(
     storage: &mut dyn Storage,
     member: Addr,
     amount: Uint128,
+    block: &BlockInfo,
 ) -> StdResult<()> {
+    save_member_weight_snapshots(storage, member.clone(), amount, block)?;
+
     MEMBER_WEIGHTS.save(storage, member, &amount)?;
 
     Ok(())
 }
 
+fn save_member_weight_snapshots(
+    storage: &mut dyn Storage,
+    member: Addr,
+    amount: Uint128,
+    block: &BlockInfo,
+) -> StdResult<()> {
+    // if the member's weight predates snapshots, record it as their initial snapshot,
+    // so that the changelog will correctly remember the weight being replaced
+    if MEMBER_WEIGHTS_HEIGHT_SNAPSHOT
+        .may_load(storage, member.clone())?
+        .is_none()
+    {
+        if let Some(existing_weight) = MEMBER_WEIGHTS.may_load(storage, member.clone())? {
+            MEMBER_WEIGHTS_HEIGHT_SNAPSHOT.save(storage, member.clone(), &existing_weight, 0)?;
+            MEMBER_WEIGHTS_SECONDS_SNAPSHOT.save(storage, member.clone(), &existing_weight, 0)?;
+        }
+    }
+
+    MEMBER_WEIGHTS_HEIGHT_SNAPSHOT.save(storage, member.clone(), &amount, block.height)?;
+    MEMBER_WEIGHTS_SECONDS_SNAPSHOT.save(storage, member, &amount, block.time.seconds())?;
+
+    Ok(())
+}
+
+/// Removes all members, recording their weights as zero from the given block onwards.
+pub fn clear_member_weights(storage: &mut dyn Storage, block: &BlockInfo) -> StdResult<()> {
+    let members = MEMBER_WEIGHTS
+        .keys(storage, None, None, Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    for member in members {
+        save_member_weight_snapshots(storage, member, Uint128::zero(), block)?;
+    }
+
+    MEMBER_WEIGHTS.clear(storage);
+
+    Ok(())
+}
+
 pub fn increment_member_weight(
     storage: &mut dyn Storage,
     member: Addr,
     amount: Uint128,
+    block: &BlockInfo,
 ) -> StdResult<Uint128> {
     let member_weight = get_member_weight(storage, member.clone())?;
     let new_member_weight = member_weight + amount;
-    set_member_weight(storage, member, new_member_weight)?;
+    set_member_weight(storage, member, new_member_weight, block)?;
 
     Ok(new_member_weight)
 }
@@ -35,10 +146,97 @@ pub fn decrement_member_weight(
     storage: &mut dyn Storage,
     member: Addr,
     amount: Uint128,
+    block: &BlockInfo,
 ) -> StdResult<Uint128> {
     let member_weight = get_member_weight(storage, member.clone())?;
     let new_member_weight = member_weight - amount;
-    set_member_weight(storage, member, new_member_weight)?;
+    set_member_weight(storage, member, new_member_weight, block)?;
 
     Ok(new_member_weight)
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{Addr, BlockInfo, Storage, Timestamp, Uint128};
+
+    use crate::member_weights::{
+        clear_member_weights, load_member_weight_at_height, load_member_weight_at_time,
+        set_member_weight, MEMBER_WEIGHTS,
+    };
+
+    fn block(height: u64, seconds: u64) -> BlockInfo {
+        BlockInfo {
+            height,
+            time: Timestamp::from_seconds(seconds),
+            ..mock_env().block
+        }
+    }
+
+    fn member() -> Addr {
+        Addr::unchecked("member")
+    }
+
+    fn weight_at(storage: &dyn Storage, height: u64, seconds: u64) -> (u128, u128) {
+        let at_height = load_member_weight_at_height(storage, member(), height).unwrap();
+        let at_time =
+            load_member_weight_at_time(storage, member(), Timestamp::from_seconds(seconds))
+                .unwrap();
+
+        (at_height.u128(), at_time.u128())
+    }
+
+    #[test]
+    fn loads_weights_as_they_were_at_given_moment() {
+        let mut storage = MockStorage::new();
+
+        set_member_weight(&mut storage, member(), Uint128::new(10), &block(10, 100)).unwrap();
+        set_member_weight(&mut storage, member(), Uint128::new(25), &block(20, 200)).unwrap();
+
+        // changes are only visible from the block after the one they were made in
+        assert_eq!(weight_at(&storage, 10, 100), (0, 0));
+        assert_eq!(weight_at(&storage, 11, 101), (10, 10));
+        assert_eq!(weight_at(&storage, 20, 200), (10, 10));
+        assert_eq!(weight_at(&storage, 21, 201), (25, 25));
+    }
+
+    #[test]
+    fn member_never_snapshotted_falls_back_to_current_weight() {
+        let mut storage = MockStorage::new();
+
+        // weight written before snapshots existed, bypassing the snapshot maps
+        MEMBER_WEIGHTS
+            .save(&mut storage, member(), &Uint128::new(7))
+            .unwrap();
+
+        assert_eq!(weight_at(&storage, 5, 50), (7, 7));
+        assert_eq!(weight_at(&storage, 500, 5000), (7, 7));
+    }
+
+    #[test]
+    fn member_weight_predating_snapshots_is_kept_for_earlier_moments() {
+        let mut storage = MockStorage::new();
+
+        MEMBER_WEIGHTS
+            .save(&mut storage, member(), &Uint128::new(7))
+            .unwrap();
+
+        set_member_weight(&mut storage, member(), Uint128::new(3), &block(10, 100)).unwrap();
+
+        assert_eq!(weight_at(&storage, 5, 50), (7, 7));
+        assert_eq!(weight_at(&storage, 11, 101), (3, 3));
+    }
+
+    #[test]
+    fn snapshotted_member_without_weight_at_moment_has_zero_weight() {
+        let mut storage = MockStorage::new();
+
+        set_member_weight(&mut storage, member(), Uint128::new(10), &block(10, 100)).unwrap();
+        clear_member_weights(&mut storage, &block(20, 200)).unwrap();
+
+        // cleared from current weights, but the snapshot still knows their history
+        assert_eq!(weight_at(&storage, 5, 50), (0, 0));
+        assert_eq!(weight_at(&storage, 15, 150), (10, 10));
+        assert_eq!(weight_at(&storage, 25, 250), (0, 0));
+    }
+}
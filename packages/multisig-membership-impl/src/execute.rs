@@ -1,7 +1,9 @@
 use crate::validate::dedup_user_weights;
 use common::cw::Context;
 use cosmwasm_std::{Addr, Order, Response, StdResult, Uint128};
-use membership_common::member_weights::{get_member_weight, set_member_weight, MEMBER_WEIGHTS};
+use membership_common::member_weights::{
+    clear_member_weights, get_member_weight, set_member_weight, MEMBER_WEIGHTS,
+};
 use membership_common::total_weight::{load_total_weight, save_total_weight};
 use membership_common::validate::enterprise_governance_controller_only;
 use membership_common::weight_change_hooks::report_weight_change_submsgs;
@@ -35,7 +37,7 @@ pub fn update_members(
             new_weight: weight,
         });
 
-        set_member_weight(ctx.deps.storage, member, weight)?;
+        set_member_weight(ctx.deps.storage, member, weight, &ctx.env.block)?;
     }
 
     save_total_weight(ctx.deps.storage, &total_weight, &ctx.env.block)?;
@@ -56,7 +58,7 @@ pub fn set_members(ctx: &mut Context, msg: SetMembersMsg) -> MultisigMembershipR
         .range(ctx.deps.storage, None, None, Order::Ascending)
         .collect::<StdResult<HashMap<Addr, Uint128>>>()?;
 
-    MEMBER_WEIGHTS.clear(ctx.deps.storage);
+    clear_member_weights(ctx.deps.storage, &ctx.env.block)?;
 
     let deduped_edit_members = dedup_user_weights(ctx, msg.new_members)?;
 
@@ -74,7 +76,7 @@ pub fn set_members(ctx: &mut Context, msg: SetMembersMsg) -> MultisigMembershipR
             new_weight: weight,
         });
 
-        set_member_weight(ctx.deps.storage, member, weight)?;
+        set_member_weight(ctx.deps.storage, member, weight, &ctx.env.block)?;
     }
 
     save_total_weight(ctx.deps.storage, &total_weight, &ctx.env.block)?;
@@ -41,7 +41,7 @@ fn save_initial_weights(
 
     for (user, weight) in deduped_weights {
         let existing_weight = get_member_weight(ctx.deps.storage, user.clone())?;
-        set_member_weight(ctx.deps.storage, user, weight)?;
+        set_member_weight(ctx.deps.storage, user, weight, &ctx.env.block)?;
 
         total_weight = total_weight - existing_weight + weight;
     }
@@ -86,7 +86,12 @@ fn stake_nft(ctx: &mut Context, msg: ReceiveNftMsg, user: String) -> NftStakingR
     save_nft_stake(ctx.deps.storage, &nft_stake)?;
 
     let old_weight = get_member_weight(ctx.deps.storage, user.clone())?;
-    let new_weight = increment_member_weight(ctx.deps.storage, user.clone(), Uint128::one())?;
+    let new_weight = increment_member_weight(
+        ctx.deps.storage,
+        user.clone(),
+        Uint128::one(),
+        &ctx.env.block,
+    )?;
     let new_total_staked = increment_total_weight(ctx, Uint128::one())?;
 
     let report_weight_change_submsgs = report_weight_change_submsgs(
@@ -149,7 +154,12 @@ pub fn unstake(ctx: &mut Context, msg: UnstakeMsg) -> NftStakingResult<Response>
 
     let unstaked_amount = Uint128::from(msg.nft_ids.len() as u128);
 
-    let new_weight = decrement_member_weight(ctx.deps.storage, user.clone(), unstaked_amount)?;
+    let new_weight = decrement_member_weight(
+        ctx.deps.storage,
+        user.clone(),
+        unstaked_amount,
+        &ctx.env.block,
+    )?;
 
     let new_total_staked = decrement_total_weight(ctx, unstaked_amount)?;
 
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use std::collections::BTreeMap;
+use std::fmt;
 
 use cosmwasm_std::{to_json_binary, Addr, Decimal, Timestamp, Uint128, Uint64};
 use serde_with::serde_as;
@@ -44,6 +45,10 @@ pub struct Poll {
     /// Calculated as (veto votes) / (total available votes - abstaining votes).
     /// If None, regular threshold will be used for veto option.
     pub veto_threshold: Option<Decimal>,
+    /// Labels of the options voters choose from in a multiple-choice poll, indexed by outcome.
+    /// Empty for other voting schemes.
+    #[serde(default)]
+    pub options: Vec<String>,
 
     #[schemars(with = "Vec<(u8, Uint128)>")]
     #[serde_as(as = "Vec<(_, _)>")]
@@ -56,6 +61,9 @@ pub struct Poll {
 /// Supported voting schemes.
 pub enum VotingScheme {
     CoinVoting,
+    /// Voters choose one of the poll's labelled options, the most voted option over the
+    /// threshold wins. There are no abstaining or veto outcomes.
+    MultipleChoice,
 }
 
 #[derive(Copy, Display)]
@@ -81,6 +89,56 @@ impl From<u8> for VoteOutcome {
     }
 }
 
+impl From<VoteOutcome> for u8 {
+    fn from(outcome: VoteOutcome) -> u8 {
+        outcome as u8
+    }
+}
+
+#[derive(Copy)]
+#[cw_serde]
+#[serde(untagged)]
+/// What a voter votes for - either one of the standard outcomes for coin-voting polls,
+/// or the index of the chosen option for multiple-choice polls.
+pub enum VoteChoice {
+    Outcome(VoteOutcome),
+    MultipleChoiceOption(u8),
+}
+
+impl From<VoteOutcome> for VoteChoice {
+    fn from(outcome: VoteOutcome) -> VoteChoice {
+        VoteChoice::Outcome(outcome)
+    }
+}
+
+impl From<VoteChoice> for u8 {
+    fn from(choice: VoteChoice) -> u8 {
+        match choice {
+            VoteChoice::Outcome(outcome) => outcome as u8,
+            VoteChoice::MultipleChoiceOption(option) => option,
+        }
+    }
+}
+
+impl fmt::Display for VoteChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoteChoice::Outcome(outcome) => write!(f, "{}", outcome),
+            VoteChoice::MultipleChoiceOption(option) => write!(f, "{}", option),
+        }
+    }
+}
+
+impl VoteChoice {
+    /// Reconstructs the vote choice from a stored outcome, given the poll's voting scheme.
+    pub fn from_outcome(scheme: VotingScheme, outcome: u8) -> VoteChoice {
+        match scheme {
+            VotingScheme::CoinVoting => VoteChoice::Outcome(VoteOutcome::from(outcome)),
+            VotingScheme::MultipleChoice => VoteChoice::MultipleChoiceOption(outcome),
+        }
+    }
+}
+
 /// Unique identifier for a vote, (voter, poll_id, outcome).
 pub type VoteUid = (Addr, PollId, u8);
 
@@ -92,9 +150,15 @@ pub struct Vote {
     /// Voter address.
     pub voter: Addr,
     /// The outcome, 0-indexed.
+    /// For split votes, this is the outcome with the largest share.
     pub outcome: u8,
     /// Number of votes on the outcome.
+    /// For split votes, this is the total number of votes across all shares.
     pub amount: u128,
+    /// Shares of the votes assigned to each outcome, if the voter split their votes.
+    /// Empty if all the votes were cast on a single outcome.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub split: Vec<VoteShare>,
 }
 
 impl Vote {
@@ -104,10 +168,43 @@ impl Vote {
             voter,
             outcome: outcome as u8,
             amount: count,
+            split: vec![],
+        }
+    }
+
+    /// Returns the number of votes cast on each of the outcomes of this vote.
+    pub fn outcome_amounts(&self) -> Vec<(u8, u128)> {
+        if self.split.is_empty() {
+            vec![(self.outcome, self.amount)]
+        } else {
+            self.split
+                .iter()
+                .map(|share| (share.outcome, share.amount))
+                .collect()
         }
     }
 }
 
+#[cw_serde]
+/// A share of a split vote, assigned to a single outcome.
+pub struct VoteShare {
+    /// The outcome, 0-indexed.
+    pub outcome: u8,
+    /// Portion of voter's votes assigned to this outcome.
+    pub weight: Decimal,
+    /// Number of votes on the outcome.
+    pub amount: u128,
+}
+
+#[cw_serde]
+/// A choice in a weighted vote, along with the portion of votes assigned to it.
+pub struct WeightedVoteChoice {
+    pub outcome: VoteChoice,
+    /// Portion of voter's votes assigned to this outcome.
+    /// Weights of all the choices in a vote must add up to 1.
+    pub weight: Decimal,
+}
+
 #[cw_serde]
 /// Params for creating a new poll.
 pub struct CreatePollParams {
@@ -132,6 +229,10 @@ pub struct CreatePollParams {
     /// Calculated as (veto votes) / (total available votes - abstaining votes).
     /// If None, regular threshold will be used for veto option.
     pub veto_threshold: Option<Decimal>,
+    /// Labels of the options voters choose from. Required for multiple-choice polls,
+    /// must be empty for other voting schemes.
+    #[serde(default)]
+    pub options: Vec<String>,
 }
 
 #[cw_serde]
@@ -140,13 +241,26 @@ pub struct CastVoteParams {
     /// Unique identifier for the poll
     pub poll_id: Uint64,
     /// The outcome.
-    pub outcome: VoteOutcome,
+    pub outcome: VoteChoice,
     /// Address of the voter casting the vote.
     pub voter: String,
     /// Number of votes on the outcome.
     pub amount: Uint128,
 }
 
+#[cw_serde]
+/// Params for casting a vote split across multiple outcomes on a poll.
+pub struct CastWeightedVoteParams {
+    /// Unique identifier for the poll
+    pub poll_id: Uint64,
+    /// The outcomes and the portion of votes assigned to each of them.
+    pub outcomes: Vec<WeightedVoteChoice>,
+    /// Address of the voter casting the vote.
+    pub voter: String,
+    /// Total number of votes across all the outcomes.
+    pub amount: Uint128,
+}
+
 #[cw_serde]
 /// Params for updating user's vote amount on all active polls they voted on.
 pub struct UpdateVotesParams {
@@ -154,6 +268,10 @@ pub struct UpdateVotesParams {
     pub voter: String,
     /// New number of user's available votes.
     pub new_amount: Uint128,
+    /// Polls in which the user's votes are left as they are, e.g. because votes in them
+    /// are counted by voting power at a snapshot.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_polls: Vec<Uint64>,
 }
 
 #[cw_serde]
@@ -169,6 +287,24 @@ pub struct EndPollParams {
     pub allow_early_ending: bool,
 }
 
+#[cw_serde]
+/// Params for cancelling a poll.
+pub struct CancelPollParams {
+    /// Unique identifier for the poll
+    pub poll_id: Uint64,
+}
+
+#[cw_serde]
+/// Params for updating the label and description of a poll.
+pub struct UpdatePollParams {
+    /// Unique identifier for the poll
+    pub poll_id: Uint64,
+    /// New label for the poll. If None, the label is left unchanged.
+    pub label: Option<String>,
+    /// New description for the poll. If None, the description is left unchanged.
+    pub description: Option<String>,
+}
+
 #[cw_serde]
 /// Params for querying a poll's status.
 pub struct PollStatusParams {
@@ -191,9 +327,18 @@ pub enum PollRejectionReason {
 #[cw_serde]
 /// Status of a poll.
 pub enum PollStatus {
-    InProgress { ends_at: Timestamp },
-    Passed { outcome: u8, count: Uint128 },
-    Rejected { reason: PollRejectionReason },
+    InProgress {
+        ends_at: Timestamp,
+    },
+    Passed {
+        outcome: u8,
+        count: Uint128,
+    },
+    Rejected {
+        reason: PollRejectionReason,
+    },
+    /// Poll was withdrawn before it ended, its results are disregarded.
+    Cancelled {},
 }
 
 impl PollStatus {
@@ -203,6 +348,7 @@ impl PollStatus {
             PollStatus::InProgress { .. } => PollStatusFilter::InProgress,
             PollStatus::Passed { .. } => PollStatusFilter::Passed,
             PollStatus::Rejected { .. } => PollStatusFilter::Rejected,
+            PollStatus::Cancelled {} => PollStatusFilter::Cancelled,
         }
     }
 }
@@ -214,6 +360,7 @@ pub enum PollStatusFilter {
     InProgress,
     Passed,
     Rejected,
+    Cancelled,
 }
 
 impl PollStatusFilter {
@@ -23,6 +23,9 @@ pub enum PollError {
     #[error("Poll {poll_id} already ended with status: {status}")]
     PollAlreadyEnded { poll_id: Uint64, status: String },
 
+    #[error("Poll {poll_id} was cancelled")]
+    PollCancelled { poll_id: Uint64 },
+
     #[error("Outside voting period: {}/{}, must be start < {now} (now) < end", voting_period.0, voting_period.1)]
     OutsideVotingPeriod {
         voting_period: (Timestamp, Timestamp),
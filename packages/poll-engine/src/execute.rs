@@ -1,14 +1,16 @@
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{StdError, Uint128, Uint64};
 
 use common::cw::Context;
 
 use crate::state::{poll_from, polls, votes, GovState, PollHelpers, PollStorage, GOV_STATE};
 use crate::validate::{
     validate_can_end_early, validate_create_poll, validate_not_already_ended,
-    validate_voting_period_ended, validate_within_voting_period,
+    validate_not_cancelled, validate_vote_choice, validate_voting_period_ended,
+    validate_weighted_vote_choices, validate_within_voting_period,
 };
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, Poll, Vote, VoteOutcome,
+    CancelPollParams, CastVoteParams, CastWeightedVoteParams, CreatePollParams, EndPollParams,
+    Poll, PollStatus, UpdatePollParams, Vote, VoteShare, WeightedVoteChoice,
 };
 use poll_engine_api::error::PollError::PollNotFound;
 use poll_engine_api::error::*;
@@ -40,54 +42,169 @@ pub fn cast_vote(
         amount,
     }: CastVoteParams,
 ) -> PollResult<()> {
-    let state = GOV_STATE.load(ctx.deps.storage)?;
+    let voter = ctx.deps.api.addr_validate(&voter)?;
 
-    if poll_id == Uint64::zero() || poll_id.u64() > state.poll_count {
-        return Err(PollNotFound { poll_id });
-    }
+    // 1. load existing poll
+    let mut poll = load_poll_to_vote_on(ctx, poll_id)?;
+
+    // 2. validate
+    validate_vote_choice(&poll, outcome)?;
 
-    let poll_key = poll_id.u64();
+    let new = Vote {
+        poll_id: poll_id.u64(),
+        voter,
+        outcome: outcome.into(),
+        amount: amount.u128(),
+        split: vec![],
+    };
+
+    save_vote(ctx, &mut poll, new)
+}
+
+/// Casts a vote on a poll, splitting the votes across multiple outcomes.
+pub fn cast_weighted_vote(
+    ctx: &mut Context,
+    CastWeightedVoteParams {
+        poll_id,
+        outcomes,
+        voter,
+        amount,
+    }: CastWeightedVoteParams,
+) -> PollResult<()> {
     let voter = ctx.deps.api.addr_validate(&voter)?;
 
     // 1. load existing poll
-    let mut poll = polls()
-        .may_load(ctx.deps.storage, poll_key)?
-        .ok_or(PollNotFound { poll_id })?;
+    let mut poll = load_poll_to_vote_on(ctx, poll_id)?;
 
     // 2. validate
-    validate_not_already_ended(&poll)?;
-    validate_within_voting_period(ctx.env.block.time, (poll.started_at, poll.ends_at))?;
+    validate_weighted_vote_choices(&poll, &outcomes)?;
+
+    let split = split_votes(amount.u128(), &outcomes);
+
+    // the largest share represents the vote for anyone not aware of split votes
+    let largest_share_outcome = split
+        .iter()
+        .max_by_key(|share| share.amount)
+        .map(|share| share.outcome)
+        .unwrap_or_default();
 
     let new = Vote {
         poll_id: poll_id.u64(),
-        voter: voter.clone(),
-        outcome: outcome as u8,
+        voter,
+        outcome: largest_share_outcome,
         amount: amount.u128(),
+        split,
     };
+
+    save_vote(ctx, &mut poll, new)
+}
+
+/// Loads the poll, ensuring it can currently be voted on.
+fn load_poll_to_vote_on(ctx: &Context, poll_id: Uint64) -> PollResult<Poll> {
+    let state = GOV_STATE.load(ctx.deps.storage)?;
+
+    if poll_id == Uint64::zero() || poll_id.u64() > state.poll_count {
+        return Err(PollNotFound { poll_id });
+    }
+
+    let poll = polls()
+        .may_load(ctx.deps.storage, poll_id.u64())?
+        .ok_or(PollNotFound { poll_id })?;
+
+    validate_not_already_ended(&poll)?;
+    validate_within_voting_period(ctx.env.block.time, (poll.started_at, poll.ends_at))?;
+
+    Ok(poll)
+}
+
+/// Splits the amount of votes according to the weights of the outcomes.
+/// Any remainder left after rounding down is assigned to the first outcome.
+fn split_votes(amount: u128, outcomes: &[WeightedVoteChoice]) -> Vec<VoteShare> {
+    let mut split: Vec<VoteShare> = outcomes
+        .iter()
+        .map(|choice| VoteShare {
+            outcome: choice.outcome.into(),
+            weight: choice.weight,
+            amount: (Uint128::from(amount) * choice.weight).u128(),
+        })
+        .collect();
+
+    let split_amount: u128 = split.iter().map(|share| share.amount).sum();
+    if let Some(first_share) = split.first_mut() {
+        first_share.amount += amount - split_amount;
+    }
+
+    split
+}
+
+/// Saves the voter's vote, replacing their previous vote in the poll's results if there was one.
+fn save_vote(ctx: &mut Context, poll: &mut Poll, new: Vote) -> PollResult<()> {
     // 3. load potential old voting data
-    let key = (voter, poll_id.u64());
+    let key = (new.voter.clone(), new.poll_id);
     votes()
-        .update(ctx.deps.storage, key, |old| match old {
-            // 5a. if old voting data for same outcome exists, subtract before adding new one to the results
-            Some(old) => {
-                poll.decrease_results(VoteOutcome::from(old.outcome), old.amount);
-                poll.increase_results(outcome, new.amount)
-                    .map_err(|e| e.std_err())?;
-                // 6. also save vote in the voting storage
-                Ok(new)
+        .update(ctx.deps.storage, key, |old| {
+            // 5. if old voting data exists, subtract before adding new one to the results
+            if let Some(old) = old {
+                for (outcome, amount) in old.outcome_amounts() {
+                    poll.decrease_results(outcome, amount);
+                }
             }
-            // 5b. if not, just add new one to the results
-            None => {
-                poll.increase_results(outcome, new.amount)
+            for (outcome, amount) in new.outcome_amounts() {
+                poll.increase_results(outcome, amount)
                     .map_err(|e| e.std_err())?;
-                // 6. also save vote in the voting storage
-                Ok(new)
             }
+            // 6. also save vote in the voting storage
+            Ok::<Vote, StdError>(new)
         })
         .map_err(PollError::Std)?;
 
     // 7. ...and poll storage
-    polls().save(ctx.deps.storage, poll_key, &poll)?;
+    polls().save(ctx.deps.storage, poll.id, poll)?;
+
+    Ok(())
+}
+
+/// Cancels a poll that is still in progress. Cancelled polls can no longer be voted on or ended.
+pub fn cancel_poll(
+    ctx: &mut Context,
+    CancelPollParams { poll_id }: CancelPollParams,
+) -> PollResult<()> {
+    let mut poll = polls()
+        .may_load(ctx.deps.storage, poll_id.into())?
+        .ok_or(PollNotFound { poll_id })?;
+
+    validate_not_already_ended(&poll)?;
+
+    poll.status = PollStatus::Cancelled {};
+    polls().save(ctx.deps.storage, poll_id.into(), &poll)?;
+
+    Ok(())
+}
+
+/// Updates the label and description of a poll that is still in progress.
+pub fn update_poll(
+    ctx: &mut Context,
+    UpdatePollParams {
+        poll_id,
+        label,
+        description,
+    }: UpdatePollParams,
+) -> PollResult<()> {
+    let mut poll = polls()
+        .may_load(ctx.deps.storage, poll_id.into())?
+        .ok_or(PollNotFound { poll_id })?;
+
+    validate_not_already_ended(&poll)?;
+
+    if let Some(label) = label {
+        poll.label = label;
+    }
+
+    if let Some(description) = description {
+        poll.description = description;
+    }
+
+    polls().save(ctx.deps.storage, poll_id.into(), &poll)?;
 
     Ok(())
 }
@@ -107,6 +224,8 @@ pub fn end_poll(
         .may_load(ctx.deps.storage, poll_id.into())?
         .ok_or(PollNotFound { poll_id })?;
 
+    validate_not_cancelled(&poll)?;
+
     if allow_early_ending {
         validate_can_end_early(now, maximum_available_votes, &poll)?;
     } else {
@@ -134,19 +253,23 @@ mod tests {
     use PollRejectionReason::OutcomeDraw;
     use VoteOutcome::{No, Veto};
 
-    use crate::execute::{cast_vote, create_poll, end_poll, initialize_poll_engine};
+    use crate::execute::{
+        cancel_poll, cast_vote, cast_weighted_vote, create_poll, end_poll, initialize_poll_engine,
+        update_poll,
+    };
     use crate::helpers::mock_poll;
     use crate::query::query_poll_status;
     use crate::state::{polls, GovState, GOV_STATE};
     use poll_engine_api::api::VoteOutcome::{Abstain, Yes};
     use poll_engine_api::api::{
-        CastVoteParams, CreatePollParams, EndPollParams, Poll, PollRejectionReason, PollStatus,
-        PollStatusFilter, VoteOutcome, VotingScheme,
+        CancelPollParams, CastVoteParams, CastWeightedVoteParams, CreatePollParams, EndPollParams,
+        Poll, PollRejectionReason, PollStatus, PollStatusFilter, UpdatePollParams, VoteChoice,
+        VoteOutcome, VotingScheme, WeightedVoteChoice,
     };
     use poll_engine_api::error::PollError;
     use poll_engine_api::error::PollError::{
         EndingEarlyQuorumNotReached, EndingEarlyThresholdNotReached, PollAlreadyEnded,
-        WithinVotingPeriod,
+        PollCancelled, WithinVotingPeriod,
     };
 
     #[test]
@@ -183,6 +306,7 @@ mod tests {
             quorum: quorum.clone(),
             threshold: threshold.clone(),
             veto_threshold: None,
+            options: vec![],
         };
 
         create_poll(&mut ctx, params).unwrap();
@@ -209,7 +333,7 @@ mod tests {
 
         let params = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter".to_string(),
             amount: Uint128::new(10),
         };
@@ -240,7 +364,7 @@ mod tests {
         ctx.env.block.time = Timestamp::from_nanos(2);
         let params = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter".to_string(),
             amount: Uint128::new(4),
         };
@@ -250,7 +374,7 @@ mod tests {
         ctx.env.block.time = Timestamp::from_nanos(2);
         let params = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter".to_string(),
             amount: Uint128::new(9),
         };
@@ -343,7 +467,7 @@ mod tests {
         ctx.env.block.time = Timestamp::from_nanos(4);
         let params = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter".to_string(),
             amount: Uint128::new(4),
         };
@@ -358,6 +482,316 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cannot_create_multiple_choice_poll_with_single_option() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+
+        let params = CreatePollParams {
+            proposer: "proposer".to_string(),
+            deposit_amount: Uint128::zero(),
+            label: "some poll".to_string(),
+            description: "some description".to_string(),
+            scheme: VotingScheme::MultipleChoice,
+            ends_at: ctx.env.block.time.plus_seconds(1000),
+            quorum: Decimal::percent(30),
+            threshold: Decimal::percent(50),
+            veto_threshold: None,
+            options: vec!["only option".to_string()],
+        };
+        let result = create_poll(&mut ctx, params);
+
+        assert!(matches!(result, Err(PollError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn casts_vote_on_multiple_choice_option() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.scheme = VotingScheme::MultipleChoice;
+        poll.options = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let params = CastVoteParams {
+            poll_id: poll.id.into(),
+            outcome: VoteChoice::MultipleChoiceOption(2),
+            voter: "voter".to_string(),
+            amount: Uint128::new(7),
+        };
+        cast_vote(&mut ctx, params).unwrap();
+
+        let poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        assert_eq!(&7, poll.results.get(&2).unwrap());
+    }
+
+    #[test]
+    fn cannot_vote_on_nonexistent_multiple_choice_option() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.scheme = VotingScheme::MultipleChoice;
+        poll.options = vec!["a".to_string(), "b".to_string()];
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let params = CastVoteParams {
+            poll_id: poll.id.into(),
+            outcome: VoteChoice::MultipleChoiceOption(2),
+            voter: "voter".to_string(),
+            amount: Uint128::new(7),
+        };
+        let result = cast_vote(&mut ctx, params);
+        assert!(matches!(result, Err(PollError::InvalidArgument { .. })));
+
+        let params = CastVoteParams {
+            poll_id: poll.id.into(),
+            outcome: Yes.into(),
+            voter: "voter".to_string(),
+            amount: Uint128::new(7),
+        };
+        let result = cast_vote(&mut ctx, params);
+        assert!(matches!(result, Err(PollError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn casts_weighted_vote() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let poll = mock_poll(ctx.deps.storage);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let params = CastWeightedVoteParams {
+            poll_id: poll.id.into(),
+            outcomes: vec![
+                WeightedVoteChoice {
+                    outcome: Yes.into(),
+                    weight: Decimal::percent(30),
+                },
+                WeightedVoteChoice {
+                    outcome: No.into(),
+                    weight: Decimal::percent(70),
+                },
+            ],
+            voter: "voter".to_string(),
+            amount: Uint128::new(11),
+        };
+        cast_weighted_vote(&mut ctx, params).unwrap();
+
+        let poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        // 30% of 11 rounds down to 3, 70% rounds down to 7, remainder goes to the first outcome
+        assert_eq!(&4, poll.results.get(&(Yes as u8)).unwrap());
+        assert_eq!(&7, poll.results.get(&(No as u8)).unwrap());
+    }
+
+    #[test]
+    fn casts_new_vote_over_weighted_vote() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let poll = mock_poll(ctx.deps.storage);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let params = CastWeightedVoteParams {
+            poll_id: poll.id.into(),
+            outcomes: vec![
+                WeightedVoteChoice {
+                    outcome: Yes.into(),
+                    weight: Decimal::percent(50),
+                },
+                WeightedVoteChoice {
+                    outcome: Abstain.into(),
+                    weight: Decimal::percent(50),
+                },
+            ],
+            voter: "voter".to_string(),
+            amount: Uint128::new(10),
+        };
+        cast_weighted_vote(&mut ctx, params).unwrap();
+
+        let params = CastVoteParams {
+            poll_id: poll.id.into(),
+            outcome: No.into(),
+            voter: "voter".to_string(),
+            amount: Uint128::new(6),
+        };
+        cast_vote(&mut ctx, params).unwrap();
+
+        let poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        assert_eq!(&0, poll.results.get(&(Yes as u8)).unwrap());
+        assert_eq!(&0, poll.results.get(&(Abstain as u8)).unwrap());
+        assert_eq!(&6, poll.results.get(&(No as u8)).unwrap());
+    }
+
+    #[test]
+    fn cannot_cast_weighted_vote_with_invalid_weights() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let poll = mock_poll(ctx.deps.storage);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let weighted_vote = |outcomes: Vec<(VoteOutcome, u64)>| CastWeightedVoteParams {
+            poll_id: poll.id.into(),
+            outcomes: outcomes
+                .into_iter()
+                .map(|(outcome, percent)| WeightedVoteChoice {
+                    outcome: outcome.into(),
+                    weight: Decimal::percent(percent),
+                })
+                .collect(),
+            voter: "voter".to_string(),
+            amount: Uint128::new(10),
+        };
+
+        for outcomes in [
+            vec![],
+            vec![(Yes, 50), (No, 40)],
+            vec![(Yes, 50), (Yes, 50)],
+            vec![(Yes, 100), (No, 0)],
+        ] {
+            let result = cast_weighted_vote(&mut ctx, weighted_vote(outcomes));
+            assert!(matches!(result, Err(PollError::InvalidArgument { .. })));
+        }
+    }
+
+    #[test]
+    fn cancels_poll() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        GOV_STATE
+            .save(ctx.deps.storage, &GovState::default())
+            .unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(1);
+
+        let poll = mock_poll(ctx.deps.storage);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        cancel_poll(
+            &mut ctx,
+            CancelPollParams {
+                poll_id: poll.id.into(),
+            },
+        )
+        .unwrap();
+
+        let poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        assert_eq!(PollStatus::Cancelled {}, poll.status);
+
+        let result = cast_vote(
+            &mut ctx,
+            CastVoteParams {
+                poll_id: poll.id.into(),
+                outcome: Yes.into(),
+                voter: "voter".to_string(),
+                amount: Uint128::new(1),
+            },
+        );
+        assert!(matches!(result, Err(PollAlreadyEnded { .. })));
+    }
+
+    #[test]
+    fn updates_poll_label_and_description() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        GOV_STATE
+            .save(ctx.deps.storage, &GovState::default())
+            .unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(1);
+
+        let poll = mock_poll(ctx.deps.storage);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        update_poll(
+            &mut ctx,
+            UpdatePollParams {
+                poll_id: poll.id.into(),
+                label: Some("amended label".to_string()),
+                description: None,
+            },
+        )
+        .unwrap();
+
+        let updated_poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        assert_eq!(updated_poll.label, "amended label");
+        assert_eq!(updated_poll.description, poll.description);
+    }
+
+    #[test]
+    fn cannot_update_ended_poll() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        GOV_STATE
+            .save(ctx.deps.storage, &GovState::default())
+            .unwrap();
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.status = PollStatus::Cancelled {};
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let result = update_poll(
+            &mut ctx,
+            UpdatePollParams {
+                poll_id: poll.id.into(),
+                label: Some("amended label".to_string()),
+                description: Some("amended description".to_string()),
+            },
+        );
+        assert!(matches!(result, Err(PollAlreadyEnded { .. })));
+    }
+
+    #[test]
+    fn cannot_end_cancelled_poll() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        GOV_STATE
+            .save(ctx.deps.storage, &GovState::default())
+            .unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(5);
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.status = PollStatus::Cancelled {};
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let result = end_poll(
+            &mut ctx,
+            EndPollParams {
+                poll_id: poll.id.into(),
+                maximum_available_votes: Uint128::new(10),
+                error_if_already_ended: false,
+                allow_early_ending: false,
+            },
+        );
+        assert!(matches!(result, Err(PollCancelled { .. })));
+
+        let result = cancel_poll(
+            &mut ctx,
+            CancelPollParams {
+                poll_id: poll.id.into(),
+            },
+        );
+        assert!(matches!(result, Err(PollAlreadyEnded { .. })));
+    }
+
     #[test]
     fn can_end_already_ended_poll_with_error_flag_set_to_false() {
         let mut deps = mock_dependencies();
@@ -568,25 +1002,25 @@ mod tests {
         ctx.env.block.time = Timestamp::from_nanos(2);
         let params = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter1".to_string(),
             amount: Uint128::new(10),
         };
         let params2 = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: No,
+            outcome: No.into(),
             voter: "voter2".to_string(),
             amount: Uint128::new(121),
         };
         let params3 = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: Abstain,
+            outcome: Abstain.into(),
             voter: "voter3".to_string(),
             amount: Uint128::new(110),
         };
         let params4 = CastVoteParams {
             poll_id: poll.id.into(),
-            outcome: Veto,
+            outcome: Veto.into(),
             voter: "voter4".to_string(),
             amount: Uint128::new(10),
         };
@@ -23,6 +23,7 @@ pub fn mock_poll_with_id(id: u64) -> Poll {
         quorum: Default::default(),
         threshold: Decimal::percent(50),
         veto_threshold: None,
+        options: vec![],
         results: Default::default(),
     }
 }
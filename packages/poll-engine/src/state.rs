@@ -13,9 +13,10 @@ use PollRejectionReason::{IsVetoOutcome, OutcomeDraw, QuorumNotReached, Threshol
 
 use poll_engine_api::api::PollRejectionReason::IsRejectingOutcome;
 use poll_engine_api::api::VoteOutcome::{Abstain, No, Veto, Yes};
+use poll_engine_api::api::VotingScheme::{CoinVoting, MultipleChoice};
 use poll_engine_api::api::{
     CreatePollParams, Poll, PollId, PollRejectionReason, PollStatus, PollStatusFilter, Vote,
-    VoteOutcome, VotingScheme,
+    VotingScheme,
 };
 use poll_engine_api::error::*;
 
@@ -415,6 +416,7 @@ pub fn new_poll(
         quorum,
         threshold,
         veto_threshold,
+        options: vec![],
         results: BTreeMap::new(),
     })
 }
@@ -459,6 +461,7 @@ pub fn new_poll(
 /// #     quorum: quorum.clone(),
 /// #     threshold: threshold.clone(),
 /// #     veto_threshold: veto_threshold.clone(),
+/// #     options: vec![],
 /// # };
 /// # let expected = Poll {
 /// #     id: poll_id.into(),
@@ -474,6 +477,7 @@ pub fn new_poll(
 /// #     quorum,
 /// #     threshold,
 /// #     veto_threshold,
+/// #     options: vec![],
 /// #     results: Default::default(),
 /// #     deposit_amount: 1000
 /// # };
@@ -487,7 +491,7 @@ pub fn new_poll(
 /// # }
 /// ```
 pub fn poll_from(deps: &mut DepsMut, env: &Env, params: CreatePollParams) -> PollResult<Poll> {
-    new_poll(
+    let poll = new_poll(
         deps,
         deps.api.addr_validate(&params.proposer)?,
         params.deposit_amount.u128(),
@@ -499,23 +503,31 @@ pub fn poll_from(deps: &mut DepsMut, env: &Env, params: CreatePollParams) -> Pol
         params.quorum,
         params.threshold,
         params.veto_threshold,
-    )
+    )?;
+
+    Ok(Poll {
+        options: params.options,
+        ..poll
+    })
 }
 
 pub trait PollHelpers {
-    fn increase_results(&mut self, outcome: VoteOutcome, count: u128) -> PollResult<Option<u128>>;
+    fn increase_results(&mut self, outcome: impl Into<u8>, count: u128)
+        -> PollResult<Option<u128>>;
 
-    fn decrease_results(&mut self, outcome: VoteOutcome, count: u128) -> Option<u128>;
+    fn decrease_results(&mut self, outcome: impl Into<u8>, count: u128) -> Option<u128>;
 
-    fn threshold_reached(&self, outcome: VoteOutcome) -> bool;
+    fn threshold_reached(&self, outcome: impl Into<u8>) -> bool;
 
-    fn ge_threshold(&self, outcome: VoteOutcome, count: u128) -> bool;
+    fn ge_threshold(&self, outcome: impl Into<u8>, count: u128) -> bool;
 
     fn quorum_reached(&self, quorum: &Decimal, maximum_available_votes: u128) -> bool;
 
     fn total_votes(&self) -> u128;
 
-    fn votes_for(&self, outcome: VoteOutcome) -> u128;
+    fn votes_for(&self, outcome: impl Into<u8>) -> u128;
+
+    fn abstaining_votes(&self) -> u128;
 
     fn most_voted_over_threshold(&self) -> MostVoted<(u8, u128)>;
 
@@ -549,13 +561,18 @@ impl PollHelpers for Poll {
     /// # Ok(())
     /// # }
     /// ```
-    fn increase_results(&mut self, outcome: VoteOutcome, count: u128) -> PollResult<Option<u128>> {
-        match self.results.get_mut(&(outcome as u8)) {
+    fn increase_results(
+        &mut self,
+        outcome: impl Into<u8>,
+        count: u128,
+    ) -> PollResult<Option<u128>> {
+        let outcome = outcome.into();
+        match self.results.get_mut(&outcome) {
             Some(total_count) => {
                 *total_count += count;
                 Ok(Some(*total_count))
             }
-            None => Ok(self.results.insert(outcome as u8, count)),
+            None => Ok(self.results.insert(outcome, count)),
         }
     }
 
@@ -587,8 +604,8 @@ impl PollHelpers for Poll {
     /// # Ok(())
     /// # }
     /// ```
-    fn decrease_results(&mut self, outcome: VoteOutcome, count: u128) -> Option<u128> {
-        match self.results.get_mut(&(outcome as u8)) {
+    fn decrease_results(&mut self, outcome: impl Into<u8>, count: u128) -> Option<u128> {
+        match self.results.get_mut(&outcome.into()) {
             Some(total_count) => {
                 *total_count -= count;
                 Some(*total_count)
@@ -624,18 +641,19 @@ impl PollHelpers for Poll {
     /// # Ok(())
     /// # }
     /// ```
-    fn threshold_reached(&self, outcome: VoteOutcome) -> bool {
+    fn threshold_reached(&self, outcome: impl Into<u8>) -> bool {
+        let outcome = outcome.into();
         self.ge_threshold(outcome, self.votes_for(outcome))
     }
 
     /// Checks if the count-to-total-votes ratio is greater than the threshold.
-    fn ge_threshold(&self, outcome: VoteOutcome, count: u128) -> bool {
-        let threshold = if outcome == Veto {
+    fn ge_threshold(&self, outcome: impl Into<u8>, count: u128) -> bool {
+        let threshold = if self.scheme == CoinVoting && outcome.into() == Veto as u8 {
             self.veto_threshold.unwrap_or(self.threshold)
         } else {
             self.threshold
         };
-        Decimal::checked_from_ratio(count, self.total_votes() - self.votes_for(Abstain))
+        Decimal::checked_from_ratio(count, self.total_votes() - self.abstaining_votes())
             .unwrap_or(Decimal::zero())
             .ge(&threshold)
     }
@@ -729,8 +747,17 @@ impl PollHelpers for Poll {
     /// # Ok(())
     /// # }
     /// ```
-    fn votes_for(&self, outcome: VoteOutcome) -> u128 {
-        *self.results.get(&(outcome as u8)).unwrap_or(&0u128)
+    fn votes_for(&self, outcome: impl Into<u8>) -> u128 {
+        *self.results.get(&outcome.into()).unwrap_or(&0u128)
+    }
+
+    /// Returns the vote count for the abstaining outcome.
+    /// Multiple-choice polls have no abstaining outcome.
+    fn abstaining_votes(&self) -> u128 {
+        match self.scheme {
+            CoinVoting => self.votes_for(Abstain),
+            MultipleChoice => 0,
+        }
     }
 
     /// Returns the most voted outcome/count, if any.
@@ -758,6 +785,10 @@ impl PollHelpers for Poll {
     /// # }
     /// ```
     fn most_voted_over_threshold(&self) -> MostVoted<(u8, u128)> {
+        if self.scheme == MultipleChoice {
+            return most_voted_option_over_threshold(self);
+        }
+
         if self.threshold_reached(Veto) {
             // if veto threshold reached, no need to check anything else
             return MostVoted::Some((Veto as u8, self.votes_for(Veto)));
@@ -827,7 +858,7 @@ impl PollHelpers for Poll {
                     reason: ThresholdNotReached,
                 },
                 MostVoted::Some((outcome, count)) => {
-                    if outcome == Yes as u8 {
+                    if self.scheme == MultipleChoice || outcome == Yes as u8 {
                         PollStatus::Passed {
                             outcome,
                             count: count.into(),
@@ -852,6 +883,35 @@ impl PollHelpers for Poll {
     }
 }
 
+/// Returns the most voted option of a multiple-choice poll, if any reached the threshold.
+fn most_voted_option_over_threshold(poll: &Poll) -> MostVoted<(u8, u128)> {
+    let mut most_voted: Option<(u8, u128)> = None;
+    let mut tied_with_most_voted: Option<(u8, u128)> = None;
+
+    for (&option, &count) in &poll.results {
+        if count == 0 || !poll.ge_threshold(option, count) {
+            continue;
+        }
+
+        match most_voted {
+            Some((_, most_voted_count)) if count < most_voted_count => {}
+            Some((_, most_voted_count)) if count == most_voted_count => {
+                tied_with_most_voted = Some((option, count))
+            }
+            _ => {
+                most_voted = Some((option, count));
+                tied_with_most_voted = None;
+            }
+        }
+    }
+
+    match (most_voted, tied_with_most_voted) {
+        (None, _) => MostVoted::None,
+        (Some(most_voted), None) => MostVoted::Some(most_voted),
+        (Some(most_voted), Some(tied)) => MostVoted::Draw(most_voted, tied),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -867,7 +927,7 @@ mod tests {
 
     use crate::helpers::mock_poll;
     use crate::state::{GovState, PollHelpers, GOV_STATE};
-    use poll_engine_api::api::{PollRejectionReason, PollStatus};
+    use poll_engine_api::api::{PollRejectionReason, PollStatus, VotingScheme};
 
     #[test]
     fn final_status_passed() {
@@ -1048,4 +1108,75 @@ mod tests {
             poll.final_status(20u8.into()).unwrap()
         );
     }
+
+    #[test]
+    fn final_status_multiple_choice_passed_with_most_voted_option() {
+        let mut deps = mock_dependencies();
+        let ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.scheme = VotingScheme::MultipleChoice;
+        poll.options = vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string(),
+        ];
+        poll.quorum = Decimal::percent(10);
+        poll.threshold = Decimal::percent(40);
+        // options 2 and 3 would be abstain and veto outcomes in a coin-voting poll
+        poll.results = BTreeMap::from([(0, 2), (1, 3), (2, 1), (3, 4)]);
+
+        assert_eq!(
+            PollStatus::Passed {
+                outcome: 3,
+                count: Uint128::new(4),
+            },
+            poll.final_status(20u8.into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn final_status_multiple_choice_rejected_outcome_draw() {
+        let mut deps = mock_dependencies();
+        let ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.scheme = VotingScheme::MultipleChoice;
+        poll.options = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        poll.threshold = Decimal::percent(30);
+        poll.results = BTreeMap::from([(0, 4), (1, 2), (2, 4)]);
+
+        assert_eq!(
+            PollStatus::Rejected {
+                reason: OutcomeDraw(0, 2, Uint128::new(4))
+            },
+            poll.final_status(10u8.into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn final_status_multiple_choice_rejected_threshold_not_reached() {
+        let mut deps = mock_dependencies();
+        let ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.scheme = VotingScheme::MultipleChoice;
+        poll.options = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        poll.threshold = Decimal::percent(50);
+        poll.results = BTreeMap::from([(0, 4), (1, 3), (2, 3)]);
+
+        assert_eq!(
+            PollStatus::Rejected {
+                reason: ThresholdNotReached
+            },
+            poll.final_status(10u8.into()).unwrap()
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::string::ToString;
 
 use cosmwasm_std::{Decimal, Timestamp, Uint128};
@@ -5,9 +6,11 @@ use cosmwasm_std::{Decimal, Timestamp, Uint128};
 use common::cw::Context;
 
 use crate::state::{MostVoted, PollHelpers};
-use poll_engine_api::api::{CreatePollParams, Poll, PollStatus};
+use poll_engine_api::api::{
+    CreatePollParams, Poll, PollStatus, VoteChoice, VotingScheme, WeightedVoteChoice,
+};
 use poll_engine_api::error::PollError::{
-    OutsideVotingPeriod, PollAlreadyEnded, WithinVotingPeriod,
+    OutsideVotingPeriod, PollAlreadyEnded, PollCancelled, WithinVotingPeriod,
 };
 use poll_engine_api::error::*;
 
@@ -24,6 +27,27 @@ pub fn validate_create_poll(ctx: &mut Context, params: &CreatePollParams) -> Pol
         });
     }
 
+    match params.scheme {
+        VotingScheme::CoinVoting => {
+            if !params.options.is_empty() {
+                return Err(PollError::InvalidArgument {
+                    msg: "Options can only be defined for multiple-choice polls".to_string(),
+                });
+            }
+        }
+        VotingScheme::MultipleChoice => {
+            if params.options.len() < 2 || params.options.len() > u8::MAX as usize {
+                return Err(PollError::InvalidArgument {
+                    msg: format!(
+                        "Invalid number of options {}, must be 2 <= options <= {}",
+                        params.options.len(),
+                        u8::MAX,
+                    ),
+                });
+            }
+        }
+    }
+
     match params {
         // if now >= ends_at
         CreatePollParams { ends_at, .. } if now.ge(ends_at) => Err(PollError::InvalidArgument {
@@ -83,6 +107,76 @@ pub fn validate_can_end_early(
     Ok(())
 }
 
+pub fn validate_vote_choice(poll: &Poll, choice: VoteChoice) -> PollResult<()> {
+    let is_valid_choice = match (poll.scheme, choice) {
+        (VotingScheme::CoinVoting, VoteChoice::Outcome(_)) => true,
+        (VotingScheme::MultipleChoice, VoteChoice::MultipleChoiceOption(option)) => {
+            (option as usize) < poll.options.len()
+        }
+        _ => false,
+    };
+
+    if is_valid_choice {
+        Ok(())
+    } else {
+        Err(PollError::InvalidArgument {
+            msg: format!("Invalid vote choice {} for poll {}", choice, poll.id),
+        })
+    }
+}
+
+pub fn validate_weighted_vote_choices(
+    poll: &Poll,
+    choices: &[WeightedVoteChoice],
+) -> PollResult<()> {
+    if choices.is_empty() {
+        return Err(PollError::InvalidArgument {
+            msg: "Weighted vote must contain at least one outcome".to_string(),
+        });
+    }
+
+    let mut outcomes: HashSet<u8> = HashSet::new();
+    let mut total_weight = Decimal::zero();
+
+    for choice in choices {
+        validate_vote_choice(poll, choice.outcome)?;
+
+        if !outcomes.insert(choice.outcome.into()) {
+            return Err(PollError::InvalidArgument {
+                msg: format!("Duplicate outcome {} in weighted vote", choice.outcome),
+            });
+        }
+
+        if choice.weight.is_zero() {
+            return Err(PollError::InvalidArgument {
+                msg: format!("Invalid weight for outcome {}, must be > 0", choice.outcome),
+            });
+        }
+
+        total_weight += choice.weight;
+    }
+
+    if total_weight != Decimal::one() {
+        return Err(PollError::InvalidArgument {
+            msg: format!(
+                "Invalid total weight {}, weights of all outcomes must add up to 1",
+                total_weight
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_not_cancelled(poll: &Poll) -> PollResult<()> {
+    match &poll.status {
+        PollStatus::Cancelled {} => Err(PollCancelled {
+            poll_id: poll.id.into(),
+        }),
+        _ => Ok(()),
+    }
+}
+
 pub fn validate_not_already_ended(poll: &Poll) -> PollResult<()> {
     match &poll.status {
         PollStatus::InProgress { .. } => Ok(()),
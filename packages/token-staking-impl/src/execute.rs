@@ -49,7 +49,8 @@ fn stake_token(
     let user = ctx.deps.api.addr_validate(&user)?;
 
     let old_weight = get_member_weight(ctx.deps.storage, user.clone())?;
-    let new_weight = increment_member_weight(ctx.deps.storage, user.clone(), msg.amount)?;
+    let new_weight =
+        increment_member_weight(ctx.deps.storage, user.clone(), msg.amount, &ctx.env.block)?;
     let new_total_staked = increment_total_weight(ctx, msg.amount)?;
 
     let report_weight_change_submsgs = report_weight_change_submsgs(
@@ -91,7 +92,7 @@ fn add_stakes(
         let existing_stake = get_member_weight(ctx.deps.storage, user.clone())?;
         let new_user_stake = existing_stake.checked_add(staker.staked_amount)?;
 
-        set_member_weight(ctx.deps.storage, user, new_user_stake)?;
+        set_member_weight(ctx.deps.storage, user, new_user_stake, &ctx.env.block)?;
 
         user_weight_changes.push(UserWeightChange {
             user: staker.user,
@@ -150,7 +151,12 @@ pub fn unstake(ctx: &mut Context, msg: UnstakeMsg) -> TokenStakingResult<Respons
 
     let unstaked_amount = msg.amount;
 
-    let new_weight = decrement_member_weight(ctx.deps.storage, user.clone(), unstaked_amount)?;
+    let new_weight = decrement_member_weight(
+        ctx.deps.storage,
+        user.clone(),
+        unstaked_amount,
+        &ctx.env.block,
+    )?;
     let new_total_staked = decrement_total_weight(ctx, unstaked_amount)?;
 
     let release_at = calculate_release_at(ctx)?;
@@ -6,7 +6,9 @@ use cosmwasm_std::{Addr, StdResult, Uint128};
 use cw_storage_plus::Bound;
 use cw_utils::Expiration;
 use membership_common::enterprise_contract::ENTERPRISE_CONTRACT;
-use membership_common::member_weights::{get_member_weight, MEMBER_WEIGHTS};
+use membership_common::member_weights::{
+    get_member_weight, load_member_weight_at_height, load_member_weight_at_time, MEMBER_WEIGHTS,
+};
 use membership_common::total_weight::{
     load_total_weight, load_total_weight_at_height, load_total_weight_at_time,
 };
@@ -38,7 +40,15 @@ pub fn query_user_weight(
 ) -> TokenStakingResult<UserWeightResponse> {
     let user = qctx.deps.api.addr_validate(&params.user)?;
 
-    let user_stake = get_member_weight(qctx.deps.storage, user.clone())?;
+    let user_stake = match params.expiration.unwrap_or(Expiration::Never {}) {
+        Expiration::AtHeight(height) => {
+            load_member_weight_at_height(qctx.deps.storage, user.clone(), height)?
+        }
+        Expiration::AtTime(time) => {
+            load_member_weight_at_time(qctx.deps.storage, user.clone(), time)?
+        }
+        Expiration::Never {} => get_member_weight(qctx.deps.storage, user.clone())?,
+    };
 
     Ok(UserWeightResponse {
         user,